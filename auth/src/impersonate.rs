@@ -0,0 +1,415 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::secret::SecretString;
+use crate::source::{RefresherSource, Source};
+use crate::{AccessToken, Credential, Diagnostics, Error, ErrorKind, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(3600);
+const MAX_LIFETIME: Duration = Duration::from_secs(12 * 3600);
+const IAM_CREDENTIALS_ENDPOINT: &str = "https://iamcredentials.googleapis.com";
+
+/// A builder for [Credential]s that impersonate a service account.
+///
+/// The resulting credentials use a base [Credential] to call the
+/// [generateAccessToken] method in the IAM Credentials API, and return the
+/// access tokens for the target service account. The base credentials need
+/// the `roles/iam.serviceAccountTokenCreator` role on the target service
+/// account, or on the first delegate if there is a delegation chain.
+///
+/// ```no_run
+/// # use google_cloud_auth::*;
+/// # async fn sample() -> Result<()> {
+/// let config = CredentialConfig::builder()
+///     .scopes(vec!["https://www.googleapis.com/auth/cloud-platform".into()])
+///     .build()?;
+/// let base = Credential::find_default(config).await?;
+/// let credential =
+///     ImpersonatedCredentialBuilder::new(base, "my-sa@my-project.iam.gserviceaccount.com")
+///         .lifetime(std::time::Duration::from_secs(600))
+///         .build()?;
+/// let token = credential.access_token().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [generateAccessToken]: https://cloud.google.com/iam/docs/reference/credentials/rest/v1/projects.serviceAccounts/generateAccessToken
+pub struct ImpersonatedCredentialBuilder {
+    base: Credential,
+    target_principal: String,
+    delegates: Vec<String>,
+    scopes: Vec<String>,
+    lifetime: Duration,
+    endpoint: String,
+    client: Option<reqwest::Client>,
+}
+
+impl ImpersonatedCredentialBuilder {
+    /// Instantiates a new builder.
+    ///
+    /// The `target_principal` is the email of the service account to
+    /// impersonate.
+    pub fn new(base: Credential, target_principal: impl Into<String>) -> Self {
+        Self {
+            base,
+            target_principal: target_principal.into(),
+            delegates: Vec::new(),
+            scopes: Vec::new(),
+            lifetime: DEFAULT_LIFETIME,
+            endpoint: IAM_CREDENTIALS_ENDPOINT.to_string(),
+            client: None,
+        }
+    }
+
+    /// Sets the delegation chain.
+    ///
+    /// Each entry is the email of a service account. The base credentials
+    /// must be able to create tokens for the first service account, each
+    /// service account must be able to create tokens for the next one, and
+    /// the last one must be able to create tokens for the target principal.
+    pub fn delegates(mut self, value: Vec<String>) -> Self {
+        self.delegates = value;
+        self
+    }
+
+    /// Sets the scopes of the access tokens.
+    ///
+    /// Defaults to `https://www.googleapis.com/auth/cloud-platform`.
+    pub fn scopes(mut self, value: Vec<String>) -> Self {
+        self.scopes = value;
+        self
+    }
+
+    /// Sets the lifetime of the access tokens.
+    ///
+    /// Defaults to one hour. The IAM Credentials API accepts up to 12 hours,
+    /// though lifetimes over one hour require an organization policy change.
+    pub fn lifetime(mut self, value: Duration) -> Self {
+        self.lifetime = value;
+        self
+    }
+
+    /// Sets the endpoint for the IAM Credentials API.
+    ///
+    /// Defaults to `https://iamcredentials.googleapis.com`.
+    pub fn endpoint(mut self, value: impl Into<String>) -> Self {
+        self.endpoint = value.into();
+        self
+    }
+
+    /// Sets the HTTP client used to call the IAM Credentials API.
+    ///
+    /// Use this to send the requests through the same proxy as the rest of
+    /// the application. By default the credentials use a client with the
+    /// default settings, which honors the proxy environment variables.
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.client = Some(value);
+        self
+    }
+
+    /// Builds a [Credential] that impersonates the target principal.
+    pub fn build(self) -> Result<Credential> {
+        if self.target_principal.is_empty() {
+            return Err(Error::new(
+                "the target principal must be provided",
+                ErrorKind::Validation,
+            ));
+        }
+        if self.lifetime.as_secs() == 0 || self.lifetime > MAX_LIFETIME {
+            return Err(Error::new(
+                format!(
+                    "the lifetime must be between 1 second and 12 hours, got {:?}",
+                    self.lifetime
+                ),
+                ErrorKind::Validation,
+            ));
+        }
+        let scopes = if self.scopes.is_empty() {
+            vec![DEFAULT_SCOPE.to_string()]
+        } else {
+            self.scopes
+        };
+        let source = ImpersonatedSource {
+            base: self.base,
+            target_principal: self.target_principal,
+            delegates: self.delegates,
+            scopes,
+            lifetime: self.lifetime,
+            endpoint: self.endpoint,
+            client: self.client.unwrap_or_default(),
+        };
+        Ok(Credential {
+            source: Box::new(RefresherSource::new(Box::new(source))),
+        })
+    }
+}
+
+/// A [Source] that impersonates a service account.
+#[derive(Clone)]
+struct ImpersonatedSource {
+    base: Credential,
+    target_principal: String,
+    delegates: Vec<String>,
+    scopes: Vec<String>,
+    lifetime: Duration,
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl ImpersonatedSource {
+    /// Retrieves an [AccessToken] for the target principal.
+    async fn _fetch_access_token(&self) -> Result<AccessToken> {
+        let base = self.base.access_token().await?;
+        let url = format!(
+            "{}/v1/{}:generateAccessToken",
+            self.endpoint,
+            service_account_name(&self.target_principal)
+        );
+        let res = self
+            .client
+            .post(url)
+            .bearer_auth(base.value.expose())
+            .json(&GenerateAccessTokenRequest {
+                delegates: self
+                    .delegates
+                    .iter()
+                    .map(|d| service_account_name(d))
+                    .collect(),
+                scope: &self.scopes,
+                lifetime: format!("{}s", self.lifetime.as_secs()),
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                Error::new_with_error(
+                    "unable to make request to the IAM credentials endpoint",
+                    e,
+                    ErrorKind::Http,
+                )
+            })?;
+        if !res.status().is_success() {
+            return Err(Error::new(
+                format!("bad request with status: {}", res.status()),
+                ErrorKind::Http,
+            ));
+        }
+        let response: GenerateAccessTokenResponse =
+            res.json().await.map_err(Error::wrap_serialization)?;
+        Ok(AccessToken {
+            value: response.access_token,
+            expires: Some(response.expire_time),
+        })
+    }
+}
+
+#[async_trait]
+impl Source for ImpersonatedSource {
+    async fn token(&self) -> Result<AccessToken> {
+        self._fetch_access_token().await
+    }
+
+    fn source_type(&self) -> &'static str {
+        "impersonated_service_account"
+    }
+
+    async fn describe(&self, token: &AccessToken) -> Result<Diagnostics> {
+        Ok(Diagnostics {
+            source_type: self.source_type().to_string(),
+            principal: Some(self.target_principal.clone()),
+            scopes: self.scopes.clone(),
+            expires_at: token.expires,
+            ..Default::default()
+        })
+    }
+}
+
+fn service_account_name(email: &str) -> String {
+    format!("projects/-/serviceAccounts/{email}")
+}
+
+/// The request body for `generateAccessToken`.
+#[derive(Serialize)]
+struct GenerateAccessTokenRequest<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    delegates: Vec<String>,
+    scope: &'a [String],
+    lifetime: String,
+}
+
+/// The response of `generateAccessToken`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateAccessTokenResponse {
+    access_token: SecretString,
+    expire_time: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::Path;
+    use axum::http::HeaderMap;
+    use axum::Json;
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+
+    type Requests = Arc<Mutex<Vec<(String, Option<String>, Value)>>>;
+
+    // Starts a fake IAM credentials server. Returns the endpoint and the
+    // captured requests.
+    async fn start() -> (String, Requests) {
+        let requests = Requests::default();
+        let captured = requests.clone();
+        let handler =
+            move |Path(name): Path<String>, headers: HeaderMap, Json(body): Json<Value>| {
+                let captured = captured.clone();
+                async move {
+                    let authorization = headers
+                        .get("authorization")
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string);
+                    captured.lock().unwrap().push((name, authorization, body));
+                    Json(json!({
+                        "accessToken": "test-only-impersonated-token",
+                        "expireTime": "2100-01-01T00:00:00Z",
+                    }))
+                }
+            };
+        let app = axum::Router::new().route(
+            "/v1/projects/-/serviceAccounts/*name",
+            axum::routing::post(handler),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("http://{addr}"), requests)
+    }
+
+    #[tokio::test]
+    async fn impersonated_token() {
+        let (endpoint, requests) = start().await;
+        let credential = ImpersonatedCredentialBuilder::new(
+            Credential::test_credentials(),
+            "target@test-only.iam.gserviceaccount.com",
+        )
+        .delegates(vec!["delegate@test-only.iam.gserviceaccount.com".into()])
+        .scopes(vec!["test-only-scope".into()])
+        .lifetime(Duration::from_secs(600))
+        .endpoint(endpoint)
+        .build()
+        .unwrap();
+        assert_eq!(credential.source_type(), "impersonated_service_account");
+
+        let token = credential.access_token().await.unwrap();
+        assert_eq!(token.value.expose(), "test-only-impersonated-token");
+        assert_eq!(
+            token.expires.map(|e| e.to_rfc3339()),
+            Some("2100-01-01T00:00:00+00:00".to_string())
+        );
+        // The token is cached.
+        let token = credential.access_token().await.unwrap();
+        assert_eq!(token.value.expose(), "test-only-impersonated-token");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let (name, authorization, body) = &requests[0];
+        assert_eq!(
+            name,
+            "target@test-only.iam.gserviceaccount.com:generateAccessToken"
+        );
+        assert_eq!(authorization.as_deref(), Some("Bearer test-only"));
+        assert_eq!(
+            body,
+            &json!({
+                "delegates": ["projects/-/serviceAccounts/delegate@test-only.iam.gserviceaccount.com"],
+                "scope": ["test-only-scope"],
+                "lifetime": "600s",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn default_scopes() {
+        let (endpoint, requests) = start().await;
+        let credential = ImpersonatedCredentialBuilder::new(
+            Credential::test_credentials(),
+            "target@test-only.iam.gserviceaccount.com",
+        )
+        .endpoint(endpoint)
+        .build()
+        .unwrap();
+        credential.access_token().await.unwrap();
+
+        let requests = requests.lock().unwrap();
+        let (_, _, body) = &requests[0];
+        assert_eq!(
+            body,
+            &json!({
+                "scope": ["https://www.googleapis.com/auth/cloud-platform"],
+                "lifetime": "3600s",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn diagnose() {
+        let (endpoint, _requests) = start().await;
+        let credential = ImpersonatedCredentialBuilder::new(
+            Credential::test_credentials(),
+            "target@test-only.iam.gserviceaccount.com",
+        )
+        .scopes(vec!["test-only-scope".into()])
+        .endpoint(endpoint)
+        .build()
+        .unwrap();
+        let diagnostics = credential.diagnose().await.unwrap();
+        assert_eq!(diagnostics.source_type, "impersonated_service_account");
+        assert_eq!(
+            diagnostics.principal.as_deref(),
+            Some("target@test-only.iam.gserviceaccount.com")
+        );
+        assert_eq!(diagnostics.scopes, vec!["test-only-scope"]);
+        assert_eq!(
+            diagnostics.expires_at.map(|e| e.to_rfc3339()),
+            Some("2100-01-01T00:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let err = ImpersonatedCredentialBuilder::new(Credential::test_credentials(), "")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        for lifetime in [Duration::ZERO, MAX_LIFETIME + Duration::from_secs(1)] {
+            let err = ImpersonatedCredentialBuilder::new(
+                Credential::test_credentials(),
+                "target@test-only.iam.gserviceaccount.com",
+            )
+            .lifetime(lifetime)
+            .build()
+            .err()
+            .unwrap();
+            assert_eq!(err.kind(), ErrorKind::Validation, "{lifetime:?}");
+        }
+    }
+}
@@ -18,15 +18,21 @@
 
 use chrono::Utc;
 use chrono::{DateTime, Duration};
+use secret::SecretString;
 use serde::Deserialize;
 use source::*;
 use std::error::Error as StdError;
 use std::path::PathBuf;
 
+mod impersonate;
 mod metadata;
 mod oauth2;
+/// Types to hold secret material, such as access tokens.
+pub mod secret;
 mod source;
 
+pub use impersonate::ImpersonatedCredentialBuilder;
+
 const GOOGLE_APPLICATION_CREDENTIALS_ENV: &str = "GOOGLE_APPLICATION_CREDENTIALS";
 const WINDOWS_APPDATA_ENV: &str = "APPDATA";
 const UNIX_HOME_ENV: &str = "HOME";
@@ -142,15 +148,25 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 /// AccessToken holds a token value that can be used in Authorization headers to
 /// authenticate with Google Cloud APIs.
-#[derive(Clone)]
+///
+/// The token value is a [SecretString][secret::SecretString]: it is zeroed
+/// when dropped, and censored in the `Debug` output.
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct AccessToken {
-    /// The actual token.
-    pub value: String,
+    value: SecretString,
     expires: Option<DateTime<Utc>>,
 }
 
 impl AccessToken {
+    /// Returns the token value.
+    ///
+    /// Use [SecretString::expose][secret::SecretString::expose] to get the
+    /// token as a string, e.g. to set the `Authorization` header.
+    pub fn value(&self) -> &SecretString {
+        &self.value
+    }
+
     /// Returns true if the token should be considered valid, compensating for
     /// clock skew with by ten seconds.
     pub(crate) fn is_validish(&self) -> bool {
@@ -165,28 +181,95 @@ impl AccessToken {
     }
 }
 
+/// A summary of the configuration of a [Credential].
+///
+/// Applications can log this summary on startup. It helps troubleshoot
+/// authentication and authorization problems, such as `PERMISSION_DENIED`
+/// errors caused by the wrong principal, missing scopes, or the wrong quota
+/// project. It never contains secrets or the token value.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Diagnostics {
+    /// The type of credentials, see [Credential::source_type].
+    pub source_type: String,
+    /// The principal (user or service account) represented by the
+    /// credentials, if known.
+    pub principal: Option<String>,
+    /// The scopes requested by the credentials, if any.
+    pub scopes: Vec<String>,
+    /// When the current token expires, if known.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// The project used for quota and billing, if configured in the
+    /// credentials.
+    pub quota_project_id: Option<String>,
+    /// The universe domain of the credentials, if known.
+    pub universe_domain: Option<String>,
+}
+
 /// Configuration for various authentication flows.
+#[derive(Clone)]
 pub struct CredentialConfig {
     /// The scopes that the minted [AccessToken] should have.
     scopes: Vec<String>,
+    /// The scopes used if `scopes` is empty.
+    default_scopes: Vec<String>,
+    /// The audience for self-signed JWTs, e.g.
+    /// `https://secretmanager.googleapis.com/`.
+    audience: Option<String>,
+    /// The HTTP client used to fetch tokens.
+    http_client: Option<reqwest::Client>,
 }
 
 impl CredentialConfig {
     pub fn builder() -> CredentialConfigBuilder {
         CredentialConfigBuilder::new()
     }
+
+    /// Returns the HTTP client used to fetch tokens.
+    fn http_client(&self) -> reqwest::Client {
+        self.http_client.clone().unwrap_or_default()
+    }
+
+    /// Returns the scopes for credentials that always need scopes.
+    fn effective_scopes(&self) -> Vec<String> {
+        if self.scopes.is_empty() {
+            self.default_scopes.clone()
+        } else {
+            self.scopes.clone()
+        }
+    }
+
+    /// Returns the scopes for service account keys.
+    ///
+    /// These use self-signed JWTs when there is an audience and the
+    /// application did not set any scopes, the default scopes are not used.
+    fn service_account_scopes(&self) -> Vec<String> {
+        if self.scopes.is_empty() && self.audience.is_some() {
+            Vec::new()
+        } else {
+            self.effective_scopes()
+        }
+    }
 }
 
 /// A builder for instantiating a [CredentialConfig].
 #[derive(Debug)]
 pub struct CredentialConfigBuilder {
     scopes: Vec<String>,
+    default_scopes: Vec<String>,
+    audience: Option<String>,
+    http_client: Option<reqwest::Client>,
 }
 
 impl CredentialConfigBuilder {
     /// Instantiates a new builder.
     pub fn new() -> Self {
-        Self { scopes: Vec::new() }
+        Self {
+            scopes: Vec::new(),
+            default_scopes: Vec::new(),
+            audience: None,
+            http_client: None,
+        }
     }
 
     /// Sets scopes used for credential authorization.
@@ -195,10 +278,47 @@ impl CredentialConfigBuilder {
         self
     }
 
+    /// Sets the scopes used if no scopes are set.
+    ///
+    /// Client libraries set the default scopes for their service, e.g.
+    /// `https://www.googleapis.com/auth/cloud-platform`. Unlike the scopes
+    /// set via [scopes][Self::scopes], the default scopes do not prevent
+    /// self-signed JWTs: service account keys with an audience ignore them.
+    pub fn default_scopes(mut self, value: Vec<String>) -> Self {
+        self.default_scopes = value;
+        self
+    }
+
+    /// Sets the audience used for self-signed JWTs.
+    ///
+    /// The audience is the service endpoint, e.g.
+    /// `https://secretmanager.googleapis.com/`. Service account keys with an
+    /// audience and no scopes produce self-signed JWTs, which avoids a round
+    /// trip to the token endpoint. If any scopes are set the credentials use
+    /// OAuth2 access tokens instead. The [default scopes][Self::default_scopes]
+    /// do not count.
+    pub fn audience(mut self, value: impl Into<String>) -> Self {
+        self.audience = Some(value.into());
+        self
+    }
+
+    /// Sets the HTTP client used to fetch tokens.
+    ///
+    /// Use this to send the token requests through the same proxy as the
+    /// rest of the application. By default the credentials use a client with
+    /// the default settings, which honors the proxy environment variables.
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http_client = Some(value);
+        self
+    }
+
     /// Builds a [CredentialConfig].
     pub fn build(self) -> Result<CredentialConfig> {
         Ok(CredentialConfig {
             scopes: self.scopes,
+            default_scopes: self.default_scopes,
+            audience: self.audience,
+            http_client: self.http_client,
         })
     }
 }
@@ -226,15 +346,98 @@ impl Credential {
     /// to figure out how a to produce a [AccessToken].
     pub async fn find_default(config: CredentialConfig) -> Result<Credential> {
         let base_source = Credential::base_source(config).await?;
-        let refreshed_source = RefresherSource {
-            source: base_source,
-            ..Default::default()
-        };
+        let refreshed_source = RefresherSource::new(base_source);
         Ok(Credential {
             source: Box::new(refreshed_source),
         })
     }
 
+    /// Creates a Credential that uses [Application Default Credentials](https://google.aip.dev/auth/4110)
+    /// without performing any I/O.
+    ///
+    /// Unlike [find_default][Credential::find_default], this function does
+    /// not read any files or probe the metadata server. The credentials are
+    /// found on first use, that is, the first call to
+    /// [access_token][Credential::access_token] or
+    /// [warmup][Credential::warmup]. Any errors finding the credentials are
+    /// reported by that call, and the search is repeated on the next call.
+    pub fn lazy_default(config: CredentialConfig) -> Credential {
+        Credential {
+            source: Box::new(LazySource::new(config)),
+        }
+    }
+
+    /// Returns the HTTP headers used to authenticate a request.
+    ///
+    /// Most credentials return an `Authorization` header with a bearer token.
+    /// Credentials created with [api_key][Credential::api_key] return an
+    /// `x-goog-api-key` header, and [anonymous][Credential::anonymous]
+    /// credentials return no headers.
+    pub async fn headers(&self) -> Result<http::HeaderMap> {
+        self.source.headers().await
+    }
+
+    /// Creates a Credential that authenticates requests with an [API key].
+    ///
+    /// API keys only work with some services, and only for some operations,
+    /// such as accessing public data. These credentials do not produce access
+    /// tokens, [access_token][Credential::access_token] returns an error.
+    ///
+    /// [API key]: https://cloud.google.com/docs/authentication/api-keys
+    pub fn api_key(api_key: impl Into<String>) -> Credential {
+        Credential {
+            source: Box::new(ApiKeySource::new(api_key.into())),
+        }
+    }
+
+    /// Creates a Credential that does not authenticate requests.
+    ///
+    /// This is useful to access public data, and to use emulators that do not
+    /// require authentication. These credentials do not produce access tokens,
+    /// [access_token][Credential::access_token] returns an error.
+    pub fn anonymous() -> Credential {
+        Credential {
+            source: Box::new(AnonymousSource),
+        }
+    }
+
+    /// Fetches a new [AccessToken], ignoring any cached token.
+    ///
+    /// Tokens are cached and refreshed automatically, most applications
+    /// never need to call this function. It is useful in tests, and to
+    /// recover from a token that was revoked before it expired.
+    pub async fn force_refresh(&self) -> Result<AccessToken> {
+        self.source.force_refresh().await
+    }
+
+    /// Finds the credentials, if needed, and fetches the first [AccessToken].
+    ///
+    /// Applications that prefer to pay the cost of finding the credentials
+    /// and fetching a token before the first request, and to detect
+    /// configuration problems early, can call this function at startup.
+    pub async fn warmup(&self) -> Result<()> {
+        self.access_token().await.map(|_| ())
+    }
+
+    /// Returns a short name for the type of credentials, e.g.
+    /// `service_account`, `authorized_user`, or `metadata_server`.
+    ///
+    /// Credentials created with [lazy_default][Credential::lazy_default]
+    /// return `application_default` until they are found.
+    pub fn source_type(&self) -> &'static str {
+        self.source.source_type()
+    }
+
+    /// Checks the credentials and summarizes their configuration.
+    ///
+    /// This fetches a token, so it fails if the credentials cannot be used
+    /// to authenticate. Credentials that do not use tokens, such as
+    /// [api_key][Credential::api_key] and [anonymous][Credential::anonymous]
+    /// credentials, only report their type.
+    pub async fn diagnose(&self) -> Result<Diagnostics> {
+        self.source.diagnose().await
+    }
+
     pub fn test_credentials() -> Self {
         Self {
             source: Box::new(source::TestSource),
@@ -258,9 +461,10 @@ impl Credential {
             }
         }
         // 3: Check if in an environment with on Google Cloud
-        if metadata::is_running_on_gce().await {
+        if metadata::is_running_on_gce(&config.http_client()).await {
             let source = ComputeSource::new(ComputeSourceConfig {
-                scopes: config.scopes,
+                scopes: config.effective_scopes(),
+                client: config.http_client(),
             });
             let source = Box::new(source);
             return Ok(source);
@@ -285,7 +489,8 @@ impl Credential {
                 let source = UserSource::from_file_contents(
                     &contents,
                     UserSourceConfig {
-                        scopes: config.scopes,
+                        scopes: config.effective_scopes(),
+                        client: config.http_client(),
                     },
                 )?;
                 Box::new(source)
@@ -294,7 +499,9 @@ impl Credential {
                 let source = ServiceAccountKeySource::from_file_contents(
                     &contents,
                     ServiceAccountKeySourceConfig {
-                        scopes: config.scopes,
+                        scopes: config.service_account_scopes(),
+                        audience: config.audience.clone(),
+                        client: config.http_client(),
                     },
                 )?;
                 Box::new(source)
@@ -355,6 +562,9 @@ mod tests {
         }
         let cred = Credential::find_default(crate::CredentialConfig {
             scopes: vec!["https://www.googleapis.com/auth/cloud-platform".into()],
+            default_scopes: Vec::new(),
+            audience: None,
+            http_client: None,
         })
         .await
         .unwrap();
@@ -362,4 +572,71 @@ mod tests {
         let tok2 = cred.access_token().await.unwrap();
         assert_eq!(tok1.value, tok2.value)
     }
+
+    #[test]
+    fn config_default_scopes() {
+        let default = vec!["default-scope".to_string()];
+        let config = crate::CredentialConfig::builder()
+            .default_scopes(default.clone())
+            .build()
+            .unwrap();
+        assert_eq!(config.effective_scopes(), default);
+        assert_eq!(config.service_account_scopes(), default);
+
+        let config = crate::CredentialConfig::builder()
+            .default_scopes(default.clone())
+            .audience("https://test-only.googleapis.com/")
+            .build()
+            .unwrap();
+        assert_eq!(config.effective_scopes(), default);
+        assert!(config.service_account_scopes().is_empty());
+
+        let scopes = vec!["scope".to_string()];
+        let config = crate::CredentialConfig::builder()
+            .scopes(scopes.clone())
+            .default_scopes(default)
+            .audience("https://test-only.googleapis.com/")
+            .build()
+            .unwrap();
+        assert_eq!(config.effective_scopes(), scopes);
+        assert_eq!(config.service_account_scopes(), scopes);
+    }
+
+    #[tokio::test]
+    async fn access_token_debug() {
+        let token = Credential::test_credentials().access_token().await.unwrap();
+        assert_eq!(token.value.expose(), "test-only");
+        let fmt = format!("{token:?}");
+        assert!(!fmt.contains("test-only"), "{fmt}");
+        assert!(fmt.contains("[censored]"), "{fmt}");
+    }
+
+    #[test]
+    fn test_source_type() {
+        let cred = Credential::test_credentials();
+        assert_eq!(cred.source_type(), "test");
+        let cred = Credential::api_key("test-only-api-key");
+        assert_eq!(cred.source_type(), "api_key");
+        let cred = Credential::anonymous();
+        assert_eq!(cred.source_type(), "anonymous");
+    }
+
+    #[tokio::test]
+    async fn diagnose() {
+        let got = Credential::test_credentials().diagnose().await.unwrap();
+        assert_eq!(
+            got,
+            crate::Diagnostics {
+                source_type: "test".to_string(),
+                ..Default::default()
+            }
+        );
+
+        let got = Credential::api_key("test-only-api-key")
+            .diagnose()
+            .await
+            .unwrap();
+        assert_eq!(got.source_type, "api_key");
+        assert!(!format!("{got:?}").contains("test-only-api-key"), "{got:?}");
+    }
 }
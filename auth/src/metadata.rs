@@ -19,49 +19,77 @@ use std::env;
 use tokio::time::{self, Duration};
 
 use super::{Error, ErrorKind, Result};
+use crate::secret::SecretString;
 
 const DEFAULT_ACCOUNT: &str = "default";
 const GCE_METADATA_HOST_ENV: &str = "GCE_METADATA_HOST";
 const DEFAULT_GCE_METADATA_HOST: &str = "169.254.169.254";
 const GCE_METADATA_HOST_DNS: &str = "metadata.google.internal";
+const KUBERNETES_SERVICE_HOST_ENV: &str = "KUBERNETES_SERVICE_HOST";
 
-fn new_metadata_client() -> Client {
+/// The headers sent with each request to the metadata server.
+fn metadata_headers() -> HeaderMap {
     let mut headers = HeaderMap::with_capacity(2);
     headers.insert("Metadata-Flavor", "Google".parse().unwrap());
     headers.insert("User-Agent", "gcloud-rust/0.1".parse().unwrap());
-    Client::builder().default_headers(headers).build().unwrap()
+    headers
 }
 
 /// Makes a request to the supplied metadata endpoint.
 #[allow(dead_code)]
-pub async fn get(suffix: impl Into<String>) -> Result<String> {
-    get_with_query::<()>(suffix.into(), None).await
+pub async fn get(client: &Client, suffix: impl Into<String>) -> Result<String> {
+    get_with_query::<()>(client, suffix.into(), None).await
 }
 
 async fn get_with_query<T: Serialize + ?Sized>(
+    client: &Client,
     suffix: String,
     query: Option<&T>,
 ) -> Result<String> {
     let host = env::var(GCE_METADATA_HOST_ENV)
         .unwrap_or_else(|_| -> String { String::from(DEFAULT_GCE_METADATA_HOST) });
-    let suffix = suffix.trim_start_matches('/');
+    get_from_host(client, &host, suffix.trim_start_matches('/'), query).await
+}
 
-    let client = new_metadata_client();
+async fn get_from_host<T: Serialize + ?Sized>(
+    client: &Client,
+    host: &str,
+    suffix: &str,
+    query: Option<&T>,
+) -> Result<String> {
     let content = backoff::future::retry(backoff::ExponentialBackoff::default(), || async {
         let url = format!("http://{}/computeMetadata/v1/{}", host, suffix);
-        let req = client.get(url);
+        let req = client.get(url).headers(metadata_headers());
         let req = if let Some(query) = query {
             req.query(query)
         } else {
             req
         };
         let res = req.send().await.map_err(Error::wrap_http)?;
-        if !res.status().is_success() {
-            return Err(backoff::Error::transient(Error::new(
-                format!("bad request with status: {}", res.status().as_str()),
-                crate::ErrorKind::Http,
+        if !is_metadata_response(res.headers()) {
+            return Err(backoff::Error::permanent(Error::new(
+                format!(
+                    "the response from {host} is missing the `Metadata-Flavor: Google` header, \
+                     a proxy or another service may be intercepting requests to the metadata server"
+                ),
+                ErrorKind::Http,
             )));
         }
+        let status = res.status();
+        if !status.is_success() {
+            let error = Error::new(
+                status_message(
+                    status,
+                    suffix,
+                    env::var(KUBERNETES_SERVICE_HOST_ENV).is_ok(),
+                ),
+                ErrorKind::Http,
+            );
+            if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                return Err(backoff::Error::transient(error));
+            }
+            return Err(backoff::Error::permanent(error));
+        }
         let content = res.text().await.map_err(Error::wrap_http)?;
         Ok(content)
     })
@@ -69,16 +97,52 @@ async fn get_with_query<T: Serialize + ?Sized>(
     Ok(content)
 }
 
+/// Returns true if the response comes from a metadata server.
+///
+/// Both the GCE and the GKE metadata servers include this header in all their
+/// responses, including errors.
+fn is_metadata_response(headers: &HeaderMap) -> bool {
+    headers
+        .get("Metadata-Flavor")
+        .is_some_and(|v| v.as_bytes() == b"Google")
+}
+
+/// Describes a metadata server error, with hints for common misconfigurations.
+fn status_message(status: reqwest::StatusCode, suffix: &str, in_kubernetes: bool) -> String {
+    let mut message = format!("the metadata server returned {status} for `{suffix}`");
+    if !suffix.starts_with("instance/service-accounts/") {
+        return message;
+    }
+    match status {
+        reqwest::StatusCode::NOT_FOUND if in_kubernetes => message.push_str(
+            ". With GKE workload identity, verify that the Kubernetes service account is \
+             annotated with `iam.gke.io/gcp-service-account`, or that it is granted IAM roles \
+             directly",
+        ),
+        reqwest::StatusCode::NOT_FOUND => {
+            message.push_str(". Verify that a service account is attached to the instance")
+        }
+        reqwest::StatusCode::FORBIDDEN if in_kubernetes => message.push_str(
+            ". With GKE workload identity, verify that the Kubernetes service account has the \
+             `roles/iam.workloadIdentityUser` role on the Google service account",
+        ),
+        _ => {}
+    }
+    message
+}
+
 /// Checks the environment to determine if code is executing in a Google Cloud
 /// environment.
-pub async fn is_running_on_gce() -> bool {
+pub async fn is_running_on_gce(client: &Client) -> bool {
     // If a user explicitly provides env var to talk to the metadata service we
     // trust them.
     if std::env::var(GCE_METADATA_HOST_ENV).is_ok() {
         return true;
     }
-    let client = new_metadata_client();
-    let res1 = client.get("http://169.254.169.254").send();
+    let res1 = client
+        .get("http://169.254.169.254")
+        .headers(metadata_headers())
+        .send();
     let res2 = tokio::net::TcpListener::bind((GCE_METADATA_HOST_DNS, 0));
 
     // Race pinging the metadata service by IP and DNS. Depending on the
@@ -99,17 +163,21 @@ pub async fn is_running_on_gce() -> bool {
 /// The result of requesting a token from the metadata service.
 #[derive(Deserialize)]
 pub struct Token {
-    pub access_token: String,
+    pub access_token: SecretString,
     pub expires_in: i64,
 }
 
 /// Fetches a [AccessToken] from the metadata service with the provided scopes. If an
 /// account is not provided the value will be set to `default`.
-pub async fn fetch_access_token(account: Option<&str>, scopes: Vec<String>) -> Result<Token> {
+pub async fn fetch_access_token(
+    client: &Client,
+    account: Option<&str>,
+    scopes: Vec<String>,
+) -> Result<Token> {
     if scopes.is_empty() {
         return Err(Error::new("scopes must be provided", ErrorKind::Validation));
     }
-    if !is_running_on_gce().await {
+    if !is_running_on_gce(client).await {
         return Err(Error::new(
             "can't get token from metadata service, not running on GCE",
             ErrorKind::Validation,
@@ -118,10 +186,10 @@ pub async fn fetch_access_token(account: Option<&str>, scopes: Vec<String>) -> R
     let account = account.unwrap_or(DEFAULT_ACCOUNT);
     let suffix = format!("instance/service-accounts/{}/token", account);
     let query = &[("scopes", scopes.join(","))];
-    let json = get_with_query(suffix, Some(query)).await?;
+    let json = get_with_query(client, suffix, Some(query)).await?;
     let token_response: Token =
         serde_json::from_str(json.as_str()).map_err(Error::wrap_serialization)?;
-    if token_response.expires_in == 0 || token_response.access_token.is_empty() {
+    if token_response.expires_in == 0 || token_response.access_token.expose().is_empty() {
         return Err(Error::new(
             "incomplete token received from metadata",
             ErrorKind::Validation,
@@ -129,3 +197,102 @@ pub async fn fetch_access_token(account: Option<&str>, scopes: Vec<String>) -> R
     }
     Ok(token_response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    async fn start(flavor: bool, status: StatusCode) -> String {
+        start_counting(flavor, status).await.0
+    }
+
+    /// Starts a fake metadata server that counts the requests it receives.
+    async fn start_counting(flavor: bool, status: StatusCode) -> (String, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        let handler = move || async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let body = "test-only-content";
+            if flavor {
+                (status, [("Metadata-Flavor", "Google")], body).into_response()
+            } else {
+                (status, body).into_response()
+            }
+        };
+        let app = axum::Router::new().fallback(handler);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (addr.to_string(), count)
+    }
+
+    #[tokio::test]
+    async fn get_success() {
+        let host = start(true, StatusCode::OK).await;
+        let got = get_from_host::<()>(&Client::new(), &host, "project/project-id", None)
+            .await
+            .unwrap();
+        assert_eq!(got, "test-only-content");
+    }
+
+    #[tokio::test]
+    async fn get_intercepted() {
+        let host = start(false, StatusCode::OK).await;
+        let err = get_from_host::<()>(&Client::new(), &host, "project/project-id", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Http);
+        assert!(err.to_string().contains("Metadata-Flavor"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn get_not_found_is_permanent() {
+        let (host, count) = start_counting(true, StatusCode::NOT_FOUND).await;
+        let suffix = "instance/service-accounts/default/token";
+        let err = get_from_host::<()>(&Client::new(), &host, suffix, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Http);
+        assert!(err.to_string().contains("404"), "{err}");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn get_forbidden_is_permanent() {
+        let (host, count) = start_counting(true, StatusCode::FORBIDDEN).await;
+        let suffix = "instance/service-accounts/default/token";
+        let err = get_from_host::<()>(&Client::new(), &host, suffix, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Http);
+        assert!(err.to_string().contains("403"), "{err}");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn status_messages() {
+        let suffix = "instance/service-accounts/default/token";
+        let got = status_message(StatusCode::NOT_FOUND, suffix, true);
+        assert!(got.contains("iam.gke.io/gcp-service-account"), "{got}");
+        let got = status_message(StatusCode::NOT_FOUND, suffix, false);
+        assert!(got.contains("attached to the instance"), "{got}");
+        let got = status_message(StatusCode::FORBIDDEN, suffix, true);
+        assert!(got.contains("roles/iam.workloadIdentityUser"), "{got}");
+        let got = status_message(StatusCode::FORBIDDEN, suffix, false);
+        assert_eq!(
+            got,
+            format!("the metadata server returned 403 Forbidden for `{suffix}`")
+        );
+        let got = status_message(StatusCode::NOT_FOUND, "project/project-id", true);
+        assert_eq!(
+            got,
+            "the metadata server returned 404 Not Found for `project/project-id`"
+        );
+    }
+}
@@ -36,7 +36,7 @@ impl JwsClaims<'_> {
         let now = Utc::now() - chrono::Duration::seconds(10);
         self.iat = self.iat.or_else(|| Some(now.timestamp()));
         self.exp = self
-            .iat
+            .exp
             .or_else(|| Some((now + chrono::Duration::hours(1)).timestamp()));
         if self.exp.unwrap() < self.iat.unwrap() {
             return Err(Error::new(
@@ -67,3 +67,59 @@ impl JwsHeader<'_> {
         Ok(BASE64_URL_SAFE_NO_PAD.encode(json.as_bytes()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::prelude::{Engine as _, BASE64_URL_SAFE_NO_PAD};
+
+    fn claims() -> JwsClaims<'static> {
+        JwsClaims {
+            iss: "test-only-iss",
+            scope: None,
+            aud: "test-only-aud",
+            exp: None,
+            iat: None,
+            typ: None,
+            sub: None,
+        }
+    }
+
+    fn decode(encoded: &str) -> serde_json::Value {
+        let json = BASE64_URL_SAFE_NO_PAD.decode(encoded).unwrap();
+        serde_json::from_slice(&json).unwrap()
+    }
+
+    #[test]
+    fn encode_default_exp() {
+        let mut claims = claims();
+        let encoded = decode(&claims.encode().unwrap());
+        let iat = claims.iat.unwrap();
+        assert_eq!(claims.exp, Some(iat + 3600));
+        assert_eq!(encoded["iat"], iat);
+        assert_eq!(encoded["exp"], iat + 3600);
+    }
+
+    #[test]
+    fn encode_explicit_times() {
+        let mut claims = JwsClaims {
+            iat: Some(1000),
+            exp: Some(2000),
+            ..claims()
+        };
+        let encoded = decode(&claims.encode().unwrap());
+        assert_eq!(encoded["iat"], 1000);
+        assert_eq!(encoded["exp"], 2000);
+    }
+
+    #[test]
+    fn encode_exp_before_iat() {
+        let mut claims = JwsClaims {
+            iat: Some(2000),
+            exp: Some(1000),
+            ..claims()
+        };
+        let err = claims.encode().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zeroize::Zeroizing;
+
+/// A string containing secret material, such as an access token.
+///
+/// The contents are overwritten with zeros when the value is dropped, and
+/// they are not included in the [Debug] output. Use [SecretString::expose]
+/// to access the contents.
+///
+/// # Example
+/// ```
+/// # use google_cloud_auth::secret::SecretString;
+/// let secret = SecretString::from("my-secret");
+/// assert_eq!(secret.expose(), "my-secret");
+/// assert_eq!(format!("{secret:?}"), "[censored]");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Creates a new secret string.
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// Returns the contents of the secret.
+    ///
+    /// Avoid copying the result, copies are not zeroed when dropped.
+    pub fn expose(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[censored]")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for SecretString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.expose())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SecretString {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        let secret = SecretString::from("test-secret");
+        assert_eq!(format!("{secret:?}"), "[censored]");
+        assert_eq!(format!("{:?}", Some(secret)), "Some([censored])");
+    }
+
+    #[test]
+    fn expose() {
+        let secret = SecretString::new("test-secret".to_string());
+        assert_eq!(secret.expose(), "test-secret");
+        assert_eq!(secret.clone(), secret);
+        assert_eq!(SecretString::default().expose(), "");
+    }
+
+    #[test]
+    fn serde() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let secret = serde_json::from_value::<SecretString>(serde_json::json!("test-secret"))?;
+        assert_eq!(secret.expose(), "test-secret");
+        let json = serde_json::to_value(&secret)?;
+        assert_eq!(json, serde_json::json!("test-secret"));
+        Ok(())
+    }
+}
@@ -117,6 +117,27 @@ type Method struct {
 	// set to true.
 	ClientSideStreaming bool
 	ServerSideStreaming bool
+	// IsPreview is true if the method is only visible to preview users, as
+	// indicated by the `google.api.method_visibility` annotation.
+	IsPreview bool
+	// Routing describes how to compute the `x-goog-request-params` header.
+	Routing []*RoutingInfo
+}
+
+// RoutingInfo describes one entry in the `x-goog-request-params` header.
+//
+// See [AIP-4222](https://google.aip.dev/client-libraries/4222) for details.
+type RoutingInfo struct {
+	// The request field used to compute the value. This may be a nested field,
+	// such as `secret.name`.
+	FieldPath string
+	// The template used to extract the value from the field, for example,
+	// `{routing_id=projects/*}/**`. The name of the template variable is the
+	// key in the header.
+	//
+	// If empty, the full value of the field is used, and the field path is the
+	// key in the header.
+	PathTemplate string
 }
 
 // Normalized request path information.
@@ -142,6 +163,13 @@ type PathInfo struct {
 	//
 	// If this is empty then the body is not used.
 	BodyFieldPath string
+	// AdditionalBindings are the alternative bindings in the
+	// `additional_bindings` field of the `google.api.http` annotation.
+	//
+	// The client uses the first binding, starting with this one, where the
+	// request fields match the path templates. Additional bindings never have
+	// additional bindings of their own.
+	AdditionalBindings []*PathInfo
 }
 
 // A path segment is either a string literal (such as "projects") or a field
@@ -170,6 +198,9 @@ type PathSegment struct {
 	Literal   *string
 	FieldPath *string
 	Verb      *string
+	// Template is the path template for a field path segment, such as
+	// `projects/*/secrets/*`. If nil, the field must match a single segment.
+	Template *string
 }
 
 func NewLiteralPathSegment(s string) PathSegment {
@@ -180,6 +211,12 @@ func NewFieldPathPathSegment(s string) PathSegment {
 	return PathSegment{FieldPath: &s}
 }
 
+// WithTemplate returns a copy of a field path segment with the given template.
+func (s PathSegment) WithTemplate(t string) PathSegment {
+	s.Template = &t
+	return s
+}
+
 func NewVerbPathSegment(s string) PathSegment {
 	return PathSegment{Verb: &s}
 }
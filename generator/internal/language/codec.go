@@ -82,6 +82,13 @@ type Codec interface {
 	// should be used in conjunction with HTTPPathFmt. An example return value
 	// might be `, req.PathParam()`
 	HTTPPathArgs(h *api.PathInfo, state *api.APIState) []string
+	// HTTPPathMatch returns an expression that is true if the request fields
+	// match the path templates in `h`. The generated code uses this
+	// expression to select between the primary and additional HTTP bindings.
+	HTTPPathMatch(m *api.Method, h *api.PathInfo, state *api.APIState) string
+	// RoutingParams returns a string representation of each routing
+	// parameter, used to compute the `x-goog-request-params` header.
+	RoutingParams(m *api.Method, state *api.APIState) []string
 	// ToSnake converts a symbol name to `snake_case`, applying any mangling
 	// required by the language, e.g., to avoid clashes with reserved words.
 	ToSnake(string) string
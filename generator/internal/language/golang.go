@@ -261,6 +261,16 @@ func (c *GoCodec) HTTPPathArgs(h *api.PathInfo, state *api.APIState) []string {
 	return args
 }
 
+func (c *GoCodec) HTTPPathMatch(m *api.Method, h *api.PathInfo, state *api.APIState) string {
+	// TODO(#34) - support additional bindings in the Go client
+	return ""
+}
+
+func (c *GoCodec) RoutingParams(m *api.Method, state *api.APIState) []string {
+	// TODO(#34) - add routing headers to the Go client
+	return nil
+}
+
 func (c *GoCodec) ToSnake(symbol string) string {
 	return goEscapeKeyword(c.ToSnakeNoMangling(symbol))
 }
@@ -20,6 +20,7 @@ import (
 	"log/slog"
 	"path"
 	"regexp"
+	"slices"
 	"sort"
 	"strconv"
 	"strings"
@@ -271,6 +272,21 @@ func (c *RustCodec) LoadWellKnownTypes(s *api.APIState) {
 			Name:    "Timestamp",
 			Package: "google.protobuf",
 		},
+		{
+			ID:      ".google.protobuf.Struct",
+			Name:    "Struct",
+			Package: "google.protobuf",
+		},
+		{
+			ID:      ".google.protobuf.Value",
+			Name:    "Value",
+			Package: "google.protobuf",
+		},
+		{
+			ID:      ".google.protobuf.ListValue",
+			Name:    "ListValue",
+			Package: "google.protobuf",
+		},
 	}
 	for _, message := range wellKnown {
 		s.MessageByID[message.ID] = message
@@ -333,7 +349,7 @@ func (c *RustCodec) fieldFormatter(typez api.Typez) string {
 		api.SINT64_TYPE:
 		return "serde_with::DisplayFromStr"
 	case api.BYTES_TYPE:
-		return "serde_with::base64::Base64"
+		return c.rustPackage("google.protobuf") + "::internal::Base64"
 	default:
 		return "_"
 	}
@@ -700,6 +716,77 @@ func (c *RustCodec) derefFieldPath(fieldPath string) string {
 	return unwrap
 }
 
+func (c *RustCodec) HTTPPathMatch(m *api.Method, h *api.PathInfo, state *api.APIState) string {
+	var conditions []string
+	for _, segment := range h.PathTemplate {
+		if segment.FieldPath == nil {
+			continue
+		}
+		template := "*"
+		if segment.Template != nil {
+			template = *segment.Template
+		}
+		value := c.routingValue(m, *segment.FieldPath, state)
+		conditions = append(conditions, fmt.Sprintf(`gax::path_parameter::matches(%s, "%s")`, value, template))
+	}
+	if len(conditions) == 0 {
+		return "true"
+	}
+	return strings.Join(conditions, " && ")
+}
+
+func (c *RustCodec) RoutingParams(m *api.Method, state *api.APIState) []string {
+	var params []string
+	for _, r := range m.Routing {
+		value := c.routingValue(m, r.FieldPath, state)
+		if r.PathTemplate == "" {
+			params = append(params, fmt.Sprintf(`.add("%s", %s)`, r.FieldPath, value))
+		} else {
+			params = append(params, fmt.Sprintf(`.add_matching(%s, "%s")`, value, r.PathTemplate))
+		}
+	}
+	return params
+}
+
+// Returns an expression to get the value of a routing parameter, or any other
+// field used in the HTTP path, as a `&str`.
+func (c *RustCodec) routingValue(m *api.Method, fieldPath string, state *api.APIState) string {
+	value := c.derefFieldPath(fieldPath)
+	field := lookupFieldPath(m.InputTypeID, fieldPath, state)
+	if field == nil {
+		slog.Error("unable to lookup routing field", "method", m.ID, "field", fieldPath)
+		return "&" + value
+	}
+	switch {
+	case field.Typez == api.STRING_TYPE && field.Optional:
+		return value + ".as_deref().unwrap_or_default()"
+	case field.Typez == api.STRING_TYPE:
+		return "&" + value
+	case field.Optional:
+		return fmt.Sprintf("&%s.map(|v| v.to_string()).unwrap_or_default()", value)
+	default:
+		return fmt.Sprintf("&%s.to_string()", value)
+	}
+}
+
+// Returns the field at the end of a (possibly nested) field path.
+func lookupFieldPath(messageID, fieldPath string, state *api.APIState) *api.Field {
+	var field *api.Field
+	for _, name := range strings.Split(fieldPath, ".") {
+		message, ok := state.MessageByID[messageID]
+		if !ok {
+			return nil
+		}
+		idx := slices.IndexFunc(message.Fields, func(f *api.Field) bool { return f.Name == name })
+		if idx == -1 {
+			return nil
+		}
+		field = message.Fields[idx]
+		messageID = field.TypezID
+	}
+	return field
+}
+
 func (c *RustCodec) HTTPPathArgs(h *api.PathInfo, state *api.APIState) []string {
 	var args []string
 	for _, arg := range h.PathTemplate {
@@ -1099,11 +1186,15 @@ func (c *RustCodec) Validate(api *api.API) error {
 type RustContext struct {
 	HasFeatures bool
 	Features    []string
+	// HasPreviewFeature is true if some methods are gated by the `preview`
+	// feature.
+	HasPreviewFeature bool
 }
 
 func (c *RustCodec) AdditionalContext(api *api.API) any {
 	rustContext := &RustContext{}
 	c.addStreamingFeature(rustContext, api)
+	c.addPreviewFeature(rustContext, api)
 	return rustContext
 }
 
@@ -1134,6 +1225,27 @@ func (c *RustCodec) addStreamingFeature(rustContext *RustContext, api *api.API)
 	rustContext.HasFeatures = true
 }
 
+// addPreviewFeature adds a `preview` feature if any of the generated methods
+// is only visible to preview users. Applications must opt into such methods,
+// as they may change or disappear without notice.
+func (c *RustCodec) addPreviewFeature(rustContext *RustContext, api *api.API) {
+	var hasPreviewRPC bool
+	for _, s := range api.Services {
+		for _, m := range s.Methods {
+			if m.IsPreview && c.GenerateMethod(m) {
+				hasPreviewRPC = true
+				break
+			}
+		}
+	}
+	if !hasPreviewRPC {
+		return
+	}
+	rustContext.Features = append(rustContext.Features, "preview = []")
+	rustContext.HasFeatures = true
+	rustContext.HasPreviewFeature = true
+}
+
 func (c *RustCodec) Imports() []string {
 	return nil
 }
@@ -16,6 +16,7 @@ package language
 
 import (
 	"fmt"
+	"slices"
 	"strings"
 	"testing"
 
@@ -222,11 +223,43 @@ func TestRust_ValidateMessageMismatch(t *testing.T) {
 	}
 }
 
+func TestRust_PreviewFeature(t *testing.T) {
+	pathInfo := &api.PathInfo{
+		Verb:         "GET",
+		PathTemplate: []api.PathSegment{api.NewLiteralPathSegment("v1")},
+	}
+	test := newTestAPI(
+		[]*api.Message{},
+		[]*api.Enum{},
+		[]*api.Service{{
+			Name:    "s1",
+			Package: "p1",
+			Methods: []*api.Method{
+				{Name: "GA", PathInfo: pathInfo},
+				{Name: "Preview", PathInfo: pathInfo, IsPreview: true},
+			},
+		}})
+	c := &RustCodec{}
+	got := c.AdditionalContext(test).(*RustContext)
+	if !got.HasPreviewFeature || !got.HasFeatures {
+		t.Errorf("expected a preview feature, got=%v", got)
+	}
+	if !slices.Contains(got.Features, "preview = []") {
+		t.Errorf("missing preview feature in %v", got.Features)
+	}
+
+	test.Services[0].Methods[1].IsPreview = false
+	got = c.AdditionalContext(test).(*RustContext)
+	if got.HasPreviewFeature {
+		t.Errorf("unexpected preview feature, got=%v", got)
+	}
+}
+
 func TestWellKnownTypesExist(t *testing.T) {
 	api := newTestAPI([]*api.Message{}, []*api.Enum{}, []*api.Service{})
 	c := &RustCodec{}
 	c.LoadWellKnownTypes(api.State)
-	for _, name := range []string{"Any", "Duration", "Empty", "FieldMask", "Timestamp"} {
+	for _, name := range []string{"Any", "Duration", "Empty", "FieldMask", "Timestamp", "Struct", "Value", "ListValue"} {
 		if _, ok := api.State.MessageByID[fmt.Sprintf(".google.protobuf.%s", name)]; !ok {
 			t.Errorf("cannot find well-known message %s in API", name)
 		}
@@ -351,9 +384,9 @@ func TestRust_FieldAttributes(t *testing.T) {
 		"f_int64_optional": `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::DisplayFromStr>")]`,
 		"f_int64_repeated": `#[serde(skip_serializing_if = "Vec::is_empty")]` + "\n" + `#[serde_as(as = "Vec<serde_with::DisplayFromStr>")]`,
 
-		"f_bytes":          `#[serde(skip_serializing_if = "bytes::Bytes::is_empty")]` + "\n" + `#[serde_as(as = "serde_with::base64::Base64")]`,
-		"f_bytes_optional": `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::base64::Base64>")]`,
-		"f_bytes_repeated": `#[serde(skip_serializing_if = "Vec::is_empty")]` + "\n" + `#[serde_as(as = "Vec<serde_with::base64::Base64>")]`,
+		"f_bytes":          `#[serde(skip_serializing_if = "bytes::Bytes::is_empty")]` + "\n" + `#[serde_as(as = "gax_wkt::internal::Base64")]`,
+		"f_bytes_optional": `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<gax_wkt::internal::Base64>")]`,
+		"f_bytes_repeated": `#[serde(skip_serializing_if = "Vec::is_empty")]` + "\n" + `#[serde_as(as = "Vec<gax_wkt::internal::Base64>")]`,
 
 		"f_string":          `#[serde(skip_serializing_if = "String::is_empty")]`,
 		"f_string_optional": `#[serde(skip_serializing_if = "Option::is_none")]`,
@@ -485,7 +518,7 @@ func TestRust_MapFieldAttributes(t *testing.T) {
 		"map":         `#[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]`,
 		"map_i64":     `#[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]` + "\n" + `#[serde_as(as = "std::collections::HashMap<_, serde_with::DisplayFromStr>")]`,
 		"map_i64_key": `#[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]` + "\n" + `#[serde_as(as = "std::collections::HashMap<serde_with::DisplayFromStr, _>")]`,
-		"map_bytes":   `#[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]` + "\n" + `#[serde_as(as = "std::collections::HashMap<_, serde_with::base64::Base64>")]`,
+		"map_bytes":   `#[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]` + "\n" + `#[serde_as(as = "std::collections::HashMap<_, gax_wkt::internal::Base64>")]`,
 	}
 	c := createRustCodec()
 	c.LoadWellKnownTypes(api.State)
@@ -556,7 +589,7 @@ func TestRust_WktFieldAttributes(t *testing.T) {
 	expectedAttributes := map[string]string{
 		"f_int64":        `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::DisplayFromStr>")]`,
 		"f_uint64":       `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::DisplayFromStr>")]`,
-		"f_bytes":        `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::base64::Base64>")]`,
+		"f_bytes":        `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<gax_wkt::internal::Base64>")]`,
 		"f_string":       `#[serde(skip_serializing_if = "Option::is_none")]`,
 		"f_repeated_any": `#[serde(skip_serializing_if = "Vec::is_empty")]`,
 		"f_any":          `#[serde(skip_serializing_if = "Option::is_none")]`,
@@ -601,7 +634,7 @@ func TestRust_FieldLossyName(t *testing.T) {
 	api := newTestAPI([]*api.Message{message}, []*api.Enum{}, []*api.Service{})
 
 	expectedAttributes := map[string]string{
-		"data":       `#[serde(skip_serializing_if = "bytes::Bytes::is_empty")]` + "\n" + `#[serde_as(as = "serde_with::base64::Base64")]`,
+		"data":       `#[serde(skip_serializing_if = "bytes::Bytes::is_empty")]` + "\n" + `#[serde_as(as = "gax_wkt::internal::Base64")]`,
 		"dataCrc32c": `#[serde(rename = "dataCrc32c")]` + "\n" + `#[serde(skip_serializing_if = "Option::is_none")]` + "\n" + `#[serde_as(as = "Option<serde_with::DisplayFromStr>")]`,
 	}
 	c := createRustCodec()
@@ -811,6 +844,107 @@ func TestRust_AsQueryParameter(t *testing.T) {
 
 }
 
+func TestRust_RoutingParams(t *testing.T) {
+	secret := &api.Message{
+		Name: "Secret",
+		ID:   "..Secret",
+		Fields: []*api.Field{
+			{Name: "name", Typez: api.STRING_TYPE},
+		},
+	}
+	request := &api.Message{
+		Name: "TestRequest",
+		ID:   "..TestRequest",
+		Fields: []*api.Field{
+			{Name: "table_name", Typez: api.STRING_TYPE},
+			{Name: "app_profile_id", Typez: api.STRING_TYPE, Optional: true},
+			{Name: "project_number", Typez: api.INT64_TYPE},
+			{Name: "secret", Typez: api.MESSAGE_TYPE, TypezID: secret.ID, Optional: true},
+		},
+	}
+	method := &api.Method{
+		Name:        "Test",
+		ID:          "..Service.Test",
+		InputTypeID: request.ID,
+		Routing: []*api.RoutingInfo{
+			{FieldPath: "table_name", PathTemplate: "{routing_id=projects/*}/**"},
+			{FieldPath: "app_profile_id"},
+			{FieldPath: "project_number"},
+			{FieldPath: "secret.name"},
+		},
+	}
+	test := newTestAPI([]*api.Message{secret, request}, []*api.Enum{}, []*api.Service{})
+	c := createRustCodec()
+	want := []string{
+		`.add_matching(&req.table_name, "{routing_id=projects/*}/**")`,
+		`.add("app_profile_id", req.app_profile_id.as_deref().unwrap_or_default())`,
+		`.add("project_number", &req.project_number.to_string())`,
+		`.add("secret.name", &gax::path_parameter::PathParameter::required(&req.secret, "secret").map_err(Error::other)?.name)`,
+	}
+	got := c.RoutingParams(method, test.State)
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("mismatched routing parameters (-want, +got):\n%s", diff)
+	}
+}
+
+func TestRust_HTTPPathMatch(t *testing.T) {
+	secret := &api.Message{
+		Name: "Secret",
+		ID:   "..Secret",
+		Fields: []*api.Field{
+			{Name: "name", Typez: api.STRING_TYPE},
+		},
+	}
+	request := &api.Message{
+		Name: "TestRequest",
+		ID:   "..TestRequest",
+		Fields: []*api.Field{
+			{Name: "parent", Typez: api.STRING_TYPE},
+			{Name: "secret", Typez: api.MESSAGE_TYPE, TypezID: secret.ID, Optional: true},
+		},
+	}
+	method := &api.Method{
+		Name:        "Test",
+		ID:          "..Service.Test",
+		InputTypeID: request.ID,
+	}
+	test := newTestAPI([]*api.Message{secret, request}, []*api.Enum{}, []*api.Service{})
+	c := createRustCodec()
+	for _, tc := range []struct {
+		pathInfo *api.PathInfo
+		want     string
+	}{
+		{
+			&api.PathInfo{PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewFieldPathPathSegment("parent").WithTemplate("projects/*/locations/*"),
+				api.NewLiteralPathSegment("secrets"),
+			}},
+			`gax::path_parameter::matches(&req.parent, "projects/*/locations/*")`,
+		},
+		{
+			&api.PathInfo{PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewFieldPathPathSegment("parent"),
+				api.NewFieldPathPathSegment("secret.name").WithTemplate("secrets/*"),
+			}},
+			`gax::path_parameter::matches(&req.parent, "*") && gax::path_parameter::matches(&gax::path_parameter::PathParameter::required(&req.secret, "secret").map_err(Error::other)?.name, "secrets/*")`,
+		},
+		{
+			&api.PathInfo{PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewLiteralPathSegment("secrets"),
+			}},
+			"true",
+		},
+	} {
+		got := c.HTTPPathMatch(method, tc.pathInfo, test.State)
+		if tc.want != got {
+			t.Errorf("mismatched path match, want=%s, got=%s", tc.want, got)
+		}
+	}
+}
+
 type rustCaseConvertTest struct {
 	Input    string
 	Expected string
@@ -87,7 +87,7 @@ func Parse(pathTemplate string) (*PathTemplate, error) {
 }
 
 // ParseSegments flattens the result of Parse into a slice of api.PathSegment,
-// ignoring variable values and match (* and **) segments.
+// ignoring match (* and **) segments outside variables.
 // TODO(#557): This function is a temporary shim to allow the existing tests to pass.
 func ParseSegments(pathTemplate string) ([]api.PathSegment, error) {
 	path, err := parsePathTemplate(pathTemplate)
@@ -107,6 +107,10 @@ func ParseSegments(pathTemplate string) ([]api.PathSegment, error) {
 			}
 			fieldPath := strings.Join(ids, ".")
 			segment.FieldPath = &fieldPath
+			if len(s.Variable.Segments) != 0 {
+				template := segmentsTemplate(s.Variable.Segments)
+				segment.Template = &template
+			}
 		}
 		segments = append(segments, segment)
 	}
@@ -120,6 +124,23 @@ func ParseSegments(pathTemplate string) ([]api.PathSegment, error) {
 	return segments, nil
 }
 
+// segmentsTemplate formats the segments of a variable, such as
+// `projects/*/secrets/*`.
+func segmentsTemplate(segments []*Segment) string {
+	var parts []string
+	for _, s := range segments {
+		switch {
+		case s.Literal != nil:
+			parts = append(parts, string(*s.Literal))
+		case s.Match != nil:
+			parts = append(parts, "*")
+		case s.MatchRecursive != nil:
+			parts = append(parts, "**")
+		}
+	}
+	return strings.Join(parts, "/")
+}
+
 // PathTemplate represents the structure in Go.
 type PathTemplate struct {
 	Segments []*Segment
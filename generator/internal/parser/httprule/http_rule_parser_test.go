@@ -17,6 +17,7 @@ package httprule
 import (
 	"github.com/google/go-cmp/cmp"
 	"github.com/google/go-cmp/cmp/cmpopts"
+	"github.com/googleapis/google-cloud-rust/generator/internal/api"
 	"testing"
 )
 
@@ -85,6 +86,50 @@ func TestProtobuf_Parse(t *testing.T) {
 	}
 }
 
+func TestProtobuf_ParseSegments(t *testing.T) {
+	tests := []struct {
+		path string
+		want []api.PathSegment
+	}{
+		{"/v1/{name}", []api.PathSegment{
+			api.NewLiteralPathSegment("v1"),
+			api.NewFieldPathPathSegment("name"),
+		}},
+		{"/v1/{name=projects/*/secrets/*}:access", []api.PathSegment{
+			api.NewLiteralPathSegment("v1"),
+			api.NewFieldPathPathSegment("name").WithTemplate("projects/*/secrets/*"),
+			api.NewVerbPathSegment("access"),
+		}},
+		{"/v1/{secret.name=projects/*/locations/*/secrets/*}", []api.PathSegment{
+			api.NewLiteralPathSegment("v1"),
+			api.NewFieldPathPathSegment("secret.name").WithTemplate("projects/*/locations/*/secrets/*"),
+		}},
+		{"/v1/{resource=**}:getIamPolicy", []api.PathSegment{
+			api.NewLiteralPathSegment("v1"),
+			api.NewFieldPathPathSegment("resource").WithTemplate("**"),
+			api.NewVerbPathSegment("getIamPolicy"),
+		}},
+		{"/v1/{name=locations}/{parent}/locations", []api.PathSegment{
+			api.NewLiteralPathSegment("v1"),
+			api.NewFieldPathPathSegment("name").WithTemplate("locations"),
+			api.NewFieldPathPathSegment("parent"),
+			api.NewLiteralPathSegment("locations"),
+		}},
+	}
+
+	for _, tc := range tests {
+		t.Run(tc.path, func(t *testing.T) {
+			got, err := ParseSegments(tc.path)
+			if err != nil {
+				t.Fatalf("expected no error, got: %v", err)
+			}
+			if diff := cmp.Diff(tc.want, got); diff != "" {
+				t.Errorf("failed parsing path [%s] (-want, +got):\n%s", tc.path, diff)
+			}
+		})
+	}
+}
+
 func expectTemplate() *PathTemplate {
 	return &PathTemplate{}
 }
@@ -95,6 +95,9 @@ func updateMixinState(serviceConfig *serviceconfig.Service, api *api.API) {
 			continue
 		}
 		m.PathInfo = pathInfo
+		// Mixin methods do not have `google.api.routing` annotations, the
+		// routing parameters are the fields in the new HTTP path.
+		m.Routing = implicitRouting(pathInfo)
 	}
 
 	// Include any documentation from the Service config.
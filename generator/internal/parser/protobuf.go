@@ -450,6 +450,8 @@ func processMethod(state *api.APIState, m *descriptorpb.MethodDescriptorProto, m
 		OutputTypeID:        m.GetOutputType(),
 		ClientSideStreaming: m.GetClientStreaming(),
 		ServerSideStreaming: m.GetServerStreaming(),
+		IsPreview:           parseIsPreview(m),
+		Routing:             parseRoutingInfo(m, pathInfo),
 	}
 	state.MethodByID[mFQN] = method
 	return method
@@ -19,9 +19,11 @@ import (
 	"github.com/googleapis/google-cloud-rust/generator/internal/api"
 	"github.com/googleapis/google-cloud-rust/generator/internal/parser/httprule"
 	"google.golang.org/genproto/googleapis/api/annotations"
+	"google.golang.org/genproto/googleapis/api/visibility"
 	"google.golang.org/protobuf/proto"
 	"google.golang.org/protobuf/types/descriptorpb"
 	"log/slog"
+	"strings"
 )
 
 func parsePathInfo(m *descriptorpb.MethodDescriptorProto, state *api.APIState) (*api.PathInfo, error) {
@@ -49,6 +51,9 @@ func processRule(httpRule *annotations.HttpRule, state *api.APIState, mID string
 	case *annotations.HttpRule_Patch:
 		verb = "PATCH"
 		rawPath = httpRule.GetPatch()
+	case *annotations.HttpRule_Custom:
+		verb = strings.ToUpper(httpRule.GetCustom().GetKind())
+		rawPath = httpRule.GetCustom().GetPath()
 	default:
 		// Most often this happens with streaming RPCs. We will handle any
 		/// errors later in the code generation, maybe by ignoring the RPC.
@@ -68,12 +73,23 @@ func processRule(httpRule *annotations.HttpRule, state *api.APIState, mID string
 		return nil, err
 	}
 
-	return &api.PathInfo{
+	pathInfo := &api.PathInfo{
 		Verb:            verb,
 		PathTemplate:    pathTemplate,
 		QueryParameters: queryParameters,
 		BodyFieldPath:   httpRule.GetBody(),
-	}, nil
+	}
+	for _, binding := range httpRule.GetAdditionalBindings() {
+		if len(binding.GetAdditionalBindings()) != 0 {
+			return nil, fmt.Errorf("nested additional bindings are not allowed in %s", mID)
+		}
+		additional, err := processRule(binding, state, mID)
+		if err != nil {
+			return nil, err
+		}
+		pathInfo.AdditionalBindings = append(pathInfo.AdditionalBindings, additional)
+	}
+	return pathInfo, nil
 }
 
 func queryParameters(msgID string, pathTemplate []api.PathSegment, body string, state *api.APIState) (map[string]bool, error) {
@@ -109,3 +125,54 @@ func parseDefaultHost(m proto.Message) string {
 	}
 	return defaultHost
 }
+
+// Returns the routing parameters for a method.
+//
+// If the method has a `google.api.routing` annotation the routing parameters
+// are taken from it. Otherwise, each field in the HTTP path is a routing
+// parameter, as described in [AIP-4222].
+//
+// [AIP-4222]: https://google.aip.dev/client-libraries/4222
+func parseRoutingInfo(m *descriptorpb.MethodDescriptorProto, pathInfo *api.PathInfo) []*api.RoutingInfo {
+	eRouting := proto.GetExtension(m.GetOptions(), annotations.E_Routing)
+	rule, _ := eRouting.(*annotations.RoutingRule)
+	var routing []*api.RoutingInfo
+	if len(rule.GetRoutingParameters()) != 0 {
+		for _, p := range rule.GetRoutingParameters() {
+			routing = append(routing, &api.RoutingInfo{
+				FieldPath:    p.GetField(),
+				PathTemplate: p.GetPathTemplate(),
+			})
+		}
+		return routing
+	}
+	return implicitRouting(pathInfo)
+}
+
+// Returns the routing parameters for methods without a `google.api.routing`
+// annotation: each field in the HTTP path is a routing parameter.
+func implicitRouting(pathInfo *api.PathInfo) []*api.RoutingInfo {
+	var routing []*api.RoutingInfo
+	for _, segment := range pathInfo.PathTemplate {
+		if segment.FieldPath != nil {
+			routing = append(routing, &api.RoutingInfo{FieldPath: *segment.FieldPath})
+		}
+	}
+	return routing
+}
+
+// parseIsPreview returns true if the method visibility annotation restricts
+// the method to preview users.
+func parseIsPreview(m *descriptorpb.MethodDescriptorProto) bool {
+	eVisibility := proto.GetExtension(m.GetOptions(), visibility.E_MethodVisibility)
+	rule, ok := eVisibility.(*visibility.VisibilityRule)
+	if !ok || rule == nil {
+		return false
+	}
+	for _, label := range strings.Split(rule.GetRestriction(), ",") {
+		if strings.TrimSpace(label) == "PREVIEW" {
+			return true
+		}
+	}
+	return false
+}
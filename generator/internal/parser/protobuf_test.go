@@ -432,12 +432,13 @@ func TestProtobuf_Comments(t *testing.T) {
 					Verb: "POST",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "*",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 		},
 	})
@@ -686,11 +687,12 @@ func TestProtobuf_Service(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("name"),
+						api.NewFieldPathPathSegment("name").WithTemplate("projects/*/foos/*"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "name"}},
 			},
 			{
 				Name:          "CreateFoo",
@@ -702,12 +704,13 @@ func TestProtobuf_Service(t *testing.T) {
 					Verb: "POST",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"foo_id": true},
 					BodyFieldPath:   "foo",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 			{
 				Name:          "UploadFoos",
@@ -733,12 +736,13 @@ func TestProtobuf_Service(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("name"),
+						api.NewFieldPathPathSegment("name").WithTemplate("projects/*/foos/*"),
 						api.NewVerbPathSegment("Download"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "",
 				},
+				Routing:             []*api.RoutingInfo{{FieldPath: "name"}},
 				ServerSideStreaming: true,
 			},
 			{
@@ -783,12 +787,13 @@ func TestProtobuf_QueryParameters(t *testing.T) {
 					Verb: "POST",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"foo_id": true},
 					BodyFieldPath:   "bar",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 			{
 				Name:          "AddBar",
@@ -800,12 +805,13 @@ func TestProtobuf_QueryParameters(t *testing.T) {
 					Verb: "POST",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*/foos/*"),
 						api.NewVerbPathSegment("addFoo"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "*",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 		},
 	})
@@ -908,10 +914,11 @@ func TestProtobuf_LocationMixin(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("name"),
+						api.NewFieldPathPathSegment("name").WithTemplate("projects/*/locations/*"),
 					},
 					QueryParameters: map[string]bool{},
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "name"}},
 			},
 		},
 	})
@@ -967,12 +974,13 @@ func TestProtobuf_IAMMixin(t *testing.T) {
 					Verb: "POST",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("resource"),
+						api.NewFieldPathPathSegment("resource").WithTemplate("services/*"),
 						api.NewVerbPathSegment("getIamPolicy"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "*",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "resource"}},
 			},
 		},
 	})
@@ -999,11 +1007,12 @@ func TestProtobuf_Pagination(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"page_size": true, "page_token": true},
 				},
+				Routing:    []*api.RoutingInfo{{FieldPath: "parent"}},
 				IsPageable: true,
 			},
 			{
@@ -1015,11 +1024,12 @@ func TestProtobuf_Pagination(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"page_size": true, "page_token": true},
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 			{
 				Name:         "ListFooMissingPageSize",
@@ -1030,11 +1040,12 @@ func TestProtobuf_Pagination(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"page_token": true},
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 			{
 				Name:         "ListFooMissingPageToken",
@@ -1045,11 +1056,12 @@ func TestProtobuf_Pagination(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"page_size": true},
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 			{
 				Name:         "ListFooMissingRepeatedItemToken",
@@ -1060,11 +1072,12 @@ func TestProtobuf_Pagination(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v1"),
-						api.NewFieldPathPathSegment("parent"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
 						api.NewLiteralPathSegment("foos"),
 					},
 					QueryParameters: map[string]bool{"page_size": true, "page_token": true},
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "parent"}},
 			},
 		},
 	})
@@ -1168,11 +1181,12 @@ func TestProtobuf_OperationMixin(t *testing.T) {
 					Verb: "GET",
 					PathTemplate: []api.PathSegment{
 						api.NewLiteralPathSegment("v2"),
-						api.NewFieldPathPathSegment("name"),
+						api.NewFieldPathPathSegment("name").WithTemplate("operations/*"),
 					},
 					QueryParameters: map[string]bool{},
 					BodyFieldPath:   "*",
 				},
+				Routing: []*api.RoutingInfo{{FieldPath: "name"}},
 			},
 		},
 	})
@@ -1190,3 +1204,112 @@ func newTestCodeGeneratorRequest(t *testing.T, filename string) *pluginpb.CodeGe
 	}
 	return request
 }
+
+func TestProtobuf_Preview(t *testing.T) {
+	test := makeAPIForProtobuf(nil, newTestCodeGeneratorRequest(t, "preview.proto"))
+	for _, c := range []struct {
+		ID   string
+		Want bool
+	}{
+		{".test.TestService.GetFoo", false},
+		{".test.TestService.GetFooPreview", true},
+		{".test.TestService.GetFooInternal", false},
+	} {
+		method, ok := test.State.MethodByID[c.ID]
+		if !ok {
+			t.Fatalf("Cannot find method %s in API State", c.ID)
+		}
+		if method.IsPreview != c.Want {
+			t.Errorf("mismatched IsPreview for %s, want=%v, got=%v", c.ID, c.Want, method.IsPreview)
+		}
+	}
+}
+
+func TestProtobuf_Routing(t *testing.T) {
+	test := makeAPIForProtobuf(nil, newTestCodeGeneratorRequest(t, "routing.proto"))
+	for _, c := range []struct {
+		ID   string
+		Want []*api.RoutingInfo
+	}{
+		{".test.TestService.GetFoo", []*api.RoutingInfo{{FieldPath: "name"}}},
+		{".test.TestService.UpdateFoo", []*api.RoutingInfo{{FieldPath: "foo.name"}}},
+		{".test.TestService.ReadFoo", []*api.RoutingInfo{
+			{FieldPath: "table_name", PathTemplate: "{routing_id=projects/*}/**"},
+			{FieldPath: "app_profile_id"},
+		}},
+	} {
+		method, ok := test.State.MethodByID[c.ID]
+		if !ok {
+			t.Fatalf("Cannot find method %s in API State", c.ID)
+		}
+		if diff := cmp.Diff(c.Want, method.Routing); diff != "" {
+			t.Errorf("mismatched routing for %s (-want, +got):\n%s", c.ID, diff)
+		}
+	}
+}
+
+func TestProtobuf_AdditionalBindings(t *testing.T) {
+	test := makeAPIForProtobuf(nil, newTestCodeGeneratorRequest(t, "http_bindings.proto"))
+	for _, c := range []struct {
+		ID   string
+		Want *api.PathInfo
+	}{
+		{".test.TestService.ListFoos", &api.PathInfo{
+			Verb: "GET",
+			PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewFieldPathPathSegment("parent").WithTemplate("locations"),
+				api.NewLiteralPathSegment("foos"),
+			},
+			QueryParameters: map[string]bool{"page_size": true},
+			AdditionalBindings: []*api.PathInfo{
+				{
+					Verb: "GET",
+					PathTemplate: []api.PathSegment{
+						api.NewLiteralPathSegment("v1"),
+						api.NewFieldPathPathSegment("parent").WithTemplate("projects/*"),
+						api.NewLiteralPathSegment("foos"),
+					},
+					QueryParameters: map[string]bool{"page_size": true},
+				},
+			},
+		}},
+		{".test.TestService.MoveFoo", &api.PathInfo{
+			Verb: "POST",
+			PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewFieldPathPathSegment("name").WithTemplate("projects/*/foos/*"),
+				api.NewVerbPathSegment("move"),
+			},
+			QueryParameters: map[string]bool{},
+			BodyFieldPath:   "*",
+			AdditionalBindings: []*api.PathInfo{
+				{
+					Verb: "PATCH",
+					PathTemplate: []api.PathSegment{
+						api.NewLiteralPathSegment("v1"),
+						api.NewFieldPathPathSegment("name").WithTemplate("projects/*/locations/*/foos/*"),
+					},
+					QueryParameters: map[string]bool{},
+					BodyFieldPath:   "destination",
+				},
+			},
+		}},
+		{".test.TestService.HeadFoo", &api.PathInfo{
+			Verb: "HEAD",
+			PathTemplate: []api.PathSegment{
+				api.NewLiteralPathSegment("v1"),
+				api.NewFieldPathPathSegment("name").WithTemplate("projects/*/foos/*"),
+			},
+			QueryParameters: map[string]bool{},
+		}},
+	} {
+		method, ok := test.State.MethodByID[c.ID]
+		if !ok {
+			t.Fatalf("Cannot find method %s in API State", c.ID)
+		}
+		if diff := cmp.Diff(c.Want, method.PathInfo); diff != "" {
+			t.Errorf("mismatched path info for %s (-want, +got):\n%s", c.ID, diff)
+		}
+	}
+}
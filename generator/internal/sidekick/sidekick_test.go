@@ -104,6 +104,10 @@ func TestRustFromProtobuf(t *testing.T) {
 				"package:location": fmt.Sprintf("package=location-golden-protobuf,path=%s/rust/protobuf/golden/location,source=google.cloud.location", testdataDir),
 			},
 		},
+		{
+			Source: "googleapis/google/test/preview/v1",
+			Name:   "preview",
+		},
 	}
 	for _, config := range configs {
 		if config.Source != "" {
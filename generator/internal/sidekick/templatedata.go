@@ -15,6 +15,7 @@
 package sidekick
 
 import (
+	"slices"
 	"strings"
 
 	"github.com/googleapis/google-cloud-rust/generator/internal/api"
@@ -81,16 +82,38 @@ type Method struct {
 	HTTPMethodToLower   string
 	HTTPPathFmt         string
 	HTTPPathArgs        []string
+	HTTPPathMatch       string
+	RoutingParams       []string
+	HasRoutingParams    bool
 	PathParams          []*Field
 	QueryParams         []*Field
 	HasBody             bool
 	BodyAccessor        string
 	IsPageable          bool
+	IsPreview           bool
 	ServiceNameToPascal string
 	ServiceNameToCamel  string
 	ServiceNameToSnake  string
 	InputTypeID         string
 	InputType           *Message
+	// AdditionalBindings are the alternative HTTP bindings for the method,
+	// excluding any that produce the same request as the primary binding.
+	AdditionalBindings    []*PathBinding
+	HasAdditionalBindings bool
+}
+
+// PathBinding is an additional HTTP binding for a method. The fields have the
+// same names as in `Method`, so the templates can use the same code to build
+// the request for any binding.
+type PathBinding struct {
+	HTTPMethod        string
+	HTTPMethodToLower string
+	HTTPPathFmt       string
+	HTTPPathArgs      []string
+	HTTPPathMatch     string
+	QueryParams       []*Field
+	HasBody           bool
+	BodyAccessor      string
 }
 
 type OneOf struct {
@@ -113,6 +136,13 @@ type Field struct {
 	PrimitiveFieldType    string
 	JSONName              string
 	AsQueryParameter      string
+	IsRepeated            bool
+	IsOptional            bool
+	IsDuration            bool
+	IsTimestamp           bool
+	IsMap                 bool
+	KeyType               string
+	ValueType             string
 }
 
 type Enum struct {
@@ -250,6 +280,14 @@ func newMessage(m *api.Message, c language.Codec, state *api.APIState) *Message
 }
 
 func newMethod(m *api.Method, c language.Codec, state *api.APIState) *Method {
+	primary := newPathBinding(m, m.PathInfo, c, state)
+	var additionalBindings []*PathBinding
+	for _, pathInfo := range m.PathInfo.AdditionalBindings {
+		binding := newPathBinding(m, pathInfo, c, state)
+		if !samePathBinding(primary, binding) {
+			additionalBindings = append(additionalBindings, binding)
+		}
+	}
 	return &Method{
 		BodyAccessor:      c.BodyAccessor(m, state),
 		DocLines:          c.FormatDocComments(m.Documentation, state),
@@ -257,6 +295,9 @@ func newMethod(m *api.Method, c language.Codec, state *api.APIState) *Method {
 		HTTPMethodToLower: strings.ToLower(m.PathInfo.Verb),
 		HTTPPathArgs:      c.HTTPPathArgs(m.PathInfo, state),
 		HTTPPathFmt:       c.HTTPPathFmt(m.PathInfo, state),
+		HTTPPathMatch:     primary.HTTPPathMatch,
+		RoutingParams:     c.RoutingParams(m, state),
+		HasRoutingParams:  len(m.Routing) != 0,
 		HasBody:           m.PathInfo.BodyFieldPath != "",
 		InputTypeName:     c.MethodInOutTypeName(m.InputTypeID, state),
 		NameToCamel:       strcase.ToCamel(m.Name),
@@ -266,10 +307,13 @@ func newMethod(m *api.Method, c language.Codec, state *api.APIState) *Method {
 		PathParams: mapSlice(language.PathParams(m, state), func(s *api.Field) *Field {
 			return newField(s, c, state)
 		}),
+		AdditionalBindings:    additionalBindings,
+		HasAdditionalBindings: len(additionalBindings) != 0,
 		QueryParams: mapSlice(language.QueryParams(m, state), func(s *api.Field) *Field {
 			return newField(s, c, state)
 		}),
 		IsPageable:          m.IsPageable,
+		IsPreview:           m.IsPreview,
 		ServiceNameToPascal: c.ToPascal(m.Parent.Name),
 		ServiceNameToCamel:  c.ToCamel(m.Parent.Name),
 		ServiceNameToSnake:  c.ToSnake(m.Parent.Name),
@@ -277,6 +321,41 @@ func newMethod(m *api.Method, c language.Codec, state *api.APIState) *Method {
 	}
 }
 
+func newPathBinding(m *api.Method, pathInfo *api.PathInfo, c language.Codec, state *api.APIState) *PathBinding {
+	// The codecs compute the body and query parameters from the method, give
+	// them a copy with the binding's path information.
+	method := *m
+	method.PathInfo = pathInfo
+	return &PathBinding{
+		HTTPMethod:        pathInfo.Verb,
+		HTTPMethodToLower: strings.ToLower(pathInfo.Verb),
+		HTTPPathFmt:       c.HTTPPathFmt(pathInfo, state),
+		HTTPPathArgs:      c.HTTPPathArgs(pathInfo, state),
+		HTTPPathMatch:     c.HTTPPathMatch(m, pathInfo, state),
+		QueryParams: mapSlice(language.QueryParams(&method, state), func(s *api.Field) *Field {
+			return newField(s, c, state)
+		}),
+		HasBody:      pathInfo.BodyFieldPath != "",
+		BodyAccessor: c.BodyAccessor(&method, state),
+	}
+}
+
+// Many additional bindings only differ from the primary binding in the path
+// templates, for example `projects/*/secrets/*` vs.
+// `projects/*/locations/*/secrets/*`. These produce the same request and do
+// not need any code to select them.
+func samePathBinding(a, b *PathBinding) bool {
+	queryParams := func(p *PathBinding) []string {
+		return mapSlice(p.QueryParams, func(f *Field) string { return f.JSONName })
+	}
+	return a.HTTPMethod == b.HTTPMethod &&
+		a.HTTPPathFmt == b.HTTPPathFmt &&
+		slices.Equal(a.HTTPPathArgs, b.HTTPPathArgs) &&
+		a.HasBody == b.HasBody &&
+		a.BodyAccessor == b.BodyAccessor &&
+		slices.Equal(queryParams(a), queryParams(b))
+}
+
 func newOneOf(oneOf *api.OneOf, c language.Codec, state *api.APIState) *OneOf {
 	return &OneOf{
 		NameToPascal:          c.ToPascal(oneOf.Name),
@@ -294,7 +373,7 @@ func newField(field *api.Field, c language.Codec, state *api.APIState) *Field {
 	if field == nil {
 		return nil
 	}
-	return &Field{
+	result := &Field{
 		NameToSnake:           c.ToSnake(field.Name),
 		NameToSnakeNoMangling: c.ToSnakeNoMangling(field.Name),
 		NameToCamel:           c.ToCamel(field.Name),
@@ -305,7 +384,28 @@ func newField(field *api.Field, c language.Codec, state *api.APIState) *Field {
 		PrimitiveFieldType:    c.PrimitiveFieldType(field, state),
 		JSONName:              field.JSONName,
 		AsQueryParameter:      c.AsQueryParameter(field, state),
+		IsRepeated:            field.Repeated,
+		IsOptional:            field.Optional && !field.Repeated,
+		IsDuration:            isWellKnownType(field, ".google.protobuf.Duration"),
+		IsTimestamp:           isWellKnownType(field, ".google.protobuf.Timestamp"),
+	}
+	if field.Typez == api.MESSAGE_TYPE {
+		// Map fields get `insert_*()` helpers, which need the key and value
+		// types.
+		if m, ok := state.MessageByID[field.TypezID]; ok && m.IsMap && len(m.Fields) == 2 {
+			result.IsMap = true
+			result.KeyType = c.FieldType(m.Fields[0], state)
+			result.ValueType = c.FieldType(m.Fields[1], state)
+		}
 	}
+	return result
+}
+
+// isWellKnownType returns true if `field` is a singular field of the given
+// well-known message type. These fields get additional setters that accept
+// the standard library types, e.g. `std::time::Duration`.
+func isWellKnownType(field *api.Field, id string) bool {
+	return field.Typez == api.MESSAGE_TYPE && field.TypezID == id && !field.Repeated
 }
 
 func newEnum(e *api.Enum, c language.Codec, state *api.APIState) *Enum {
@@ -0,0 +1,39 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package sidekick
+
+import (
+	"testing"
+
+	"github.com/googleapis/google-cloud-rust/generator/internal/api"
+)
+
+func TestIsWellKnownType(t *testing.T) {
+	for _, test := range []struct {
+		field    *api.Field
+		id       string
+		expected bool
+	}{
+		{&api.Field{Typez: api.MESSAGE_TYPE, TypezID: ".google.protobuf.Duration"}, ".google.protobuf.Duration", true},
+		{&api.Field{Typez: api.MESSAGE_TYPE, TypezID: ".google.protobuf.Timestamp"}, ".google.protobuf.Timestamp", true},
+		{&api.Field{Typez: api.MESSAGE_TYPE, TypezID: ".google.protobuf.Timestamp"}, ".google.protobuf.Duration", false},
+		{&api.Field{Typez: api.MESSAGE_TYPE, TypezID: ".google.protobuf.Duration", Repeated: true}, ".google.protobuf.Duration", false},
+		{&api.Field{Typez: api.STRING_TYPE}, ".google.protobuf.Duration", false},
+	} {
+		if got := isWellKnownType(test.field, test.id); got != test.expected {
+			t.Errorf("mismatch in isWellKnownType(%v, %q), want=%v, got=%v", test.field, test.id, test.expected, got)
+		}
+	}
+}
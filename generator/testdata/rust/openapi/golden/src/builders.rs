@@ -61,15 +61,27 @@ impl ListLocations {
     /// Streams the responses back.
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(self) -> gax::paginator::Paginator<crate::model::ListLocationsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(self) -> gax::paginator::Paginator<crate::model::ListLocationsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(self) -> gax::paginator::ItemPaginator<crate::model::ListLocationsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.project = v.into();
@@ -167,15 +179,27 @@ impl ListSecrets {
     /// Streams the responses back.
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(self) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(self) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(self) -> gax::paginator::ItemPaginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.project = v.into();
@@ -279,15 +303,27 @@ impl ListSecretsByProjectAndLocation {
     /// Streams the responses back.
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(self) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(self) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(self) -> gax::paginator::ItemPaginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.project = v.into();
@@ -809,15 +845,27 @@ impl ListSecretVersions {
     /// Streams the responses back.
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(self) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(self) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(self) -> gax::paginator::ItemPaginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.project = v.into();
@@ -880,15 +928,27 @@ impl ListSecretVersionsByProjectAndLocationAndSecret {
     /// Streams the responses back.
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(self) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(self) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(self) -> gax::paginator::ItemPaginator<crate::model::ListSecretVersionsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.project = v.into();
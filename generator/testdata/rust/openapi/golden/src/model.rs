@@ -36,8 +36,18 @@ pub struct ListLocationsResponse {
 impl ListLocationsResponse {
 
     /// Sets the value of `locations`.
-    pub fn set_locations<T: Into<Vec<crate::model::Location>>>(mut self, v: T) -> Self {
-        self.locations = v.into();
+    pub fn set_locations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Location>,
+    {
+        self.locations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `locations`.
+    pub fn add_locations<T: Into<crate::model::Location>>(mut self, v: T) -> Self {
+        self.locations.push(v.into());
         self
     }
 
@@ -46,6 +56,12 @@ impl ListLocationsResponse {
         self.next_page_token = v.into();
         self
     }
+
+    /// Sets or clears the value of `next_page_token`.
+    pub fn set_or_clear_next_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.next_page_token = v.map(|x| x.into());
+        self
+    }
 }
 
 #[cfg(feature = "unstable-stream")]
@@ -104,21 +120,50 @@ impl Location {
         self
     }
 
+    /// Sets or clears the value of `name`.
+    pub fn set_or_clear_name<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.name = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `location_id`.
     pub fn set_location_id<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.location_id = v.into();
         self
     }
 
+    /// Sets or clears the value of `location_id`.
+    pub fn set_or_clear_location_id<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.location_id = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `display_name`.
     pub fn set_display_name<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.display_name = v.into();
         self
     }
 
+    /// Sets or clears the value of `display_name`.
+    pub fn set_or_clear_display_name<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.display_name = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `labels`.
-    pub fn set_labels<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.labels = v.into();
+    pub fn set_labels<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `labels`.
+    pub fn insert_labels<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.labels.insert(k.into(), v.into());
         self
     }
 
@@ -127,6 +172,12 @@ impl Location {
         self.metadata = v.into();
         self
     }
+
+    /// Sets or clears the value of `metadata`.
+    pub fn set_or_clear_metadata<T: Into<wkt::Any>>(mut self, v: Option<T>) -> Self {
+        self.metadata = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Response message for SecretManagerService.ListSecrets.
@@ -155,8 +206,18 @@ pub struct ListSecretsResponse {
 impl ListSecretsResponse {
 
     /// Sets the value of `secrets`.
-    pub fn set_secrets<T: Into<Vec<crate::model::Secret>>>(mut self, v: T) -> Self {
-        self.secrets = v.into();
+    pub fn set_secrets<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Secret>,
+    {
+        self.secrets = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `secrets`.
+    pub fn add_secrets<T: Into<crate::model::Secret>>(mut self, v: T) -> Self {
+        self.secrets.push(v.into());
         self
     }
 
@@ -166,11 +227,23 @@ impl ListSecretsResponse {
         self
     }
 
+    /// Sets or clears the value of `next_page_token`.
+    pub fn set_or_clear_next_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.next_page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `total_size`.
     pub fn set_total_size<T: Into<Option<i32>>>(mut self, v: T) -> Self {
         self.total_size = v.into();
         self
     }
+
+    /// Sets or clears the value of `total_size`.
+    pub fn set_or_clear_total_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.total_size = v.map(|x| x.into());
+        self
+    }
 }
 
 #[cfg(feature = "unstable-stream")]
@@ -305,27 +378,78 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `name`.
+    pub fn set_or_clear_name<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.name = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `replication`.
     pub fn set_replication<T: Into<Option<crate::model::Replication>>>(mut self, v: T) -> Self {
         self.replication = v.into();
         self
     }
 
+    /// Sets or clears the value of `replication`.
+    pub fn set_or_clear_replication<T: Into<crate::model::Replication>>(mut self, v: Option<T>) -> Self {
+        self.replication = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `create_time`.
     pub fn set_create_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.create_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `labels`.
-    pub fn set_labels<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.labels = v.into();
+    pub fn set_labels<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `labels`.
+    pub fn insert_labels<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.labels.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `topics`.
-    pub fn set_topics<T: Into<Vec<crate::model::Topic>>>(mut self, v: T) -> Self {
-        self.topics = v.into();
+    pub fn set_topics<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Topic>,
+    {
+        self.topics = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `topics`.
+    pub fn add_topics<T: Into<crate::model::Topic>>(mut self, v: T) -> Self {
+        self.topics.push(v.into());
         self
     }
 
@@ -335,33 +459,103 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `expire_time`.
+    pub fn set_or_clear_expire_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.expire_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `expire_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_expire_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.expire_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `ttl`.
     pub fn set_ttl<T: Into<Option<wkt::Duration>>>(mut self, v: T) -> Self {
         self.ttl = v.into();
         self
     }
 
+    /// Sets or clears the value of `ttl`.
+    pub fn set_or_clear_ttl<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.ttl = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `ttl` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_ttl<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.ttl = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `etag`.
     pub fn set_etag<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.etag = v.into();
         self
     }
 
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `rotation`.
     pub fn set_rotation<T: Into<Option<crate::model::Rotation>>>(mut self, v: T) -> Self {
         self.rotation = v.into();
         self
     }
 
+    /// Sets or clears the value of `rotation`.
+    pub fn set_or_clear_rotation<T: Into<crate::model::Rotation>>(mut self, v: Option<T>) -> Self {
+        self.rotation = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `version_aliases`.
-    pub fn set_version_aliases<T: Into<std::collections::HashMap<String,i64>>>(mut self, v: T) -> Self {
-        self.version_aliases = v.into();
+    pub fn set_version_aliases<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<i64>,
+    {
+        self.version_aliases = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `version_aliases`.
+    pub fn insert_version_aliases<K: Into<String>, V: Into<i64>>(mut self, k: K, v: V) -> Self {
+        self.version_aliases.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `annotations`.
-    pub fn set_annotations<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.annotations = v.into();
+    pub fn set_annotations<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.annotations = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `annotations`.
+    pub fn insert_annotations<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.annotations.insert(k.into(), v.into());
         self
     }
 
@@ -371,11 +565,35 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `version_destroy_ttl`.
+    pub fn set_or_clear_version_destroy_ttl<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.version_destroy_ttl = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `version_destroy_ttl` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_version_destroy_ttl<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.version_destroy_ttl = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryption>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// A policy that defines the replication and encryption configuration of data.
@@ -402,11 +620,23 @@ impl Replication {
         self
     }
 
+    /// Sets or clears the value of `automatic`.
+    pub fn set_or_clear_automatic<T: Into<crate::model::Automatic>>(mut self, v: Option<T>) -> Self {
+        self.automatic = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `user_managed`.
     pub fn set_user_managed<T: Into<Option<crate::model::UserManaged>>>(mut self, v: T) -> Self {
         self.user_managed = v.into();
         self
     }
+
+    /// Sets or clears the value of `user_managed`.
+    pub fn set_or_clear_user_managed<T: Into<crate::model::UserManaged>>(mut self, v: Option<T>) -> Self {
+        self.user_managed = v.map(|x| x.into());
+        self
+    }
 }
 
 /// A replication policy that replicates the Secret payload without any
@@ -434,6 +664,12 @@ impl Automatic {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Configuration for encrypting secret payloads using customer-managed
@@ -486,8 +722,18 @@ pub struct UserManaged {
 impl UserManaged {
 
     /// Sets the value of `replicas`.
-    pub fn set_replicas<T: Into<Vec<crate::model::Replica>>>(mut self, v: T) -> Self {
-        self.replicas = v.into();
+    pub fn set_replicas<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Replica>,
+    {
+        self.replicas = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `replicas`.
+    pub fn add_replicas<T: Into<crate::model::Replica>>(mut self, v: T) -> Self {
+        self.replicas.push(v.into());
         self
     }
 }
@@ -523,11 +769,23 @@ impl Replica {
         self
     }
 
+    /// Sets or clears the value of `location`.
+    pub fn set_or_clear_location<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.location = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryption>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// A Pub/Sub topic which Secret Manager will publish to when control plane
@@ -591,11 +849,47 @@ impl Rotation {
         self
     }
 
+    /// Sets or clears the value of `next_rotation_time`.
+    pub fn set_or_clear_next_rotation_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.next_rotation_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `next_rotation_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_next_rotation_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.next_rotation_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `rotation_period`.
     pub fn set_rotation_period<T: Into<Option<wkt::Duration>>>(mut self, v: T) -> Self {
         self.rotation_period = v.into();
         self
     }
+
+    /// Sets or clears the value of `rotation_period`.
+    pub fn set_or_clear_rotation_period<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.rotation_period = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `rotation_period` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_rotation_period<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.rotation_period = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// Request message for SecretManagerService.AddSecretVersion.
@@ -636,6 +930,12 @@ impl AddSecretVersionRequest {
         self
     }
 
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(mut self, v: Option<T>) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -665,7 +965,7 @@ pub struct SecretPayload {
 
     /// The secret data. Must be no larger than 64KiB.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde_as(as = "Option<serde_with::base64::Base64>")]
+    #[serde_as(as = "Option<wkt::internal::Base64>")]
     pub data: Option<bytes::Bytes>,
 
     /// Optional. If specified, SecretManagerService will verify the integrity of the
@@ -692,11 +992,23 @@ impl SecretPayload {
         self
     }
 
+    /// Sets or clears the value of `data`.
+    pub fn set_or_clear_data<T: Into<bytes::Bytes>>(mut self, v: Option<T>) -> Self {
+        self.data = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `data_crc_32_c`.
     pub fn set_data_crc_32_c<T: Into<Option<i64>>>(mut self, v: T) -> Self {
         self.data_crc_32_c = v.into();
         self
     }
+
+    /// Sets or clears the value of `data_crc_32_c`.
+    pub fn set_or_clear_data_crc_32_c<T: Into<i64>>(mut self, v: Option<T>) -> Self {
+        self.data_crc_32_c = v.map(|x| x.into());
+        self
+    }
 }
 
 /// A secret version resource in the Secret Manager API.
@@ -765,53 +1077,143 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `name`.
+    pub fn set_or_clear_name<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.name = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `create_time`.
     pub fn set_create_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.create_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `destroy_time`.
     pub fn set_destroy_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.destroy_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `destroy_time`.
+    pub fn set_or_clear_destroy_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `state`.
     pub fn set_state<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.state = v.into();
         self
     }
 
+    /// Sets or clears the value of `state`.
+    pub fn set_or_clear_state<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.state = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `replication_status`.
     pub fn set_replication_status<T: Into<Option<crate::model::ReplicationStatus>>>(mut self, v: T) -> Self {
         self.replication_status = v.into();
         self
     }
 
+    /// Sets or clears the value of `replication_status`.
+    pub fn set_or_clear_replication_status<T: Into<crate::model::ReplicationStatus>>(mut self, v: Option<T>) -> Self {
+        self.replication_status = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `etag`.
     pub fn set_etag<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.etag = v.into();
         self
     }
 
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `client_specified_payload_checksum`.
     pub fn set_client_specified_payload_checksum<T: Into<Option<bool>>>(mut self, v: T) -> Self {
         self.client_specified_payload_checksum = v.into();
         self
     }
 
+    /// Sets or clears the value of `client_specified_payload_checksum`.
+    pub fn set_or_clear_client_specified_payload_checksum<T: Into<bool>>(mut self, v: Option<T>) -> Self {
+        self.client_specified_payload_checksum = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `scheduled_destroy_time`.
     pub fn set_scheduled_destroy_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.scheduled_destroy_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `scheduled_destroy_time`.
+    pub fn set_or_clear_scheduled_destroy_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.scheduled_destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `scheduled_destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_scheduled_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.scheduled_destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryptionStatus>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The replication status of a SecretVersion.
@@ -846,11 +1248,23 @@ impl ReplicationStatus {
         self
     }
 
+    /// Sets or clears the value of `automatic`.
+    pub fn set_or_clear_automatic<T: Into<crate::model::AutomaticStatus>>(mut self, v: Option<T>) -> Self {
+        self.automatic = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `user_managed`.
     pub fn set_user_managed<T: Into<Option<crate::model::UserManagedStatus>>>(mut self, v: T) -> Self {
         self.user_managed = v.into();
         self
     }
+
+    /// Sets or clears the value of `user_managed`.
+    pub fn set_or_clear_user_managed<T: Into<crate::model::UserManagedStatus>>(mut self, v: Option<T>) -> Self {
+        self.user_managed = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The replication status of a SecretVersion using automatic replication.
@@ -876,6 +1290,12 @@ impl AutomaticStatus {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Describes the status of customer-managed encryption.
@@ -920,8 +1340,18 @@ pub struct UserManagedStatus {
 impl UserManagedStatus {
 
     /// Sets the value of `replicas`.
-    pub fn set_replicas<T: Into<Vec<crate::model::ReplicaStatus>>>(mut self, v: T) -> Self {
-        self.replicas = v.into();
+    pub fn set_replicas<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::ReplicaStatus>,
+    {
+        self.replicas = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `replicas`.
+    pub fn add_replicas<T: Into<crate::model::ReplicaStatus>>(mut self, v: T) -> Self {
+        self.replicas.push(v.into());
         self
     }
 }
@@ -952,11 +1382,23 @@ impl ReplicaStatus {
         self
     }
 
+    /// Sets or clears the value of `location`.
+    pub fn set_or_clear_location<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.location = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryptionStatus>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// A generic empty message that you can re-use to avoid defining duplicated
@@ -1004,8 +1446,18 @@ pub struct ListSecretVersionsResponse {
 impl ListSecretVersionsResponse {
 
     /// Sets the value of `versions`.
-    pub fn set_versions<T: Into<Vec<crate::model::SecretVersion>>>(mut self, v: T) -> Self {
-        self.versions = v.into();
+    pub fn set_versions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::SecretVersion>,
+    {
+        self.versions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `versions`.
+    pub fn add_versions<T: Into<crate::model::SecretVersion>>(mut self, v: T) -> Self {
+        self.versions.push(v.into());
         self
     }
 
@@ -1015,11 +1467,23 @@ impl ListSecretVersionsResponse {
         self
     }
 
+    /// Sets or clears the value of `next_page_token`.
+    pub fn set_or_clear_next_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.next_page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `total_size`.
     pub fn set_total_size<T: Into<Option<i32>>>(mut self, v: T) -> Self {
         self.total_size = v.into();
         self
     }
+
+    /// Sets or clears the value of `total_size`.
+    pub fn set_or_clear_total_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.total_size = v.map(|x| x.into());
+        self
+    }
 }
 
 #[cfg(feature = "unstable-stream")]
@@ -1061,11 +1525,23 @@ impl AccessSecretVersionResponse {
         self
     }
 
+    /// Sets or clears the value of `name`.
+    pub fn set_or_clear_name<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.name = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `payload`.
     pub fn set_payload<T: Into<Option<crate::model::SecretPayload>>>(mut self, v: T) -> Self {
         self.payload = v.into();
         self
     }
+
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(mut self, v: Option<T>) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for SecretManagerService.DisableSecretVersion.
@@ -1114,6 +1590,12 @@ impl DisableSecretVersionRequest {
         self
     }
 
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -1185,6 +1667,12 @@ impl EnableSecretVersionRequest {
         self
     }
 
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -1256,6 +1744,12 @@ impl DestroySecretVersionRequest {
         self
     }
 
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -1330,12 +1824,24 @@ impl SetIamPolicyRequest {
         self
     }
 
+    /// Sets or clears the value of `policy`.
+    pub fn set_or_clear_policy<T: Into<crate::model::Policy>>(mut self, v: Option<T>) -> Self {
+        self.policy = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `update_mask`.
     pub fn set_update_mask<T: Into<Option<wkt::FieldMask>>>(mut self, v: T) -> Self {
         self.update_mask = v.into();
         self
     }
 
+    /// Sets or clears the value of `update_mask`.
+    pub fn set_or_clear_update_mask<T: Into<wkt::FieldMask>>(mut self, v: Option<T>) -> Self {
+        self.update_mask = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -1489,7 +1995,7 @@ pub struct Policy {
     /// you to overwrite a version `3` policy with a version `1` policy, and all of
     /// the conditions in the version `3` policy are lost.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde_as(as = "Option<serde_with::base64::Base64>")]
+    #[serde_as(as = "Option<wkt::internal::Base64>")]
     pub etag: Option<bytes::Bytes>,
 }
 
@@ -1501,15 +2007,41 @@ impl Policy {
         self
     }
 
+    /// Sets or clears the value of `version`.
+    pub fn set_or_clear_version<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.version = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `bindings`.
-    pub fn set_bindings<T: Into<Vec<crate::model::Binding>>>(mut self, v: T) -> Self {
-        self.bindings = v.into();
+    pub fn set_bindings<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Binding>,
+    {
+        self.bindings = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `bindings`.
+    pub fn add_bindings<T: Into<crate::model::Binding>>(mut self, v: T) -> Self {
+        self.bindings.push(v.into());
         self
     }
 
     /// Sets the value of `audit_configs`.
-    pub fn set_audit_configs<T: Into<Vec<crate::model::AuditConfig>>>(mut self, v: T) -> Self {
-        self.audit_configs = v.into();
+    pub fn set_audit_configs<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::AuditConfig>,
+    {
+        self.audit_configs = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `audit_configs`.
+    pub fn add_audit_configs<T: Into<crate::model::AuditConfig>>(mut self, v: T) -> Self {
+        self.audit_configs.push(v.into());
         self
     }
 
@@ -1518,6 +2050,12 @@ impl Policy {
         self.etag = v.into();
         self
     }
+
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<bytes::Bytes>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Associates `members`, or principals, with a `role`.
@@ -1646,9 +2184,25 @@ impl Binding {
         self
     }
 
+    /// Sets or clears the value of `role`.
+    pub fn set_or_clear_role<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.role = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `members`.
-    pub fn set_members<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.members = v.into();
+    pub fn set_members<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.members = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `members`.
+    pub fn add_members<T: Into<String>>(mut self, v: T) -> Self {
+        self.members.push(v.into());
         self
     }
 
@@ -1657,6 +2211,12 @@ impl Binding {
         self.condition = v.into();
         self
     }
+
+    /// Sets or clears the value of `condition`.
+    pub fn set_or_clear_condition<T: Into<crate::model::Expr>>(mut self, v: Option<T>) -> Self {
+        self.condition = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Represents a textual expression in the Common Expression Language (CEL)
@@ -1734,23 +2294,47 @@ impl Expr {
         self
     }
 
+    /// Sets or clears the value of `expression`.
+    pub fn set_or_clear_expression<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.expression = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `title`.
     pub fn set_title<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.title = v.into();
         self
     }
 
+    /// Sets or clears the value of `title`.
+    pub fn set_or_clear_title<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.title = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `description`.
     pub fn set_description<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.description = v.into();
         self
     }
 
+    /// Sets or clears the value of `description`.
+    pub fn set_or_clear_description<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.description = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `location`.
     pub fn set_location<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.location = v.into();
         self
     }
+
+    /// Sets or clears the value of `location`.
+    pub fn set_or_clear_location<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.location = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Specifies the audit configuration for a service.
@@ -1831,9 +2415,25 @@ impl AuditConfig {
         self
     }
 
+    /// Sets or clears the value of `service`.
+    pub fn set_or_clear_service<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.service = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `audit_log_configs`.
-    pub fn set_audit_log_configs<T: Into<Vec<crate::model::AuditLogConfig>>>(mut self, v: T) -> Self {
-        self.audit_log_configs = v.into();
+    pub fn set_audit_log_configs<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::AuditLogConfig>,
+    {
+        self.audit_log_configs = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `audit_log_configs`.
+    pub fn add_audit_log_configs<T: Into<crate::model::AuditLogConfig>>(mut self, v: T) -> Self {
+        self.audit_log_configs.push(v.into());
         self
     }
 }
@@ -1884,9 +2484,25 @@ impl AuditLogConfig {
         self
     }
 
+    /// Sets or clears the value of `log_type`.
+    pub fn set_or_clear_log_type<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.log_type = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `exempted_members`.
-    pub fn set_exempted_members<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.exempted_members = v.into();
+    pub fn set_exempted_members<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.exempted_members = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `exempted_members`.
+    pub fn add_exempted_members<T: Into<String>>(mut self, v: T) -> Self {
+        self.exempted_members.push(v.into());
         self
     }
 }
@@ -1927,8 +2543,18 @@ pub struct TestIamPermissionsRequest {
 impl TestIamPermissionsRequest {
 
     /// Sets the value of `permissions`.
-    pub fn set_permissions<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.permissions = v.into();
+    pub fn set_permissions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.permissions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `permissions`.
+    pub fn add_permissions<T: Into<String>>(mut self, v: T) -> Self {
+        self.permissions.push(v.into());
         self
     }
 
@@ -1967,8 +2593,18 @@ pub struct TestIamPermissionsResponse {
 impl TestIamPermissionsResponse {
 
     /// Sets the value of `permissions`.
-    pub fn set_permissions<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.permissions = v.into();
+    pub fn set_permissions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.permissions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `permissions`.
+    pub fn add_permissions<T: Into<String>>(mut self, v: T) -> Self {
+        self.permissions.push(v.into());
         self
     }
 }
@@ -2017,17 +2653,35 @@ impl ListLocationsRequest {
         self
     }
 
+    /// Sets or clears the value of `filter`.
+    pub fn set_or_clear_filter<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.filter = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_size`.
     pub fn set_page_size<T: Into<Option<i32>>>(mut self, v: T) -> Self {
         self.page_size = v.into();
         self
     }
 
+    /// Sets or clears the value of `page_size`.
+    pub fn set_or_clear_page_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.page_size = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_token`.
     pub fn set_page_token<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.page_token = v.into();
         self
     }
+
+    /// Sets or clears the value of `page_token`.
+    pub fn set_or_clear_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.page_token = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for GetLocation.
@@ -2112,17 +2766,35 @@ impl ListSecretsRequest {
         self
     }
 
+    /// Sets or clears the value of `page_size`.
+    pub fn set_or_clear_page_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.page_size = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_token`.
     pub fn set_page_token<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.page_token = v.into();
         self
     }
 
+    /// Sets or clears the value of `page_token`.
+    pub fn set_or_clear_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `filter`.
     pub fn set_filter<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.filter = v.into();
         self
     }
+
+    /// Sets or clears the value of `filter`.
+    pub fn set_or_clear_filter<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.filter = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for CreateSecret.
@@ -2159,6 +2831,12 @@ impl CreateSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `request_body`.
+    pub fn set_or_clear_request_body<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.request_body = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -2231,17 +2909,35 @@ impl ListSecretsByProjectAndLocationRequest {
         self
     }
 
+    /// Sets or clears the value of `page_size`.
+    pub fn set_or_clear_page_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.page_size = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_token`.
     pub fn set_page_token<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.page_token = v.into();
         self
     }
 
+    /// Sets or clears the value of `page_token`.
+    pub fn set_or_clear_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `filter`.
     pub fn set_filter<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.filter = v.into();
         self
     }
+
+    /// Sets or clears the value of `filter`.
+    pub fn set_or_clear_filter<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.filter = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for CreateSecretByProjectAndLocation.
@@ -2284,6 +2980,12 @@ impl CreateSecretByProjectAndLocationRequest {
         self
     }
 
+    /// Sets or clears the value of `request_body`.
+    pub fn set_or_clear_request_body<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.request_body = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -2383,6 +3085,12 @@ impl DeleteSecretRequest {
         self.etag = v.into();
         self
     }
+
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for UpdateSecret.
@@ -2421,6 +3129,12 @@ impl UpdateSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `request_body`.
+    pub fn set_or_clear_request_body<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.request_body = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -2544,6 +3258,12 @@ impl DeleteSecretByProjectAndLocationAndSecretRequest {
         self.etag = v.into();
         self
     }
+
+    /// Sets or clears the value of `etag`.
+    pub fn set_or_clear_etag<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.etag = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for UpdateSecretByProjectAndLocationAndSecret.
@@ -2588,6 +3308,12 @@ impl UpdateSecretByProjectAndLocationAndSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `request_body`.
+    pub fn set_or_clear_request_body<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.request_body = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `project`.
     pub fn set_project<T: Into<String>>(mut self, v: T) -> Self {
         self.project = v.into();
@@ -2672,17 +3398,35 @@ impl ListSecretVersionsRequest {
         self
     }
 
+    /// Sets or clears the value of `page_size`.
+    pub fn set_or_clear_page_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.page_size = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_token`.
     pub fn set_page_token<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.page_token = v.into();
         self
     }
 
+    /// Sets or clears the value of `page_token`.
+    pub fn set_or_clear_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `filter`.
     pub fn set_filter<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.filter = v.into();
         self
     }
+
+    /// Sets or clears the value of `filter`.
+    pub fn set_or_clear_filter<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.filter = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for ListSecretVersionsByProjectAndLocationAndSecret.
@@ -2756,17 +3500,35 @@ impl ListSecretVersionsByProjectAndLocationAndSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `page_size`.
+    pub fn set_or_clear_page_size<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.page_size = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `page_token`.
     pub fn set_page_token<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.page_token = v.into();
         self
     }
 
+    /// Sets or clears the value of `page_token`.
+    pub fn set_or_clear_page_token<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.page_token = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `filter`.
     pub fn set_filter<T: Into<Option<String>>>(mut self, v: T) -> Self {
         self.filter = v.into();
         self
     }
+
+    /// Sets or clears the value of `filter`.
+    pub fn set_or_clear_filter<T: Into<String>>(mut self, v: Option<T>) -> Self {
+        self.filter = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for GetSecretVersion.
@@ -3041,6 +3803,12 @@ impl GetIamPolicyRequest {
         self.options_requested_policy_version = v.into();
         self
     }
+
+    /// Sets or clears the value of `options_requested_policy_version`.
+    pub fn set_or_clear_options_requested_policy_version<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.options_requested_policy_version = v.map(|x| x.into());
+        self
+    }
 }
 
 /// The request message for GetIamPolicyByProjectAndLocationAndSecret.
@@ -3115,4 +3883,10 @@ impl GetIamPolicyByProjectAndLocationAndSecretRequest {
         self.options_requested_policy_version = v.into();
         self
     }
+
+    /// Sets or clears the value of `options_requested_policy_version`.
+    pub fn set_or_clear_options_requested_policy_version<T: Into<i32>>(mut self, v: Option<T>) -> Self {
+        self.options_requested_policy_version = v.map(|x| x.into());
+        self
+    }
 }
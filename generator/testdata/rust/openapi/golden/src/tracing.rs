@@ -319,5 +319,8 @@ where T: crate::traits::SecretManagerService + std::fmt::Debug + Send + Sync {
         self.inner.test_iam_permissions_by_project_and_location_and_secret(req, options).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
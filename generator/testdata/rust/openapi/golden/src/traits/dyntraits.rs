@@ -209,6 +209,7 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
         options: gax::options::RequestOptions
     ) -> crate::Result<crate::model::TestIamPermissionsResponse>;
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::SecretManagerService] also implement [SecretManagerService].
@@ -502,4 +503,8 @@ impl<T: crate::traits::SecretManagerService> SecretManagerService for T {
         T::test_iam_permissions_by_project_and_location_and_secret(self, req, options).await
     }
 
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
@@ -691,5 +691,8 @@ impl crate::traits::SecretManagerService for SecretManagerService {
         ).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
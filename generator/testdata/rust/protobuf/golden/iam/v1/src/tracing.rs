@@ -58,5 +58,8 @@ where T: crate::traits::IAMPolicy + std::fmt::Debug + Send + Sync {
         self.inner.test_iam_permissions(req, options).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
@@ -35,6 +35,7 @@ pub trait IAMPolicy: std::fmt::Debug + Send + Sync {
         options: gax::options::RequestOptions
     ) -> crate::Result<crate::model::TestIamPermissionsResponse>;
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::IAMPolicy] also implement [IAMPolicy].
@@ -67,4 +68,8 @@ impl<T: crate::traits::IAMPolicy> IAMPolicy for T {
         T::test_iam_permissions(self, req, options).await
     }
 
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
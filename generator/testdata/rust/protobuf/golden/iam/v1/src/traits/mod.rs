@@ -90,5 +90,12 @@ pub trait IAMPolicy: std::fmt::Debug + Send + Sync {
     ) -> impl std::future::Future<Output = crate::Result<crate::model::TestIamPermissionsResponse>> + Send {
         std::future::ready::<crate::Result<crate::model::TestIamPermissionsResponse>>(Err(Error::other("unimplemented")))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
 
@@ -51,6 +51,9 @@ impl crate::traits::IAMPolicy for IAMPolicy {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -69,6 +72,9 @@ impl crate::traits::IAMPolicy for IAMPolicy {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -87,6 +93,9 @@ impl crate::traits::IAMPolicy for IAMPolicy {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -94,5 +103,8 @@ impl crate::traits::IAMPolicy for IAMPolicy {
         ).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
@@ -58,6 +58,14 @@ impl Locations {
         Self { inner: Arc::new(stub) }
     }
 
+    /// Returns a redacted snapshot of the client configuration.
+    ///
+    /// Applications can include this snapshot in bug reports. Clients
+    /// created via [from_stub][Self::from_stub] may return `None`.
+    pub fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
+
     async fn build_inner(conf: gax::options::ClientConfig) -> Result<Arc<dyn crate::traits::dyntraits::Locations>> {
         if conf.tracing_enabled() {
             return Ok(Arc::new(Self::build_with_tracing(conf).await?));
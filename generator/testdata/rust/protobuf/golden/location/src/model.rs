@@ -90,8 +90,18 @@ pub struct ListLocationsResponse {
 impl ListLocationsResponse {
 
     /// Sets the value of `locations`.
-    pub fn set_locations<T: Into<Vec<crate::model::Location>>>(mut self, v: T) -> Self {
-        self.locations = v.into();
+    pub fn set_locations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Location>,
+    {
+        self.locations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `locations`.
+    pub fn add_locations<T: Into<crate::model::Location>>(mut self, v: T) -> Self {
+        self.locations.push(v.into());
         self
     }
 
@@ -194,8 +204,19 @@ impl Location {
     }
 
     /// Sets the value of `labels`.
-    pub fn set_labels<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.labels = v.into();
+    pub fn set_labels<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `labels`.
+    pub fn insert_labels<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.labels.insert(k.into(), v.into());
         self
     }
 
@@ -204,4 +225,10 @@ impl Location {
         self.metadata = v.into();
         self
     }
+
+    /// Sets or clears the value of `metadata`.
+    pub fn set_or_clear_metadata<T: Into<wkt::Any>>(mut self, v: Option<T>) -> Self {
+        self.metadata = v.map(|x| x.into());
+        self
+    }
 }
@@ -49,5 +49,8 @@ where T: crate::traits::Locations + std::fmt::Debug + Send + Sync {
         self.inner.get_location(req, options).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
@@ -29,6 +29,7 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
         options: gax::options::RequestOptions
     ) -> crate::Result<crate::model::Location>;
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::Locations] also implement [Locations].
@@ -52,4 +53,8 @@ impl<T: crate::traits::Locations> Locations for T {
         T::get_location(self, req, options).await
     }
 
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
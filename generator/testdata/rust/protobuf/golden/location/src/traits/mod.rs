@@ -50,5 +50,12 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Location>> + Send {
         std::future::ready::<crate::Result<crate::model::Location>>(Err(Error::other("unimplemented")))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
 
@@ -45,12 +45,35 @@ impl crate::traits::Locations for Locations {
         req: crate::model::ListLocationsRequest,
         options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListLocationsResponse> {
+        let primary_matches = gax::path_parameter::matches(&req.name, "locations");
+        if !primary_matches && gax::path_parameter::matches(&req.name, "projects/*") {
+            let builder = self.inner.builder(
+                reqwest::Method::GET, format!("/v1/{}/locations"
+                   , req.name
+                ))
+                .query(&[("alt", "json")])
+                .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+            let builder = gax::routing_parameter::RoutingParameters::new()
+                .add("name", &req.name)
+                .apply(builder);
+            let builder = gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
+            let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
+            let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token).map_err(Error::other)?;
+            return self.inner.execute(
+                builder,
+                None::<gax::http_client::NoBody>,
+                options,
+            ).await;
+        }
         let builder = self.inner.builder(
             reqwest::Method::GET, format!("/v1/{}"
                , req.name
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token).map_err(Error::other)?;
@@ -72,6 +95,9 @@ impl crate::traits::Locations for Locations {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             None::<gax::http_client::NoBody>,
@@ -79,5 +105,8 @@ impl crate::traits::Locations for Locations {
         ).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
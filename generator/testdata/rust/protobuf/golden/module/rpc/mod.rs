@@ -95,8 +95,19 @@ impl ErrorInfo {
     }
 
     /// Sets the value of `metadata`.
-    pub fn set_metadata<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.metadata = v.into();
+    pub fn set_metadata<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `metadata`.
+    pub fn insert_metadata<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.metadata.insert(k.into(), v.into());
         self
     }
 }
@@ -132,6 +143,24 @@ impl RetryInfo {
         self.retry_delay = v.into();
         self
     }
+
+    /// Sets or clears the value of `retry_delay`.
+    pub fn set_or_clear_retry_delay<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.retry_delay = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `retry_delay` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_retry_delay<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.retry_delay = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// Describes additional debugging info.
@@ -153,8 +182,18 @@ pub struct DebugInfo {
 impl DebugInfo {
 
     /// Sets the value of `stack_entries`.
-    pub fn set_stack_entries<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.stack_entries = v.into();
+    pub fn set_stack_entries<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.stack_entries = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `stack_entries`.
+    pub fn add_stack_entries<T: Into<String>>(mut self, v: T) -> Self {
+        self.stack_entries.push(v.into());
         self
     }
 
@@ -190,8 +229,18 @@ pub struct QuotaFailure {
 impl QuotaFailure {
 
     /// Sets the value of `violations`.
-    pub fn set_violations<T: Into<Vec<crate::error::rpc::generated::quota_failure::Violation>>>(mut self, v: T) -> Self {
-        self.violations = v.into();
+    pub fn set_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::error::rpc::generated::quota_failure::Violation>,
+    {
+        self.violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `violations`.
+    pub fn add_violations<T: Into<crate::error::rpc::generated::quota_failure::Violation>>(mut self, v: T) -> Self {
+        self.violations.push(v.into());
         self
     }
 }
@@ -259,8 +308,18 @@ pub struct PreconditionFailure {
 impl PreconditionFailure {
 
     /// Sets the value of `violations`.
-    pub fn set_violations<T: Into<Vec<crate::error::rpc::generated::precondition_failure::Violation>>>(mut self, v: T) -> Self {
-        self.violations = v.into();
+    pub fn set_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::error::rpc::generated::precondition_failure::Violation>,
+    {
+        self.violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `violations`.
+    pub fn add_violations<T: Into<crate::error::rpc::generated::precondition_failure::Violation>>(mut self, v: T) -> Self {
+        self.violations.push(v.into());
         self
     }
 }
@@ -334,8 +393,18 @@ pub struct BadRequest {
 impl BadRequest {
 
     /// Sets the value of `field_violations`.
-    pub fn set_field_violations<T: Into<Vec<crate::error::rpc::generated::bad_request::FieldViolation>>>(mut self, v: T) -> Self {
-        self.field_violations = v.into();
+    pub fn set_field_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::error::rpc::generated::bad_request::FieldViolation>,
+    {
+        self.field_violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `field_violations`.
+    pub fn add_field_violations<T: Into<crate::error::rpc::generated::bad_request::FieldViolation>>(mut self, v: T) -> Self {
+        self.field_violations.push(v.into());
         self
     }
 }
@@ -532,8 +601,18 @@ pub struct Help {
 impl Help {
 
     /// Sets the value of `links`.
-    pub fn set_links<T: Into<Vec<crate::error::rpc::generated::help::Link>>>(mut self, v: T) -> Self {
-        self.links = v.into();
+    pub fn set_links<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::error::rpc::generated::help::Link>,
+    {
+        self.links = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `links`.
+    pub fn add_links<T: Into<crate::error::rpc::generated::help::Link>>(mut self, v: T) -> Self {
+        self.links.push(v.into());
         self
     }
 }
@@ -0,0 +1,147 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+
+use crate::Result;
+use std::sync::Arc;
+
+/// Common implementation for [crate::client::Foos] request builders.
+#[derive(Clone, Debug)]
+pub struct FoosRequestBuilder<R: std::default::Default> {
+    stub: Arc<dyn crate::traits::dyntraits::Foos>,
+    request: R,
+    options: gax::options::RequestOptions,
+}
+
+impl<R> FoosRequestBuilder<R>
+where R: std::default::Default {
+    pub(crate) fn new(stub: Arc<dyn crate::traits::dyntraits::Foos>) -> Self {
+        Self {
+            stub,
+            request: R::default(),
+            options: gax::options::RequestOptions::default(),
+        }
+    }
+}
+
+/// The request builder for a Foos::get_foo call.
+#[derive(Clone, Debug)]
+pub struct GetFoo(FoosRequestBuilder<crate::model::GetFooRequest>);
+
+impl GetFoo {
+    pub(crate) fn new(stub: Arc<dyn crate::traits::dyntraits::Foos>) -> Self {
+        Self(
+            FoosRequestBuilder::new(stub)
+        )
+    }
+
+    /// Set the full request.
+    pub fn with_request<V: Into<crate::model::GetFooRequest>>(mut self, v: V) -> Self {
+        self.0.request = v.into();
+        self
+    }
+
+    /// Sends the request.
+    pub async fn send(self) -> Result<crate::model::Foo> {
+        self.0.stub.get_foo(self.0.request, self.0.options).await
+    }
+
+    /// Sets the value of `name`.
+    pub fn set_name<T: Into<String>>(mut self, v: T) -> Self {
+        self.0.request.name = v.into();
+        self
+    }
+}
+
+impl gax::options::RequestBuilder for GetFoo {
+    fn request_options(&mut self) -> &mut gax::options::RequestOptions {
+        &mut self.0.options
+    }
+}
+
+/// The request builder for a Foos::get_foo_preview call.
+#[cfg(feature = "preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "preview")))]
+#[derive(Clone, Debug)]
+pub struct GetFooPreview(FoosRequestBuilder<crate::model::GetFooRequest>);
+
+#[cfg(feature = "preview")]
+impl GetFooPreview {
+    pub(crate) fn new(stub: Arc<dyn crate::traits::dyntraits::Foos>) -> Self {
+        Self(
+            FoosRequestBuilder::new(stub)
+        )
+    }
+
+    /// Set the full request.
+    pub fn with_request<V: Into<crate::model::GetFooRequest>>(mut self, v: V) -> Self {
+        self.0.request = v.into();
+        self
+    }
+
+    /// Sends the request.
+    pub async fn send(self) -> Result<crate::model::Foo> {
+        self.0.stub.get_foo_preview(self.0.request, self.0.options).await
+    }
+
+    /// Sets the value of `name`.
+    pub fn set_name<T: Into<String>>(mut self, v: T) -> Self {
+        self.0.request.name = v.into();
+        self
+    }
+}
+
+#[cfg(feature = "preview")]
+impl gax::options::RequestBuilder for GetFooPreview {
+    fn request_options(&mut self) -> &mut gax::options::RequestOptions {
+        &mut self.0.options
+    }
+}
+
+/// The request builder for a Foos::get_foo_internal call.
+#[derive(Clone, Debug)]
+pub struct GetFooInternal(FoosRequestBuilder<crate::model::GetFooRequest>);
+
+impl GetFooInternal {
+    pub(crate) fn new(stub: Arc<dyn crate::traits::dyntraits::Foos>) -> Self {
+        Self(
+            FoosRequestBuilder::new(stub)
+        )
+    }
+
+    /// Set the full request.
+    pub fn with_request<V: Into<crate::model::GetFooRequest>>(mut self, v: V) -> Self {
+        self.0.request = v.into();
+        self
+    }
+
+    /// Sends the request.
+    pub async fn send(self) -> Result<crate::model::Foo> {
+        self.0.stub.get_foo_internal(self.0.request, self.0.options).await
+    }
+
+    /// Sets the value of `name`.
+    pub fn set_name<T: Into<String>>(mut self, v: T) -> Self {
+        self.0.request.name = v.into();
+        self
+    }
+}
+
+impl gax::options::RequestBuilder for GetFooInternal {
+    fn request_options(&mut self) -> &mut gax::options::RequestOptions {
+        &mut self.0.options
+    }
+}
+
@@ -0,0 +1,112 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+
+use crate::Result;
+use std::sync::Arc;
+
+/// An implementation of [crate::traits::Foos] to make requests with.
+///
+/// `Foos` has various configuration parameters, but the defaults
+/// are set to work with most applications.
+///
+/// `Foos` holds a connection pool internally, it is advised to
+/// create one and the reuse it.  You do not need to wrap `Foos` in
+/// an [Rc](std::rc::Rc) or [Arc] to reuse it, because it already uses an `Arc`
+/// internally.
+///
+/// A service with preview and internal methods.
+#[derive(Clone, Debug)]
+pub struct Foos {
+    inner: Arc<dyn crate::traits::dyntraits::Foos>,
+}
+
+impl Foos {
+    /// Creates a new client with the default configuration.
+    pub async fn new() -> Result<Self> {
+        Self::new_with_config(gax::options::ClientConfig::default()).await
+    }
+
+    /// Creates a new client with the specified configuration.
+    pub async fn new_with_config(conf: gax::options::ClientConfig) -> Result<Self> {
+        let inner = Self::build_inner(conf).await?;
+        Ok(Self { inner }) 
+    }
+
+    /// Creates a new client from the provided stub.
+    ///
+    /// The most common case for calling this function is when mocking the
+    /// client.
+    pub fn from_stub<T>(stub: T) -> Self
+    where T: crate::traits::Foos + 'static {
+        Self { inner: Arc::new(stub) }
+    }
+
+    /// Returns a redacted snapshot of the client configuration.
+    ///
+    /// Applications can include this snapshot in bug reports. Clients
+    /// created via [from_stub][Self::from_stub] may return `None`.
+    pub fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
+
+    async fn build_inner(conf: gax::options::ClientConfig) -> Result<Arc<dyn crate::traits::dyntraits::Foos>> {
+        if conf.tracing_enabled() {
+            return Ok(Arc::new(Self::build_with_tracing(conf).await?));
+        }
+        Ok(Arc::new(Self::build_transport(conf).await?))
+    }
+
+    async fn build_transport(conf: gax::options::ClientConfig) -> Result<impl crate::traits::Foos> {
+        crate::transport::Foos::new(conf).await
+    }
+
+    async fn build_with_tracing(conf: gax::options::ClientConfig) -> Result<impl crate::traits::Foos> {
+        Self::build_transport(conf).await.map(crate::tracing::Foos::new)
+    }
+
+    /// Gets a foo.
+    pub fn get_foo(
+        &self,
+        name: impl Into<String>,
+    ) -> crate::builders::GetFoo
+    {
+        crate::builders::GetFoo::new(self.inner.clone())
+            .set_name ( name.into() )
+    }
+
+    /// Gets a foo, including the fields in preview.
+    #[cfg(feature = "preview")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "preview")))]
+    pub fn get_foo_preview(
+        &self,
+        name: impl Into<String>,
+    ) -> crate::builders::GetFooPreview
+    {
+        crate::builders::GetFooPreview::new(self.inner.clone())
+            .set_name ( name.into() )
+    }
+
+    /// Gets a foo, including the internal fields.
+    pub fn get_foo_internal(
+        &self,
+        name: impl Into<String>,
+    ) -> crate::builders::GetFooInternal
+    {
+        crate::builders::GetFooInternal::new(self.inner.clone())
+            .set_name ( name.into() )
+    }
+
+}
@@ -0,0 +1,66 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+use crate::Result;
+
+/// Implements a [Foos](crate::traits::) decorator for logging and tracing.
+#[derive(Clone, Debug)]
+pub struct Foos<T>
+where T: crate::traits::Foos + std::fmt::Debug + Send + Sync {
+    inner: T,
+}
+
+impl<T> Foos<T>
+where T: crate::traits::Foos + std::fmt::Debug + Send + Sync {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> crate::traits::Foos for Foos<T>
+where T: crate::traits::Foos + std::fmt::Debug + Send + Sync {
+    #[tracing::instrument(ret)]
+    async fn get_foo(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> Result<crate::model::Foo> {
+        self.inner.get_foo(req, options).await
+    }
+
+    #[cfg(feature = "preview")]
+    #[tracing::instrument(ret)]
+    async fn get_foo_preview(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> Result<crate::model::Foo> {
+        self.inner.get_foo_preview(req, options).await
+    }
+
+    #[tracing::instrument(ret)]
+    async fn get_foo_internal(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> Result<crate::model::Foo> {
+        self.inner.get_foo_internal(req, options).await
+    }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
+}
+
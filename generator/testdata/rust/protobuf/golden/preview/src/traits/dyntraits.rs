@@ -0,0 +1,77 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+
+/// A dyn-compatible, crate-private version of `Foos`.
+#[async_trait::async_trait]
+pub trait Foos: std::fmt::Debug + Send + Sync {
+    async fn get_foo(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo>;
+
+    #[cfg(feature = "preview")]
+    async fn get_foo_preview(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo>;
+
+    async fn get_foo_internal(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
+}
+
+/// All implementations of [crate::traits::Foos] also implement [Foos].
+#[async_trait::async_trait]
+impl<T: crate::traits::Foos> Foos for T {
+    /// Forwards the call to the implementation provided by `T`.
+    async fn get_foo(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo> {
+        T::get_foo(self, req, options).await
+    }
+
+    #[cfg(feature = "preview")]
+    /// Forwards the call to the implementation provided by `T`.
+    async fn get_foo_preview(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo> {
+        T::get_foo_preview(self, req, options).await
+    }
+
+    /// Forwards the call to the implementation provided by `T`.
+    async fn get_foo_internal(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions
+    ) -> crate::Result<crate::model::Foo> {
+        T::get_foo_internal(self, req, options).await
+    }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+
+use gax::error::Error;
+
+pub(crate) mod dyntraits;
+
+/// A service with preview and internal methods.
+///
+/// # Mocking
+///
+/// Application developers may use this trait to mock the  clients.
+///
+/// Services gain new RPCs routinely. Consequently, this trait gains new methods
+/// too. To avoid breaking applications the trait provides a default
+/// implementation for each method. These implementations return an error.
+pub trait Foos: std::fmt::Debug + Send + Sync {
+
+    /// Gets a foo.
+    fn get_foo(
+        &self,
+        _req: crate::model::GetFooRequest,
+        _options: gax::options::RequestOptions
+    ) -> impl std::future::Future<Output = crate::Result<crate::model::Foo>> + Send {
+        std::future::ready::<crate::Result<crate::model::Foo>>(Err(Error::other("unimplemented")))
+    }
+
+    /// Gets a foo, including the fields in preview.
+    #[cfg(feature = "preview")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "preview")))]
+    fn get_foo_preview(
+        &self,
+        _req: crate::model::GetFooRequest,
+        _options: gax::options::RequestOptions
+    ) -> impl std::future::Future<Output = crate::Result<crate::model::Foo>> + Send {
+        std::future::ready::<crate::Result<crate::model::Foo>>(Err(Error::other("unimplemented")))
+    }
+
+    /// Gets a foo, including the internal fields.
+    fn get_foo_internal(
+        &self,
+        _req: crate::model::GetFooRequest,
+        _options: gax::options::RequestOptions
+    ) -> impl std::future::Future<Output = crate::Result<crate::model::Foo>> + Send {
+        std::future::ready::<crate::Result<crate::model::Foo>>(Err(Error::other("unimplemented")))
+    }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
+}
+
@@ -0,0 +1,111 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Code generated by sidekick. DO NOT EDIT.
+
+use crate::Result;
+#[allow(unused_imports)]
+use gax::error::Error;
+
+/// Implements [Foos](crate::traits::) using a [gax::http_client::ReqwestClient].
+#[derive(Clone)]
+pub struct Foos {
+    inner: gax::http_client::ReqwestClient,
+}
+
+impl std::fmt::Debug for Foos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.debug_struct("Foos")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Foos {
+    pub async fn new(config: gax::http_client::ClientConfig) -> Result<Self> {
+        let inner = gax::http_client::ReqwestClient::new(config, crate::DEFAULT_HOST).await?;
+        Ok(Self { inner })
+    }
+}
+
+impl crate::traits::Foos for Foos {
+    async fn get_foo(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::Foo> {
+        let builder = self.inner.builder(
+            reqwest::Method::GET, format!("/v1/{}"
+               , req.name
+            ))
+            .query(&[("alt", "json")])
+            .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
+        self.inner.execute(
+            builder,
+            None::<gax::http_client::NoBody>,
+            options,
+        ).await
+    }
+
+    #[cfg(feature = "preview")]
+    async fn get_foo_preview(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::Foo> {
+        let builder = self.inner.builder(
+            reqwest::Method::GET, format!("/v1/{}:preview"
+               , req.name
+            ))
+            .query(&[("alt", "json")])
+            .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
+        self.inner.execute(
+            builder,
+            None::<gax::http_client::NoBody>,
+            options,
+        ).await
+    }
+
+    async fn get_foo_internal(
+        &self,
+        req: crate::model::GetFooRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<crate::model::Foo> {
+        let builder = self.inner.builder(
+            reqwest::Method::GET, format!("/v1/{}:internal"
+               , req.name
+            ))
+            .query(&[("alt", "json")])
+            .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
+        self.inner.execute(
+            builder,
+            None::<gax::http_client::NoBody>,
+            options,
+        ).await
+    }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
+}
+
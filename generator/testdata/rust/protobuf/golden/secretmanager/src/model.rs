@@ -176,21 +176,66 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `replication`.
+    pub fn set_or_clear_replication<T: Into<crate::model::Replication>>(mut self, v: Option<T>) -> Self {
+        self.replication = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `create_time`.
     pub fn set_create_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.create_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `labels`.
-    pub fn set_labels<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.labels = v.into();
+    pub fn set_labels<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `labels`.
+    pub fn insert_labels<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.labels.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `topics`.
-    pub fn set_topics<T: Into<Vec<crate::model::Topic>>>(mut self, v: T) -> Self {
-        self.topics = v.into();
+    pub fn set_topics<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Topic>,
+    {
+        self.topics = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `topics`.
+    pub fn add_topics<T: Into<crate::model::Topic>>(mut self, v: T) -> Self {
+        self.topics.push(v.into());
         self
     }
 
@@ -206,15 +251,43 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `rotation`.
+    pub fn set_or_clear_rotation<T: Into<crate::model::Rotation>>(mut self, v: Option<T>) -> Self {
+        self.rotation = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `version_aliases`.
-    pub fn set_version_aliases<T: Into<std::collections::HashMap<String,i64>>>(mut self, v: T) -> Self {
-        self.version_aliases = v.into();
+    pub fn set_version_aliases<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<i64>,
+    {
+        self.version_aliases = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `version_aliases`.
+    pub fn insert_version_aliases<K: Into<String>, V: Into<i64>>(mut self, k: K, v: V) -> Self {
+        self.version_aliases.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `annotations`.
-    pub fn set_annotations<T: Into<std::collections::HashMap<String,String>>>(mut self, v: T) -> Self {
-        self.annotations = v.into();
+    pub fn set_annotations<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.annotations = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `annotations`.
+    pub fn insert_annotations<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.annotations.insert(k.into(), v.into());
         self
     }
 
@@ -224,12 +297,36 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `version_destroy_ttl`.
+    pub fn set_or_clear_version_destroy_ttl<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.version_destroy_ttl = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `version_destroy_ttl` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_version_destroy_ttl<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.version_destroy_ttl = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryption>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
 
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `expiration`.
     pub fn set_expiration<T: Into<Option<crate::model::secret::Expiration>>>(mut self, v: T) ->Self {
         self.expiration = v.into();
@@ -377,12 +474,48 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `destroy_time`.
     pub fn set_destroy_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.destroy_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `destroy_time`.
+    pub fn set_or_clear_destroy_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `state`.
     pub fn set_state<T: Into<crate::model::secret_version::State>>(mut self, v: T) -> Self {
         self.state = v.into();
@@ -395,6 +528,12 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `replication_status`.
+    pub fn set_or_clear_replication_status<T: Into<crate::model::ReplicationStatus>>(mut self, v: Option<T>) -> Self {
+        self.replication_status = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `etag`.
     pub fn set_etag<T: Into<String>>(mut self, v: T) -> Self {
         self.etag = v.into();
@@ -413,11 +552,35 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `scheduled_destroy_time`.
+    pub fn set_or_clear_scheduled_destroy_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.scheduled_destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `scheduled_destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_scheduled_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.scheduled_destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<T: Into<Option<crate::model::CustomerManagedEncryptionStatus>>>(mut self, v: T) -> Self {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Defines additional types related to SecretVersion
@@ -533,6 +696,12 @@ pub mod replication {
             self.customer_managed_encryption = v.into();
             self
         }
+
+        /// Sets or clears the value of `customer_managed_encryption`.
+        pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+            self.customer_managed_encryption = v.map(|x| x.into());
+            self
+        }
     }
 
     /// A replication policy that replicates the
@@ -559,8 +728,18 @@ pub mod replication {
     impl UserManaged {
 
         /// Sets the value of `replicas`.
-        pub fn set_replicas<T: Into<Vec<crate::model::replication::user_managed::Replica>>>(mut self, v: T) -> Self {
-            self.replicas = v.into();
+        pub fn set_replicas<T, V>(mut self, v: T) -> Self
+        where
+            T: IntoIterator<Item = V>,
+            V: Into<crate::model::replication::user_managed::Replica>,
+        {
+            self.replicas = v.into_iter().map(|i| i.into()).collect();
+            self
+        }
+
+        /// Appends a value to `replicas`.
+        pub fn add_replicas<T: Into<crate::model::replication::user_managed::Replica>>(mut self, v: T) -> Self {
+            self.replicas.push(v.into());
             self
         }
     }
@@ -612,6 +791,12 @@ pub mod replication {
                 self.customer_managed_encryption = v.into();
                 self
             }
+
+            /// Sets or clears the value of `customer_managed_encryption`.
+            pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryption>>(mut self, v: Option<T>) -> Self {
+                self.customer_managed_encryption = v.map(|x| x.into());
+                self
+            }
         }
     }
 
@@ -731,6 +916,12 @@ pub mod replication_status {
             self.customer_managed_encryption = v.into();
             self
         }
+
+        /// Sets or clears the value of `customer_managed_encryption`.
+        pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+            self.customer_managed_encryption = v.map(|x| x.into());
+            self
+        }
     }
 
     /// The replication status of a
@@ -759,8 +950,18 @@ pub mod replication_status {
     impl UserManagedStatus {
 
         /// Sets the value of `replicas`.
-        pub fn set_replicas<T: Into<Vec<crate::model::replication_status::user_managed_status::ReplicaStatus>>>(mut self, v: T) -> Self {
-            self.replicas = v.into();
+        pub fn set_replicas<T, V>(mut self, v: T) -> Self
+        where
+            T: IntoIterator<Item = V>,
+            V: Into<crate::model::replication_status::user_managed_status::ReplicaStatus>,
+        {
+            self.replicas = v.into_iter().map(|i| i.into()).collect();
+            self
+        }
+
+        /// Appends a value to `replicas`.
+        pub fn add_replicas<T: Into<crate::model::replication_status::user_managed_status::ReplicaStatus>>(mut self, v: T) -> Self {
+            self.replicas.push(v.into());
             self
         }
     }
@@ -805,6 +1006,12 @@ pub mod replication_status {
                 self.customer_managed_encryption = v.into();
                 self
             }
+
+            /// Sets or clears the value of `customer_managed_encryption`.
+            pub fn set_or_clear_customer_managed_encryption<T: Into<crate::model::CustomerManagedEncryptionStatus>>(mut self, v: Option<T>) -> Self {
+                self.customer_managed_encryption = v.map(|x| x.into());
+                self
+            }
         }
     }
 
@@ -946,11 +1153,47 @@ impl Rotation {
         self
     }
 
+    /// Sets or clears the value of `next_rotation_time`.
+    pub fn set_or_clear_next_rotation_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.next_rotation_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `next_rotation_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_next_rotation_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.next_rotation_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `rotation_period`.
     pub fn set_rotation_period<T: Into<Option<wkt::Duration>>>(mut self, v: T) -> Self {
         self.rotation_period = v.into();
         self
     }
+
+    /// Sets or clears the value of `rotation_period`.
+    pub fn set_or_clear_rotation_period<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.rotation_period = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `rotation_period` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_rotation_period<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.rotation_period = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// A secret payload resource in the Secret Manager API. This contains the
@@ -966,7 +1209,7 @@ pub struct SecretPayload {
 
     /// The secret data. Must be no larger than 64KiB.
     #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde_as(as = "wkt::internal::Base64")]
     pub data: bytes::Bytes,
 
     /// Optional. If specified,
@@ -1009,6 +1252,12 @@ impl SecretPayload {
         self.data_crc32c = v.into();
         self
     }
+
+    /// Sets or clears the value of `data_crc32c`.
+    pub fn set_or_clear_data_crc32c<T: Into<i64>>(mut self, v: Option<T>) -> Self {
+        self.data_crc32c = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1115,8 +1364,18 @@ pub struct ListSecretsResponse {
 impl ListSecretsResponse {
 
     /// Sets the value of `secrets`.
-    pub fn set_secrets<T: Into<Vec<crate::model::Secret>>>(mut self, v: T) -> Self {
-        self.secrets = v.into();
+    pub fn set_secrets<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Secret>,
+    {
+        self.secrets = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `secrets`.
+    pub fn add_secrets<T: Into<crate::model::Secret>>(mut self, v: T) -> Self {
+        self.secrets.push(v.into());
         self
     }
 
@@ -1199,6 +1458,12 @@ impl CreateSecretRequest {
         self.secret = v.into();
         self
     }
+
+    /// Sets or clears the value of `secret`.
+    pub fn set_or_clear_secret<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.secret = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1242,6 +1507,12 @@ impl AddSecretVersionRequest {
         self.payload = v.into();
         self
     }
+
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(mut self, v: Option<T>) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1377,8 +1648,18 @@ pub struct ListSecretVersionsResponse {
 impl ListSecretVersionsResponse {
 
     /// Sets the value of `versions`.
-    pub fn set_versions<T: Into<Vec<crate::model::SecretVersion>>>(mut self, v: T) -> Self {
-        self.versions = v.into();
+    pub fn set_versions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::SecretVersion>,
+    {
+        self.versions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `versions`.
+    pub fn add_versions<T: Into<crate::model::SecretVersion>>(mut self, v: T) -> Self {
+        self.versions.push(v.into());
         self
     }
 
@@ -1472,11 +1753,23 @@ impl UpdateSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `secret`.
+    pub fn set_or_clear_secret<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.secret = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `update_mask`.
     pub fn set_update_mask<T: Into<Option<wkt::FieldMask>>>(mut self, v: T) -> Self {
         self.update_mask = v.into();
         self
     }
+
+    /// Sets or clears the value of `update_mask`.
+    pub fn set_or_clear_update_mask<T: Into<wkt::FieldMask>>(mut self, v: Option<T>) -> Self {
+        self.update_mask = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1550,6 +1843,12 @@ impl AccessSecretVersionResponse {
         self.payload = v.into();
         self
     }
+
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(mut self, v: Option<T>) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -166,6 +166,9 @@ where T: crate::traits::SecretManagerService + std::fmt::Debug + Send + Sync {
         self.inner.test_iam_permissions(req, options).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
 /// Implements a [Locations](crate::traits::) decorator for logging and tracing.
@@ -202,5 +205,8 @@ where T: crate::traits::Locations + std::fmt::Debug + Send + Sync {
         self.inner.get_location(req, options).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
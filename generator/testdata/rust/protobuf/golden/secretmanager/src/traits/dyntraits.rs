@@ -107,6 +107,7 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
         options: gax::options::RequestOptions
     ) -> crate::Result<iam::model::TestIamPermissionsResponse>;
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::SecretManagerService] also implement [SecretManagerService].
@@ -247,6 +248,10 @@ impl<T: crate::traits::SecretManagerService> SecretManagerService for T {
         T::test_iam_permissions(self, req, options).await
     }
 
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
 
 /// A dyn-compatible, crate-private version of `Locations`.
@@ -264,6 +269,7 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
         options: gax::options::RequestOptions
     ) -> crate::Result<location::model::Location>;
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::Locations] also implement [Locations].
@@ -287,4 +293,8 @@ impl<T: crate::traits::Locations> Locations for T {
         T::get_location(self, req, options).await
     }
 
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
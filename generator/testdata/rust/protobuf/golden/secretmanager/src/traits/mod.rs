@@ -246,6 +246,13 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
     ) -> impl std::future::Future<Output = crate::Result<iam::model::TestIamPermissionsResponse>> + Send {
         std::future::ready::<crate::Result<iam::model::TestIamPermissionsResponse>>(Err(Error::other("unimplemented")))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
 
 /// Manages location-related information with an API service.
@@ -276,5 +283,12 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
     ) -> impl std::future::Future<Output = crate::Result<location::model::Location>> + Send {
         std::future::ready::<crate::Result<location::model::Location>>(Err(Error::other("unimplemented")))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
 
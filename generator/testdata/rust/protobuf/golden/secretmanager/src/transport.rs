@@ -51,6 +51,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
@@ -72,6 +75,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "secretId", &req.secret_id).map_err(Error::other)?;
         self.inner.execute(
             builder,
@@ -91,6 +97,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -109,6 +118,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             None::<gax::http_client::NoBody>,
@@ -127,6 +139,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("secret.name", &gax::path_parameter::PathParameter::required(&req.secret, "secret").map_err(Error::other)?.name)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "updateMask", &serde_json::to_value(&req.update_mask).map_err(Error::serde)?).map_err(Error::other)?;
         self.inner.execute(
             builder,
@@ -146,6 +161,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "etag", &req.etag).map_err(Error::other)?;
         self.inner.execute(
             builder,
@@ -165,6 +183,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
@@ -186,6 +207,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             None::<gax::http_client::NoBody>,
@@ -204,6 +228,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             None::<gax::http_client::NoBody>,
@@ -222,6 +249,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -240,6 +270,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -258,6 +291,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -276,6 +312,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -294,6 +333,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "options", &serde_json::to_value(&req.options).map_err(Error::serde)?).map_err(Error::other)?;
         self.inner.execute(
             builder,
@@ -313,6 +355,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(
             builder,
             Some(req),
@@ -320,6 +365,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
         ).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
 /// Implements [Locations](crate::traits::) using a [gax::http_client::ReqwestClient].
@@ -355,6 +403,9 @@ impl crate::traits::Locations for Locations {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder = gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token).map_err(Error::other)?;
@@ -376,6 +427,9 @@ impl crate::traits::Locations for Locations {
             ))
             .query(&[("alt", "json")])
             .header("x-goog-api-client", reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER));
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(
             builder,
             None::<gax::http_client::NoBody>,
@@ -383,5 +437,8 @@ impl crate::traits::Locations for Locations {
         ).await
     }
 
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
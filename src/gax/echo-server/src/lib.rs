@@ -87,12 +87,19 @@ async fn echo_impl(query: HashMap<String, String>, headers: HeaderMap) -> Result
 async fn error(
     Query(query): Query<HashMap<String, String>>,
     headers: HeaderMap,
-) -> (StatusCode, String) {
+) -> (StatusCode, HeaderMap, String) {
+    // Echo the request id, tests use it to verify the id is stable across
+    // retry attempts.
+    let mut response_headers = HeaderMap::new();
+    if let Some(id) = headers.get("x-goog-request-id") {
+        response_headers.insert("x-goog-request-id", id.clone());
+    }
     let response = error_impl(query, headers).await;
-    match response {
+    let (status, body) = match response {
         Err(e) => internal_error(e),
         Ok(r) => r,
-    }
+    };
+    (status, response_headers, body)
 }
 
 async fn error_impl(
@@ -0,0 +1,375 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the trait for backoff policies and a common implementation.
+//!
+//! When a request fails with a retryable error the client libraries wait for
+//! some time before the next attempt. The backoff policy determines how long
+//! to wait. Increasing the delay between attempts gives the service a chance
+//! to recover from transient problems, and avoids overloading the service
+//! with retry attempts.
+//!
+//! The retry loop does not sleep directly. It receives a `sleep` function from
+//! the client, which uses [tokio::time::sleep][sleep]. Applications can use
+//! [tokio::time::pause()][pause] in their tests, and the backoff delays
+//! complete without waiting for real time to elapse.
+//!
+//! # Example:
+//! ```
+//! # use gcp_sdk_gax::backoff_policy::*;
+//! # use gcp_sdk_gax::options;
+//! use std::time::Duration;
+//! fn customize_backoff_policy(config: options::ClientConfig) -> options::ClientConfig {
+//!     let policy = ExponentialBackoffBuilder::new()
+//!         .with_initial_delay(Duration::from_millis(100))
+//!         .with_maximum_delay(Duration::from_secs(5))
+//!         .build();
+//!     config.set_backoff_policy(policy.unwrap())
+//! }
+//! ```
+//!
+//! [sleep]: https://docs.rs/tokio/latest/tokio/time/fn.sleep.html
+//! [pause]: https://docs.rs/tokio/latest/tokio/time/fn.pause.html
+
+use crate::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Determines how long the retry loop waits before the next attempt.
+///
+/// Implementations of this trait compute the backoff delay after a failed
+/// attempt. The retry loop only queries the policy if the retry policy
+/// determined the error is retryable.
+pub trait BackoffPolicy: Send + Sync + std::fmt::Debug {
+    /// Returns the backoff delay after a failure.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the retry loop started.
+    /// * `attempt_count` - the number of attempts. This includes the initial
+    ///   attempt. This method is called after the first attempt, so the
+    ///   value is always non-zero.
+    fn on_failure(&self, loop_start: std::time::Instant, attempt_count: u32) -> Duration;
+
+    /// Returns the backoff delay after a failure, for a request with a deadline.
+    ///
+    /// The retry loop calls this function instead of `on_failure()` when the
+    /// request has an overall timeout or deadline. The result should not
+    /// exceed `remaining_time`. If the delay reaches the deadline, the retry
+    /// loop stops without sleeping and returns the last error.
+    ///
+    /// The default implementation truncates the result of `on_failure()`.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the retry loop started.
+    /// * `attempt_count` - the number of attempts, as in `on_failure()`.
+    /// * `remaining_time` - the time left before the request deadline.
+    fn on_failure_with_remaining_time(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        remaining_time: Duration,
+    ) -> Duration {
+        self.on_failure(loop_start, attempt_count)
+            .min(remaining_time)
+    }
+}
+
+/// A helper type to use [BackoffPolicy] in client and request options.
+#[derive(Clone)]
+pub struct BackoffPolicyArg(pub(crate) Arc<dyn BackoffPolicy>);
+
+impl<T> std::convert::From<T> for BackoffPolicyArg
+where
+    T: BackoffPolicy + 'static,
+{
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl std::convert::From<Arc<dyn BackoffPolicy>> for BackoffPolicyArg {
+    fn from(value: Arc<dyn BackoffPolicy>) -> Self {
+        Self(value)
+    }
+}
+
+impl std::convert::From<BackoffPolicyArg> for Arc<dyn BackoffPolicy> {
+    fn from(value: BackoffPolicyArg) -> Self {
+        value.0
+    }
+}
+
+/// Configure and build an [ExponentialBackoff] policy.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_gax::backoff_policy::*;
+/// use std::time::Duration;
+/// let policy = ExponentialBackoffBuilder::new()
+///     .with_initial_delay(Duration::from_millis(100))
+///     .with_maximum_delay(Duration::from_secs(5))
+///     .with_scaling(4.0)
+///     .build();
+/// assert!(policy.is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoffBuilder {
+    initial_delay: Duration,
+    maximum_delay: Duration,
+    scaling: f64,
+}
+
+impl ExponentialBackoffBuilder {
+    /// Creates a builder with the default parameters.
+    pub fn new() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            maximum_delay: Duration::from_secs(60),
+            scaling: 2.0,
+        }
+    }
+
+    /// Change the initial delay.
+    pub fn with_initial_delay<V: Into<Duration>>(mut self, v: V) -> Self {
+        self.initial_delay = v.into();
+        self
+    }
+
+    /// Change the maximum delay.
+    pub fn with_maximum_delay<V: Into<Duration>>(mut self, v: V) -> Self {
+        self.maximum_delay = v.into();
+        self
+    }
+
+    /// Change the scaling factor.
+    ///
+    /// The delay after the `n`-th attempt is `initial_delay * scaling^(n-1)`,
+    /// truncated to the maximum delay.
+    pub fn with_scaling<V: Into<f64>>(mut self, v: V) -> Self {
+        self.scaling = v.into();
+        self
+    }
+
+    /// Creates a new exponential backoff policy.
+    ///
+    /// Returns an error if the parameters are invalid. The initial delay must
+    /// be non-zero, and not larger than the maximum delay. The scaling factor
+    /// must be at least `1.0`.
+    pub fn build(self) -> Result<ExponentialBackoff, Error> {
+        if self.initial_delay.is_zero() {
+            return Err(Error::other(format!(
+                "the initial delay must be non-zero, got {:?}",
+                self.initial_delay
+            )));
+        }
+        if self.initial_delay > self.maximum_delay {
+            return Err(Error::other(format!(
+                "the initial delay ({:?}) must not be larger than the maximum delay ({:?})",
+                self.initial_delay, self.maximum_delay
+            )));
+        }
+        if self.scaling.is_nan() || self.scaling < 1.0 {
+            return Err(Error::other(format!(
+                "the scaling factor must be at least 1.0, got {}",
+                self.scaling
+            )));
+        }
+        Ok(ExponentialBackoff {
+            initial_delay: self.initial_delay,
+            maximum_delay: self.maximum_delay,
+            scaling: self.scaling,
+        })
+    }
+}
+
+impl std::default::Default for ExponentialBackoffBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implements truncated exponential backoff with jitter.
+///
+/// The delay after the `n`-th attempt is `initial_delay * scaling^(n-1)`,
+/// truncated to `maximum_delay`. The policy returns a random value between
+/// half of that delay and the full delay. The randomization avoids
+/// synchronized retry attempts from multiple clients.
+///
+/// If the request has a deadline, the delay is first truncated to the time
+/// remaining before the deadline. When the delay reaches the deadline, the
+/// policy returns the remaining time without randomization: waiting less
+/// would only add retry attempts that the deadline is about to cut short.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    initial_delay: Duration,
+    maximum_delay: Duration,
+    scaling: f64,
+}
+
+impl ExponentialBackoff {
+    fn delay(&self, attempt_count: u32) -> Duration {
+        let exp = attempt_count.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let scaling = self.scaling.powi(exp);
+        if scaling >= self.maximum_delay.div_duration_f64(self.initial_delay) {
+            return self.maximum_delay;
+        }
+        self.initial_delay.mul_f64(scaling).min(self.maximum_delay)
+    }
+}
+
+impl std::default::Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            maximum_delay: Duration::from_secs(60),
+            scaling: 2.0,
+        }
+    }
+}
+
+impl BackoffPolicy for ExponentialBackoff {
+    fn on_failure(&self, _loop_start: std::time::Instant, attempt_count: u32) -> Duration {
+        use rand::Rng;
+        let delay = self.delay(attempt_count);
+        rand::thread_rng().gen_range((delay / 2)..=delay)
+    }
+
+    fn on_failure_with_remaining_time(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        remaining_time: Duration,
+    ) -> Duration {
+        if self.delay(attempt_count) >= remaining_time {
+            return remaining_time;
+        }
+        self.on_failure(loop_start, attempt_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn builder_defaults() -> Result {
+        let b = ExponentialBackoffBuilder::default();
+        assert_eq!(b.initial_delay, Duration::from_secs(1));
+        assert_eq!(b.maximum_delay, Duration::from_secs(60));
+        assert_eq!(b.scaling, 2.0);
+        let p = b.build()?;
+        let d = ExponentialBackoff::default();
+        assert_eq!(p.initial_delay, d.initial_delay);
+        assert_eq!(p.maximum_delay, d.maximum_delay);
+        assert_eq!(p.scaling, d.scaling);
+        Ok(())
+    }
+
+    #[test]
+    fn builder_errors() {
+        let b = ExponentialBackoffBuilder::new().with_initial_delay(Duration::ZERO);
+        assert!(b.build().is_err());
+
+        let b = ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_secs(10))
+            .with_maximum_delay(Duration::from_secs(5));
+        assert!(b.build().is_err());
+
+        let b = ExponentialBackoffBuilder::new().with_scaling(0.5);
+        assert!(b.build().is_err());
+
+        let b = ExponentialBackoffBuilder::new().with_scaling(f64::NAN);
+        assert!(b.build().is_err());
+    }
+
+    #[test]
+    fn delay_grows_and_truncates() -> Result {
+        let p = ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_maximum_delay(Duration::from_secs(10))
+            .with_scaling(2.0)
+            .build()?;
+        assert_eq!(p.delay(1), Duration::from_secs(1));
+        assert_eq!(p.delay(2), Duration::from_secs(2));
+        assert_eq!(p.delay(3), Duration::from_secs(4));
+        assert_eq!(p.delay(4), Duration::from_secs(8));
+        assert_eq!(p.delay(5), Duration::from_secs(10));
+        assert_eq!(p.delay(u32::MAX), Duration::from_secs(10));
+        Ok(())
+    }
+
+    #[test]
+    fn on_failure_is_jittered() -> Result {
+        let p = ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_maximum_delay(Duration::from_secs(10))
+            .build()?;
+        let now = std::time::Instant::now();
+        for attempt in 1..10 {
+            let want = p.delay(attempt);
+            let got = p.on_failure(now, attempt);
+            assert!(got <= want, "{got:?} > {want:?} for {attempt}");
+            assert!(got >= want / 2, "{got:?} < {want:?}/2 for {attempt}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn on_failure_with_remaining_time() -> Result {
+        let p = ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_maximum_delay(Duration::from_secs(10))
+            .build()?;
+        let now = std::time::Instant::now();
+        let remaining = Duration::from_secs(5);
+        for attempt in 1..10 {
+            let want = p.delay(attempt);
+            let got = p.on_failure_with_remaining_time(now, attempt, remaining);
+            if want >= remaining {
+                assert_eq!(got, remaining, "{attempt}");
+            } else {
+                assert!(got <= want, "{got:?} > {want:?} for {attempt}");
+                assert!(got >= want / 2, "{got:?} < {want:?}/2 for {attempt}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn on_failure_with_remaining_time_default() {
+        #[derive(Debug)]
+        struct Fixed;
+        impl BackoffPolicy for Fixed {
+            fn on_failure(&self, _: std::time::Instant, _: u32) -> Duration {
+                Duration::from_secs(10)
+            }
+        }
+        let now = std::time::Instant::now();
+        let got = Fixed.on_failure_with_remaining_time(now, 1, Duration::from_secs(3));
+        assert_eq!(got, Duration::from_secs(3));
+        let got = Fixed.on_failure_with_remaining_time(now, 1, Duration::from_secs(30));
+        assert_eq!(got, Duration::from_secs(10));
+    }
+
+    // Verify `BackoffPolicyArg` can be converted from the desired types.
+    #[test]
+    fn backoff_policy_arg() {
+        let policy = ExponentialBackoff::default();
+        let _ = BackoffPolicyArg::from(policy);
+
+        let policy: Arc<dyn BackoffPolicy> = Arc::new(ExponentialBackoff::default());
+        let _ = BackoffPolicyArg::from(policy);
+    }
+}
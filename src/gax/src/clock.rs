@@ -0,0 +1,105 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the clock used by the retry, backoff, and polling loops.
+//!
+//! Retry and polling policies measure elapsed time, and the loops sleep
+//! between attempts. Both go through a [Clock], so the loops never depend on
+//! the wall clock directly.
+//!
+//! The default clock, [TokioClock], reads the time from the tokio runtime. In
+//! tests, [tokio::time::pause] freezes this clock and the loops complete
+//! without waiting in real time, while time limits still expire as the
+//! runtime advances its clock.
+//!
+//! # Example
+//! ```
+//! # use gcp_sdk_gax::clock::*;
+//! # use gcp_sdk_gax::retry_policy::*;
+//! # #[tokio::main(flavor = "current_thread", start_paused = true)]
+//! # async fn main() {
+//! let clock = TokioClock;
+//! let start = clock.now();
+//! let policy = AlwaysRetry.with_time_limit(std::time::Duration::from_secs(60));
+//! clock.sleep(std::time::Duration::from_secs(60)).await;
+//! assert_eq!(policy.remaining_time(start, 1), Some(std::time::Duration::ZERO));
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+/// The future returned by [Clock::sleep].
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A source of time and timers.
+///
+/// Implementations must be consistent: the value returned by `now()` must
+/// advance by (at least) `duration` while the future returned by `sleep()` is
+/// pending.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+
+    /// Returns a future that completes after `duration`.
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+/// A [Clock] backed by the tokio runtime.
+///
+/// This is the default clock in the client libraries. It follows the tokio
+/// clock, so [tokio::time::pause] and [tokio::time::advance] control the
+/// time observed by the policies and the duration of the backoff sleeps.
+#[derive(Clone, Debug, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Returns the current time, as observed by [TokioClock].
+pub(crate) fn now() -> Instant {
+    TokioClock.now()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn tokio_clock_paused() {
+        let clock = TokioClock;
+        let start = clock.now();
+        let wall = Instant::now();
+        clock.sleep(Duration::from_secs(3600)).await;
+        assert_eq!(clock.now().duration_since(start), Duration::from_secs(3600));
+        assert!(wall.elapsed() < Duration::from_secs(60), "{wall:?}");
+    }
+
+    #[tokio::test]
+    async fn tokio_clock_running() {
+        let clock = TokioClock;
+        let start = clock.now();
+        clock.sleep(Duration::from_millis(10)).await;
+        assert!(clock.now().duration_since(start) >= Duration::from_millis(10));
+        assert!(Instant::now() >= start);
+    }
+}
@@ -19,6 +19,7 @@ type BoxError = Box<dyn std::error::Error + Send + Sync>;
 pub struct Error {
     kind: ErrorKind,
     source: BoxError,
+    request_id: Option<String>,
 }
 
 impl Error {
@@ -27,6 +28,7 @@ impl Error {
         Error {
             kind,
             source: source.into(),
+            request_id: None,
         }
     }
 
@@ -50,6 +52,16 @@ impl Error {
         Error::new(ErrorKind::Rpc, source)
     }
 
+    /// A helper to create a new [ErrorKind::Timeout] error.
+    pub fn timeout<T: Into<BoxError>>(source: T) -> Self {
+        Error::new(ErrorKind::Timeout, source)
+    }
+
+    /// A helper to create a new [ErrorKind::DeadlineExceeded] error.
+    pub fn deadline_exceeded<T: Into<BoxError>>(source: T) -> Self {
+        Error::new(ErrorKind::DeadlineExceeded, source)
+    }
+
     /// A helper to create a new [ErrorKind::Other] error.
     pub fn other<T: Into<BoxError>>(source: T) -> Self {
         Error::new(ErrorKind::Other, source)
@@ -60,6 +72,23 @@ impl Error {
         self.kind.clone()
     }
 
+    /// Returns the request id associated with this error, if any.
+    ///
+    /// The client libraries send a unique id with each request, in the
+    /// `x-goog-request-id` header. The id remains the same across retry
+    /// attempts. Include this value when contacting support, it can be used
+    /// to correlate the client-side error with the service logs.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Associates a request id with this error.
+    #[cfg_attr(not(feature = "unstable-sdk-client"), allow(dead_code))]
+    pub(crate) fn with_request_id<T: Into<String>>(mut self, v: T) -> Self {
+        self.request_id = Some(v.into());
+        self
+    }
+
     /// Recurses through the source error chain and returns a reference to the
     /// inner value if it is of type `T`, or `None` if no such inner value is
     /// found.
@@ -84,11 +113,55 @@ impl Error {
             }
         }
     }
+
+    /// Returns the [Status][crate::error::rpc::Status] returned by the service,
+    /// if any.
+    ///
+    /// Only errors returned by the service carry a status. This function
+    /// returns `None` for other errors, such as I/O or authentication errors.
+    ///
+    /// If the error response does not contain a status, e.g., because a proxy
+    /// returned an HTML error page, the status is based on the HTTP status
+    /// code. Use [HttpError::payload][crate::error::HttpError::payload] to
+    /// examine the original response body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gcp_sdk_gax::error::Error;
+    /// fn handle_error(e: Error) {
+    ///     if let Some(status) = e.status() {
+    ///         println!("code={}, message={}", status.code, status.message);
+    ///     }
+    /// }
+    /// ```
+    pub fn status(&self) -> Option<crate::error::rpc::Status> {
+        self.as_inner::<crate::error::HttpError>()
+            .map(crate::error::rpc::Status::from_http_error)
+    }
+
+    /// Returns the error reason reported by the service, if any.
+    ///
+    /// See [Status::reason][crate::error::rpc::Status::reason].
+    pub fn reason(&self) -> Option<String> {
+        self.status().and_then(|s| s.reason().map(str::to_string))
+    }
+
+    /// Returns the retry delay requested by the service, if any.
+    ///
+    /// See [Status::retry_delay][crate::error::rpc::Status::retry_delay].
+    pub fn retry_delay(&self) -> Option<std::time::Duration> {
+        self.status().and_then(|s| s.retry_delay())
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", self.kind, self.source)
+        write!(f, "{}: {}", self.kind, self.source)?;
+        if let Some(id) = &self.request_id {
+            write!(f, " (request id: {id})")?;
+        }
+        Ok(())
     }
 }
 
@@ -105,7 +178,11 @@ impl From<crate::error::HttpError> for Error {
 }
 
 /// The type of error held by an [Error] instance.
+///
+/// New kinds of errors may be added in future versions of this crate, so
+/// matches on this type must include a wildcard arm.
 #[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
 pub enum ErrorKind {
     /// A serialization or deserialization error.
     Serde,
@@ -115,6 +192,12 @@ pub enum ErrorKind {
     Io,
     /// An error related to making a RPC.
     Rpc,
+    /// The request did not complete before its timeout or deadline.
+    Timeout,
+    /// The retry loop stopped because the request deadline was reached.
+    ///
+    /// The source of the error is the error from the last attempt.
+    DeadlineExceeded,
     /// A uncategorized error.
     #[default]
     Other,
@@ -130,6 +213,10 @@ impl std::fmt::Display for ErrorKind {
             ErrorKind::Authentication => write!(f, "a problem occurred during authentication"),
             ErrorKind::Io => write!(f, "a problem occurred during I/O"),
             ErrorKind::Rpc => write!(f, "a problem occurred while making a RPC"),
+            ErrorKind::Timeout => write!(f, "the request timed out"),
+            ErrorKind::DeadlineExceeded => {
+                write!(f, "the request deadline was exceeded while retrying")
+            }
             ErrorKind::Other => write!(f, "a problem occurred"),
         }
     }
@@ -155,15 +242,94 @@ mod test {
         let error = Error::rpc("source".to_string());
         assert_eq!(error.kind(), ErrorKind::Rpc);
         assert!(error.source().is_some(), "missing source for {error:?}");
+        let error = Error::timeout("source".to_string());
+        assert_eq!(error.kind(), ErrorKind::Timeout);
+        assert!(error.source().is_some(), "missing source for {error:?}");
+        let error = Error::deadline_exceeded("source".to_string());
+        assert_eq!(error.kind(), ErrorKind::DeadlineExceeded);
+        assert!(error.source().is_some(), "missing source for {error:?}");
         let error = Error::other("source".to_string());
         assert_eq!(error.kind(), ErrorKind::Other);
         assert!(error.source().is_some(), "missing source for {error:?}");
     }
 
+    #[test]
+    fn error_request_id() {
+        let error = Error::other("source".to_string());
+        assert_eq!(error.request_id(), None);
+        assert!(!format!("{error}").contains("request id"), "{error}");
+
+        let error = error.with_request_id("test-request-id");
+        assert_eq!(error.request_id(), Some("test-request-id"));
+        let fmt = format!("{error}");
+        assert!(fmt.contains("request id: test-request-id"), "{fmt}");
+    }
+
+    #[test]
+    fn error_status_details() {
+        use crate::error::HttpError;
+        use std::collections::HashMap;
+        use std::time::Duration;
+        let payload = serde_json::json!({"error": {
+            "code": 429,
+            "status": "RESOURCE_EXHAUSTED",
+            "message": "quota exceeded",
+            "details": [
+                {"@type": "google.rpc.ErrorInfo", "reason": "RATE_LIMIT_EXCEEDED", "domain": "googleapis.com"},
+                {"@type": "google.rpc.RetryInfo", "retryDelay": "1.5s"},
+            ]
+        }});
+        let error = Error::from(HttpError::new(
+            429,
+            HashMap::new(),
+            Some(bytes::Bytes::from_owner(payload.to_string())),
+        ));
+        let status = error.status();
+        assert_eq!(status.as_ref().map(|s| s.code), Some(429), "{status:?}");
+        assert_eq!(error.reason().as_deref(), Some("RATE_LIMIT_EXCEEDED"));
+        assert_eq!(error.retry_delay(), Some(Duration::from_millis(1500)));
+
+        let error = Error::from(HttpError::new(
+            502,
+            HashMap::new(),
+            Some(bytes::Bytes::from_static(b"<html>Bad Gateway</html>")),
+        ));
+        let status = error.status();
+        assert_eq!(status.as_ref().map(|s| s.code), Some(502), "{status:?}");
+        assert_eq!(
+            status.as_ref().and_then(|s| s.status.as_deref()),
+            Some("INTERNAL"),
+            "{status:?}"
+        );
+        assert!(error.reason().is_none(), "{error:?}");
+        assert!(error.retry_delay().is_none(), "{error:?}");
+
+        let error = Error::io("source".to_string());
+        assert!(error.status().is_none(), "{error:?}");
+    }
+
+    #[test]
+    fn error_status_success_code() {
+        use crate::error::rpc::Code;
+        use crate::error::HttpError;
+        use std::collections::HashMap;
+        // A success status code in an error is unexpected, it is not `OK`.
+        let error = Error::from(HttpError::new(
+            200,
+            HashMap::new(),
+            Some(bytes::Bytes::from_static(b"not JSON")),
+        ));
+        let status = error.status().unwrap();
+        assert_eq!(status.code, 200, "{status:?}");
+        let code = status.status.as_deref().map(Code::try_from).transpose();
+        assert_eq!(code.ok().flatten(), Some(Code::Unknown), "{status:?}");
+    }
+
     #[test_case(ErrorKind::Serde)]
     #[test_case(ErrorKind::Authentication)]
     #[test_case(ErrorKind::Io)]
     #[test_case(ErrorKind::Rpc)]
+    #[test_case(ErrorKind::Timeout)]
     #[test_case(ErrorKind::Other)]
     fn error_display_includes_kind_and_source(kind: ErrorKind) {
         let kind_msg = format!("{kind}");
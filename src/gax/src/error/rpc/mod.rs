@@ -48,6 +48,64 @@ pub struct Status {
     pub details: Vec<StatusDetails>,
 }
 
+impl Status {
+    /// Returns the first [ErrorInfo][rpc::model::ErrorInfo] in the error
+    /// details, if any.
+    pub fn error_info(&self) -> Option<&rpc::model::ErrorInfo> {
+        self.details.iter().find_map(|d| match d {
+            StatusDetails::ErrorInfo(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [RetryInfo][rpc::model::RetryInfo] in the error
+    /// details, if any.
+    pub fn retry_info(&self) -> Option<&rpc::model::RetryInfo> {
+        self.details.iter().find_map(|d| match d {
+            StatusDetails::RetryInfo(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [BadRequest][rpc::model::BadRequest] in the error
+    /// details, if any.
+    pub fn bad_request(&self) -> Option<&rpc::model::BadRequest> {
+        self.details.iter().find_map(|d| match d {
+            StatusDetails::BadRequest(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [QuotaFailure][rpc::model::QuotaFailure] in the error
+    /// details, if any.
+    pub fn quota_failure(&self) -> Option<&rpc::model::QuotaFailure> {
+        self.details.iter().find_map(|d| match d {
+            StatusDetails::QuotaFailure(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// Returns the reason for the error, as reported in
+    /// [ErrorInfo][rpc::model::ErrorInfo].
+    ///
+    /// The reason is a short, machine-readable identifier for the proximate
+    /// cause of the error, such as `API_DISABLED` or `RATE_LIMIT_EXCEEDED`.
+    pub fn reason(&self) -> Option<&str> {
+        self.error_info().map(|v| v.reason.as_str())
+    }
+
+    /// Returns the minimum delay before retrying the request, as reported in
+    /// [RetryInfo][rpc::model::RetryInfo].
+    ///
+    /// Returns `None` if the service did not provide a delay, or if the delay
+    /// cannot be represented as a [std::time::Duration].
+    pub fn retry_delay(&self) -> Option<std::time::Duration> {
+        self.retry_info()
+            .and_then(|v| v.retry_delay.clone())
+            .and_then(|d| std::time::Duration::try_from(d).ok())
+    }
+}
+
 /// The canonical error codes for APIs.
 //
 /// Sometimes multiple error codes may apply.  Services should return
@@ -213,6 +271,31 @@ pub enum Code {
     Unauthenticated = 16,
 }
 
+impl Code {
+    /// Maps a HTTP status code to the closest [Code].
+    ///
+    /// See the "HTTP Mapping" in the documentation of each value. This is only
+    /// used for error responses, any other status code (including `2xx`) maps
+    /// to [Code::Unknown].
+    fn from_http_status(code: u16) -> Self {
+        match code {
+            400 => Code::InvalidArgument,
+            401 => Code::Unauthenticated,
+            403 => Code::PermissionDenied,
+            404 => Code::NotFound,
+            409 => Code::Aborted,
+            429 => Code::ResourceExhausted,
+            499 => Code::Canceled,
+            402 | 405..=408 | 410..=428 | 430..=498 => Code::FailedPrecondition,
+            501 => Code::Unimplemented,
+            503 => Code::Unavailable,
+            504 => Code::DeadlineExceeded,
+            500 | 502 | 505..=599 => Code::Internal,
+            _ => Code::Unknown,
+        }
+    }
+}
+
 impl Default for Code {
     fn default() -> Self {
         Self::Unknown
@@ -332,6 +415,30 @@ impl TryFrom<&bytes::Bytes> for Status {
     }
 }
 
+impl Status {
+    /// Returns the [Status] in the error response, or a [Status] based on the
+    /// HTTP status code if the response does not contain one.
+    ///
+    /// Proxies and load balancers may return error responses that are not
+    /// JSON, for example, HTML error pages. The status code in these responses
+    /// is still meaningful, e.g., to decide if the request can be retried.
+    pub(crate) fn from_http_error(value: &HttpError) -> Self {
+        Status::try_from(value).unwrap_or_else(|_| {
+            let code = value.status_code();
+            let message = http::StatusCode::from_u16(code)
+                .ok()
+                .and_then(|c| c.canonical_reason())
+                .unwrap_or_default();
+            Status {
+                code: code as i32,
+                message: message.to_string(),
+                status: Some(String::from(Code::from_http_status(code))),
+                details: Vec::new(),
+            }
+        })
+    }
+}
+
 /// The type of details associated with [Status].
 ///
 /// Google cloud RPCs often return a detailed error description. This details
@@ -385,6 +492,7 @@ mod test {
     use rpc::model::RetryInfo;
     use serde_json::json;
     use std::collections::HashMap;
+    use std::time::Duration;
     use test_case::test_case;
     type Result = std::result::Result<(), Box<dyn std::error::Error>>;
 
@@ -410,7 +518,7 @@ mod test {
                         ErrorInfo::default()
                             .set_reason("reason")
                             .set_domain("domain")
-                            .set_metadata(HashMap::new()),
+                            .set_metadata(HashMap::<String, String>::new()),
                     ),
                     StatusDetails::Help(Help::default().set_links(
                         vec![rpc::model::help::Link::default()
@@ -630,6 +738,125 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn status_from_http_error() {
+        let got = Status::from_http_error(&sample_http_error());
+        assert_eq!(got, sample_status());
+
+        let error = HttpError::new(
+            503,
+            HashMap::new(),
+            Some(bytes::Bytes::from_static(
+                b"<html><body>Service Unavailable</body></html>",
+            )),
+        );
+        let got = Status::from_http_error(&error);
+        let want = Status {
+            code: 503,
+            message: "Service Unavailable".into(),
+            status: Some("UNAVAILABLE".to_string()),
+            details: [].into(),
+        };
+        assert_eq!(got, want);
+
+        let error = HttpError::new(418, HashMap::new(), None);
+        let got = Status::from_http_error(&error);
+        assert_eq!(got.code, 418);
+        assert_eq!(got.status.as_deref(), Some("FAILED_PRECONDITION"));
+    }
+
+    #[test_case(200, Code::Unknown)]
+    #[test_case(204, Code::Unknown)]
+    #[test_case(400, Code::InvalidArgument)]
+    #[test_case(401, Code::Unauthenticated)]
+    #[test_case(403, Code::PermissionDenied)]
+    #[test_case(404, Code::NotFound)]
+    #[test_case(409, Code::Aborted)]
+    #[test_case(412, Code::FailedPrecondition)]
+    #[test_case(429, Code::ResourceExhausted)]
+    #[test_case(499, Code::Canceled)]
+    #[test_case(500, Code::Internal)]
+    #[test_case(501, Code::Unimplemented)]
+    #[test_case(502, Code::Internal)]
+    #[test_case(503, Code::Unavailable)]
+    #[test_case(504, Code::DeadlineExceeded)]
+    #[test_case(599, Code::Internal)]
+    #[test_case(302, Code::Unknown)]
+    #[test_case(600, Code::Unknown)]
+    fn code_from_http_status(input: u16, want: Code) {
+        assert_eq!(Code::from_http_status(input), want);
+    }
+
+    fn status_with_details() -> Status {
+        Status {
+            code: 429,
+            message: "quota exceeded".into(),
+            status: Some("RESOURCE_EXHAUSTED".to_string()),
+            details: vec![
+                StatusDetails::Help(Help::default()),
+                StatusDetails::ErrorInfo(
+                    ErrorInfo::default()
+                        .set_reason("RATE_LIMIT_EXCEEDED")
+                        .set_domain("googleapis.com"),
+                ),
+                StatusDetails::RetryInfo(
+                    RetryInfo::default().set_retry_delay(wkt::Duration::clamp(2, 500_000_000)),
+                ),
+                StatusDetails::BadRequest(BadRequest::default().set_field_violations(vec![
+                    rpc::model::bad_request::FieldViolation::default().set_field("name"),
+                ])),
+                StatusDetails::QuotaFailure(QuotaFailure::default().set_violations(vec![
+                    rpc::model::quota_failure::Violation::default().set_subject("project:test"),
+                ])),
+            ],
+        }
+    }
+
+    #[test]
+    fn status_detail_accessors() {
+        let status = status_with_details();
+        assert_eq!(
+            status.error_info().map(|v| v.domain.as_str()),
+            Some("googleapis.com")
+        );
+        assert_eq!(status.reason(), Some("RATE_LIMIT_EXCEEDED"));
+        assert_eq!(status.retry_delay(), Some(Duration::from_millis(2500)));
+        assert_eq!(
+            status.bad_request().map(|v| v
+                .field_violations
+                .iter()
+                .map(|f| f.field.as_str())
+                .collect::<Vec<_>>()),
+            Some(vec!["name"])
+        );
+        assert_eq!(
+            status.quota_failure().map(|v| v
+                .violations
+                .iter()
+                .map(|f| f.subject.as_str())
+                .collect::<Vec<_>>()),
+            Some(vec!["project:test"])
+        );
+    }
+
+    #[test]
+    fn status_detail_accessors_missing() {
+        let status = sample_status();
+        assert!(status.error_info().is_none(), "{status:?}");
+        assert!(status.retry_info().is_none(), "{status:?}");
+        assert!(status.bad_request().is_none(), "{status:?}");
+        assert!(status.quota_failure().is_none(), "{status:?}");
+        assert_eq!(status.reason(), None);
+        assert_eq!(status.retry_delay(), None);
+
+        let status = Status {
+            details: vec![StatusDetails::RetryInfo(RetryInfo::default())],
+            ..Default::default()
+        };
+        assert!(status.retry_info().is_some(), "{status:?}");
+        assert_eq!(status.retry_delay(), None);
+    }
+
     #[test]
     fn code_to_string() {
         let got = String::from(Code::AlreadyExists);
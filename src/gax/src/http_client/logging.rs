@@ -0,0 +1,177 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Logs the requests and responses when request logging is enabled.
+//!
+//! The logs are intended for troubleshooting, and may be sent to shared
+//! logging systems. Sensitive values are redacted before they are logged:
+//! credentials in the headers, customer-supplied encryption keys, and any
+//! JSON fields that typically contain secrets, such as Secret Manager
+//! payloads.
+//!
+//! Logging the response body requires reading it in full. The client only
+//! does so if the DEBUG level is enabled, and never for streamed responses,
+//! which are logged without a body.
+
+use crate::interceptor::InterceptedRequest;
+
+const REDACTED: &str = "[censored]";
+
+/// Headers that contain credentials or encryption keys.
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-goog-api-key",
+    "x-goog-encryption-key",
+    "x-goog-copy-source-encryption-key",
+];
+
+/// JSON fields that contain secrets, in any object of the request or
+/// response body.
+const SENSITIVE_FIELDS: &[&str] = &[
+    "payload",
+    "privateKeyData",
+    "privateKey",
+    "password",
+    "accessToken",
+    "refreshToken",
+    "clientSecret",
+];
+
+/// Returns true if the subscriber records the log events.
+pub(crate) fn enabled() -> bool {
+    tracing::enabled!(tracing::Level::DEBUG)
+}
+
+pub(crate) fn log_request(request: &InterceptedRequest) {
+    tracing::debug!(
+        method = %request.method,
+        url = %request.url,
+        headers = ?redact_headers(&request.headers),
+        body = redact_body(request.body.as_deref()),
+        "sending request"
+    );
+}
+
+pub(crate) fn log_response(
+    request: &InterceptedRequest,
+    status: http::StatusCode,
+    headers: &http::HeaderMap,
+    body: Option<&[u8]>,
+) {
+    tracing::debug!(
+        method = %request.method,
+        url = %request.url,
+        status = status.as_u16(),
+        headers = ?redact_headers(headers),
+        body = redact_body(body),
+        "received response"
+    );
+}
+
+fn redact_headers(headers: &http::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+fn redact_body(body: Option<&[u8]>) -> String {
+    let Some(body) = body.filter(|b| !b.is_empty()) else {
+        return String::new();
+    };
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("[{} bytes]", body.len()),
+    }
+}
+
+fn redact_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&k.as_str()) {
+                    *v = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(v);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn headers() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("authorization", "Bearer secret".parse().unwrap());
+        headers.insert("x-goog-encryption-key", "c2VjcmV0".parse().unwrap());
+        headers.insert("x-goog-request-id", "abc".parse().unwrap());
+        let got = redact_headers(&headers);
+        assert!(
+            got.contains(&("authorization".to_string(), REDACTED.to_string())),
+            "{got:?}"
+        );
+        assert!(
+            got.contains(&("x-goog-encryption-key".to_string(), REDACTED.to_string())),
+            "{got:?}"
+        );
+        assert!(
+            got.contains(&("x-goog-request-id".to_string(), "abc".to_string())),
+            "{got:?}"
+        );
+    }
+
+    #[test]
+    fn body() {
+        let body = json!({
+            "name": "projects/p/secrets/s/versions/1",
+            "payload": {"data": "c2VjcmV0", "dataCrc32c": "123"},
+            "items": [{"privateKeyData": "c2VjcmV0", "keyType": "x"}],
+        });
+        let got = redact_body(Some(body.to_string().as_bytes()));
+        let got = serde_json::from_str::<serde_json::Value>(&got).unwrap();
+        let want = json!({
+            "name": "projects/p/secrets/s/versions/1",
+            "payload": REDACTED,
+            "items": [{"privateKeyData": REDACTED, "keyType": "x"}],
+        });
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn body_not_json() {
+        assert_eq!(redact_body(Some(b"not json")), "[8 bytes]");
+        assert_eq!(redact_body(Some(b"")), "");
+        assert_eq!(redact_body(None), "");
+    }
+}
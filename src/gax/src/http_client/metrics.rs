@@ -0,0 +1,197 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Records OpenTelemetry metrics for client requests.
+//!
+//! The metrics are recorded using the global meter provider. Applications that
+//! do not configure a meter provider pay very little for these metrics.
+//!
+//! The names and attributes follow the OpenTelemetry semantic conventions for
+//! HTTP clients. Each attempt is an HTTP request, the `gcp.client.operation.*`
+//! metrics describe the complete client call, including any retries.
+
+use super::{AttemptInfo, RequestTarget};
+use crate::error::HttpError;
+use crate::Result;
+use opentelemetry::metrics::Histogram;
+use opentelemetry::KeyValue;
+use std::time::Duration;
+
+const METER_NAME: &str = "gcp-sdk-gax";
+
+#[derive(Clone)]
+pub(crate) struct Metrics {
+    attempt_duration: Histogram<f64>,
+    request_body_size: Histogram<u64>,
+    response_body_size: Histogram<u64>,
+    operation_duration: Histogram<f64>,
+    operation_attempts: Histogram<u64>,
+}
+
+impl Metrics {
+    pub(crate) fn new() -> Self {
+        let meter = opentelemetry::global::meter(METER_NAME);
+        Self {
+            attempt_duration: meter
+                .f64_histogram("http.client.request.duration")
+                .with_unit("s")
+                .with_description("Duration of each request attempt.")
+                .build(),
+            request_body_size: meter
+                .u64_histogram("http.client.request.body.size")
+                .with_unit("By")
+                .with_description("Size of the request payload in each attempt.")
+                .build(),
+            response_body_size: meter
+                .u64_histogram("http.client.response.body.size")
+                .with_unit("By")
+                .with_description("Size of the response payload in each attempt.")
+                .build(),
+            operation_duration: meter
+                .f64_histogram("gcp.client.operation.duration")
+                .with_unit("s")
+                .with_description("Duration of each client call, including retries.")
+                .build(),
+            operation_attempts: meter
+                .u64_histogram("gcp.client.operation.attempts")
+                .with_unit("{attempt}")
+                .with_description("Number of attempts in each client call.")
+                .build(),
+        }
+    }
+
+    pub(crate) fn record_attempt<O>(
+        &self,
+        target: &RequestTarget,
+        info: &AttemptInfo,
+        elapsed: Duration,
+        result: &Result<O>,
+    ) {
+        let attributes = attributes(target, info.status_code, result);
+        self.attempt_duration
+            .record(elapsed.as_secs_f64(), &attributes);
+        if let Some(size) = info.request_size {
+            self.request_body_size.record(size, &attributes);
+        }
+        if let Some(size) = info.response_size {
+            self.response_body_size.record(size, &attributes);
+        }
+    }
+
+    pub(crate) fn record_operation<O>(
+        &self,
+        target: &RequestTarget,
+        elapsed: Duration,
+        attempt_count: u32,
+        result: &Result<O>,
+    ) {
+        let status_code = result
+            .as_ref()
+            .err()
+            .and_then(|e| e.as_inner::<HttpError>())
+            .map(HttpError::status_code);
+        let attributes = attributes(target, status_code, result);
+        self.operation_duration
+            .record(elapsed.as_secs_f64(), &attributes);
+        self.operation_attempts
+            .record(attempt_count as u64, &attributes);
+    }
+}
+
+fn attributes<O>(
+    target: &RequestTarget,
+    status_code: Option<u16>,
+    result: &Result<O>,
+) -> Vec<KeyValue> {
+    let mut attributes = Vec::new();
+    if let Some((method, url)) = target {
+        attributes.push(KeyValue::new(
+            "http.request.method",
+            method.as_str().to_string(),
+        ));
+        if let Some(host) = url.host_str() {
+            attributes.push(KeyValue::new("server.address", host.to_string()));
+        }
+    }
+    if let Some(code) = status_code {
+        attributes.push(KeyValue::new("http.response.status_code", code as i64));
+    }
+    if let Err(e) = result {
+        let error_type = match status_code {
+            Some(code) => code.to_string(),
+            None => format!("{:?}", e.kind()),
+        };
+        attributes.push(KeyValue::new("error.type", error_type));
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::Error;
+
+    fn target() -> RequestTarget {
+        Some((
+            reqwest::Method::GET,
+            reqwest::Url::parse("https://secretmanager.googleapis.com/v1/projects/p/secrets")
+                .unwrap(),
+        ))
+    }
+
+    fn find<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a opentelemetry::Value> {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| &kv.value)
+    }
+
+    #[test]
+    fn attributes_success() {
+        let attributes = attributes(&target(), Some(200), &Ok(()));
+        assert_eq!(
+            find(&attributes, "http.request.method"),
+            Some(&"GET".into())
+        );
+        assert_eq!(
+            find(&attributes, "server.address"),
+            Some(&"secretmanager.googleapis.com".into())
+        );
+        assert_eq!(
+            find(&attributes, "http.response.status_code"),
+            Some(&200_i64.into())
+        );
+        assert_eq!(find(&attributes, "error.type"), None);
+    }
+
+    #[test]
+    fn attributes_http_error() {
+        let result: Result<()> = Err(Error::rpc("test-only"));
+        let attributes = attributes(&target(), Some(503), &result);
+        assert_eq!(
+            find(&attributes, "http.response.status_code"),
+            Some(&503_i64.into())
+        );
+        assert_eq!(find(&attributes, "error.type"), Some(&"503".into()));
+    }
+
+    #[test]
+    fn attributes_other_error() {
+        let result: Result<()> = Err(Error::timeout("test-only"));
+        let attributes = attributes(&None, None, &result);
+        assert_eq!(find(&attributes, "http.request.method"), None);
+        assert_eq!(find(&attributes, "http.response.status_code"), None);
+        assert_eq!(find(&attributes, "error.type"), Some(&"Timeout".into()));
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the interceptor hooks for client requests.
+//!
+//! Applications may need to change the outgoing requests, e.g. to add custom
+//! headers or replace the authentication header, or to observe the responses,
+//! e.g. to collect custom metrics. Some tests need to return canned responses
+//! or inject faults without changing the code that uses the client.
+//!
+//! Interceptors are configured with
+//! [ClientConfig::add_interceptor][crate::options::ClientConfig::add_interceptor].
+//! The client calls them on each attempt, including any retries, in the order
+//! they were added.
+//!
+//! # Example
+//! ```
+//! # use gcp_sdk_gax::interceptor::*;
+//! # use gcp_sdk_gax::options::ClientConfig;
+//! # use gcp_sdk_gax::Result;
+//! #[derive(Debug)]
+//! struct AddHeader;
+//! impl Interceptor for AddHeader {
+//!     fn on_request(
+//!         &self,
+//!         request: &mut InterceptedRequest,
+//!     ) -> Result<Option<InterceptedResponse>> {
+//!         let value = http::HeaderValue::from_static("my-value");
+//!         request.headers.insert("x-my-header", value);
+//!         Ok(None)
+//!     }
+//! }
+//! let config = ClientConfig::new().add_interceptor(AddHeader);
+//! ```
+
+use crate::Result;
+
+/// A request about to be sent by the client.
+///
+/// The request already includes all the headers set by the client, including
+/// the authentication headers.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InterceptedRequest {
+    /// The HTTP method.
+    pub method: http::Method,
+    /// The full URL, including the query parameters.
+    pub url: String,
+    /// The request headers.
+    pub headers: http::HeaderMap,
+    /// The request body, if any.
+    pub body: Option<bytes::Bytes>,
+}
+
+/// A response returned by an [Interceptor] instead of sending the request.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InterceptedResponse {
+    /// The HTTP status code.
+    pub status: http::StatusCode,
+    /// The response headers.
+    pub headers: http::HeaderMap,
+    /// The response body, for successful responses this must be the JSON
+    /// representation of the response message.
+    pub body: bytes::Bytes,
+}
+
+impl InterceptedResponse {
+    /// Creates a response with the given status code and body.
+    pub fn new<B: Into<bytes::Bytes>>(status: http::StatusCode, body: B) -> Self {
+        Self {
+            status,
+            headers: http::HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Intercepts the requests made by a client.
+///
+/// Both functions have default implementations that do nothing, so
+/// implementations only need to override the hooks they use.
+pub trait Interceptor: Send + Sync + std::fmt::Debug {
+    /// Called before each attempt is sent.
+    ///
+    /// The interceptor may modify the request. Returning a response skips
+    /// sending the request, and any interceptors after this one. The client
+    /// handles the response as if it was received from the service.
+    /// Returning an error fails the attempt, the retry policy determines if
+    /// the request is retried.
+    fn on_request(&self, _request: &mut InterceptedRequest) -> Result<Option<InterceptedResponse>> {
+        Ok(None)
+    }
+
+    /// Called when the response headers are received.
+    ///
+    /// This is also called for responses returned by [Interceptor::on_request].
+    fn on_response(
+        &self,
+        _request: &InterceptedRequest,
+        _status: http::StatusCode,
+        _headers: &http::HeaderMap,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Noop;
+    impl Interceptor for Noop {}
+
+    #[test]
+    fn defaults() -> Result<()> {
+        let mut request = InterceptedRequest {
+            method: http::Method::GET,
+            url: "https://example.com/v1/test".to_string(),
+            headers: http::HeaderMap::new(),
+            body: None,
+        };
+        assert!(Noop.on_request(&mut request)?.is_none());
+        Noop.on_response(&request, http::StatusCode::OK, &http::HeaderMap::new());
+        Ok(())
+    }
+
+    #[test]
+    fn response_new() {
+        let response = InterceptedResponse::new(http::StatusCode::NOT_FOUND, "{}");
+        assert_eq!(response.status, http::StatusCode::NOT_FOUND);
+        assert!(response.headers.is_empty());
+        assert_eq!(response.body, "{}");
+    }
+}
@@ -52,6 +52,11 @@ pub mod query_parameter;
 #[doc(hidden)]
 pub mod path_parameter;
 
+/// Defines helpers to compute the `x-goog-request-params` routing header.
+#[cfg(feature = "unstable-sdk-client")]
+#[doc(hidden)]
+pub mod routing_parameter;
+
 /// Implementation details for [query_parameter](::crate::query_parameter) and
 /// [path_parameter](::crate::path_parameter).
 #[cfg(feature = "unstable-sdk-client")]
@@ -76,5 +81,24 @@ pub mod paginator;
 #[doc(hidden)]
 pub mod http_client;
 
+/// Incrementally parses the responses of List RPCs.
+#[cfg(all(feature = "unstable-sdk-client", feature = "unstable-stream"))]
+#[doc(hidden)]
+pub mod list_stream;
+
+/// Implements the retry loop used by the client libraries.
+#[cfg(feature = "unstable-sdk-client")]
+#[doc(hidden)]
+pub mod retry_loop_internal;
+
+pub mod backoff_policy;
+pub mod clock;
+pub mod interceptor;
+#[cfg(feature = "unstable-sdk-client")]
+pub mod optimistic_concurrency;
 pub mod options;
+pub mod polling_policy;
+pub mod response;
 pub mod retry_policy;
+pub mod retry_throttler;
+pub mod transport;
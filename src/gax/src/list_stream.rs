@@ -0,0 +1,583 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Incrementally parse the responses of List RPCs.
+//!
+//! Some List RPCs return multi-megabyte responses. Parsing the full response
+//! before returning any items increases the peak memory usage and the time
+//! until the application receives the first item. The types in this module
+//! parse each item as soon as its bytes arrive from the service.
+
+use crate::error::Error;
+use crate::http_client::send_error;
+use crate::paginator::PageableResponse;
+use crate::Result;
+use futures::stream::unfold;
+use futures::{Stream, StreamExt};
+use pin_project::pin_project;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The items of a single page, parsed as the response body arrives.
+///
+/// Created by [ReqwestClient::execute_list_stream]. Retries only apply until
+/// the response headers are received, errors reading or parsing the body are
+/// returned by [ListStream::next].
+///
+/// [ReqwestClient::execute_list_stream]: crate::http_client::ReqwestClient::execute_list_stream
+pub struct ListStream<P>
+where
+    P: PageableResponse,
+{
+    response: reqwest::Response,
+    scanner: Scanner,
+    items: VecDeque<P::PageItem>,
+    next_page_token: Option<String>,
+    done: bool,
+}
+
+impl<P> ListStream<P>
+where
+    P: PageableResponse + DeserializeOwned,
+    P::PageItem: DeserializeOwned,
+{
+    /// Creates a stream over the elements of `items_field` in `response`.
+    ///
+    /// The `items_field` is the JSON name of the repeated field, e.g.
+    /// `secrets` for `ListSecretsResponse`.
+    pub(crate) fn new(response: reqwest::Response, items_field: &'static str) -> Self {
+        Self {
+            response,
+            scanner: Scanner::new(items_field),
+            items: VecDeque::new(),
+            next_page_token: None,
+            done: false,
+        }
+    }
+
+    /// Returns the next item in the page, or `None` at the end of the page.
+    ///
+    /// After an error the stream returns `None`.
+    pub async fn next(&mut self) -> Option<Result<P::PageItem>> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+            if let Err(e) = self.fill().await {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+    }
+
+    /// Returns the token for the next page.
+    ///
+    /// The token is only known after [ListStream::next] returns `None`
+    /// without any errors. It is empty on the last page.
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    async fn fill(&mut self) -> Result<()> {
+        let Some(chunk) = self.response.chunk().await.map_err(send_error)? else {
+            let rest = self.scanner.finish().map_err(Error::serde)?;
+            let page = serde_json::from_slice::<P>(&rest).map_err(Error::serde)?;
+            self.next_page_token = Some(page.next_page_token());
+            self.done = true;
+            return Ok(());
+        };
+        let mut raw = Vec::new();
+        self.scanner.push(&chunk, &mut raw).map_err(Error::serde)?;
+        for item in raw {
+            let item = serde_json::from_slice(&item).map_err(Error::serde)?;
+            self.items.push_back(item);
+        }
+        Ok(())
+    }
+}
+
+impl<P> std::fmt::Debug for ListStream<P>
+where
+    P: PageableResponse,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListStream")
+            .field("response", &self.response)
+            .field("next_page_token", &self.next_page_token)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+/// A stream of items across all the pages of a List RPC.
+///
+/// Unlike [ItemPaginator][crate::paginator::ItemPaginator] the items are
+/// returned as soon as they are parsed, before the full page is received.
+#[pin_project]
+pub struct ListItems<T> {
+    #[pin]
+    stream: Pin<Box<dyn Stream<Item = Result<T>> + Send>>,
+}
+
+impl<T> ListItems<T> {
+    /// Creates a new [ListItems] given the initial page token and a function
+    /// to start fetching each page.
+    pub fn new<P, F>(
+        seed_token: String,
+        execute: impl Fn(String) -> F + Clone + Send + 'static,
+    ) -> Self
+    where
+        P: PageableResponse<PageItem = T> + DeserializeOwned + Send + 'static,
+        T: DeserializeOwned + Send + 'static,
+        F: Future<Output = Result<ListStream<P>>> + Send + 'static,
+    {
+        let stream = unfold(State::Fetch(seed_token), move |mut state| {
+            let execute = execute.clone();
+            async move {
+                loop {
+                    state = match state {
+                        State::Done => return None,
+                        State::Fetch(token) => match execute(token).await {
+                            Ok(page) => State::Reading(Box::new(page)),
+                            Err(e) => return Some((Err(e), State::Done)),
+                        },
+                        State::Reading(mut page) => match page.next().await {
+                            Some(Ok(item)) => return Some((Ok(item), State::Reading(page))),
+                            Some(Err(e)) => return Some((Err(e), State::Done)),
+                            None => match page.next_page_token() {
+                                Some(token) if !token.is_empty() => State::Fetch(token.to_string()),
+                                _ => State::Done,
+                            },
+                        },
+                    }
+                }
+            }
+        });
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+
+    /// Returns the next mutation of the wrapped stream.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> futures::stream::Next<'_, Self> {
+        StreamExt::next(self)
+    }
+}
+
+impl<T> Stream for ListItems<T> {
+    type Item = Result<T>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for ListItems<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListItems").finish()
+    }
+}
+
+enum State<P>
+where
+    P: PageableResponse,
+{
+    Fetch(String),
+    Reading(Box<ListStream<P>>),
+    Done,
+}
+
+/// Splits a JSON object into the elements of one array field, and a JSON
+/// object with all the other fields.
+///
+/// The scanner does not fully validate the input. The elements and the
+/// remaining fields are parsed with `serde_json`, which reports any errors.
+#[derive(Debug)]
+struct Scanner {
+    field: &'static str,
+    state: ScannerState,
+    in_string: bool,
+    escape: bool,
+    nesting: usize,
+    key: Vec<u8>,
+    current: Vec<u8>,
+    rest: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScannerState {
+    Start,
+    Open,
+    BeforeKey,
+    Key,
+    AfterKey,
+    BeforeValue,
+    Value,
+    BeforeItem,
+    Item,
+    AfterValue,
+    End,
+}
+
+impl Scanner {
+    fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            state: ScannerState::Start,
+            in_string: false,
+            escape: false,
+            nesting: 0,
+            key: Vec::new(),
+            current: Vec::new(),
+            rest: b"{".to_vec(),
+        }
+    }
+
+    /// Consumes `data`, appending any complete array elements to `items`.
+    fn push(&mut self, data: &[u8], items: &mut Vec<Vec<u8>>) -> std::result::Result<(), String> {
+        for b in data {
+            self.byte(*b, items)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a JSON object with all the fields except the array.
+    fn finish(&mut self) -> std::result::Result<Vec<u8>, String> {
+        if self.state != ScannerState::End {
+            return Err("incomplete JSON object in list response".to_string());
+        }
+        let mut rest = std::mem::take(&mut self.rest);
+        rest.push(b'}');
+        Ok(rest)
+    }
+
+    fn byte(&mut self, b: u8, items: &mut Vec<Vec<u8>>) -> std::result::Result<(), String> {
+        use ScannerState::*;
+        match self.state {
+            Start => match b {
+                b'{' => self.state = Open,
+                _ if b.is_ascii_whitespace() => {}
+                _ => return Err(unexpected(b, "the start of the list response")),
+            },
+            Open if b == b'}' => self.state = End,
+            Open | BeforeKey => match b {
+                b'"' => {
+                    self.key.clear();
+                    self.state = Key;
+                }
+                _ if b.is_ascii_whitespace() => {}
+                _ => return Err(unexpected(b, "a field name")),
+            },
+            Key => {
+                if self.escape {
+                    self.escape = false;
+                } else if b == b'\\' {
+                    self.escape = true;
+                } else if b == b'"' {
+                    self.state = AfterKey;
+                    return Ok(());
+                }
+                self.key.push(b);
+            }
+            AfterKey => match b {
+                b':' => self.state = BeforeValue,
+                _ if b.is_ascii_whitespace() => {}
+                _ => return Err(unexpected(b, "the `:` after a field name")),
+            },
+            BeforeValue => match b {
+                _ if b.is_ascii_whitespace() => {}
+                b'[' if self.key == self.field.as_bytes() => self.state = BeforeItem,
+                _ => {
+                    self.current.clear();
+                    self.state = Value;
+                    return self.byte(b, items);
+                }
+            },
+            Value => {
+                if !self.ends_value(b) {
+                    self.current.push(b);
+                    return Ok(());
+                }
+                let value = self.current.trim_ascii_end();
+                if value.is_empty() || b == b']' {
+                    return Err(unexpected(b, "a field value"));
+                }
+                if self.rest.len() > 1 {
+                    self.rest.push(b',');
+                }
+                self.rest.push(b'"');
+                self.rest.extend_from_slice(&self.key);
+                self.rest.extend_from_slice(b"\":");
+                self.rest.extend_from_slice(value);
+                self.state = if b == b'}' { End } else { BeforeKey };
+            }
+            BeforeItem => match b {
+                _ if b.is_ascii_whitespace() => {}
+                b']' => self.state = AfterValue,
+                _ => {
+                    self.current.clear();
+                    self.state = Item;
+                    return self.byte(b, items);
+                }
+            },
+            Item => {
+                if !self.ends_value(b) {
+                    self.current.push(b);
+                    return Ok(());
+                }
+                let item = self.current.trim_ascii_end();
+                if item.is_empty() || b == b'}' {
+                    return Err(unexpected(b, "an array element"));
+                }
+                items.push(item.to_vec());
+                self.state = if b == b']' { AfterValue } else { BeforeItem };
+            }
+            AfterValue => match b {
+                b',' => self.state = BeforeKey,
+                b'}' => self.state = End,
+                _ if b.is_ascii_whitespace() => {}
+                _ => return Err(unexpected(b, "the end of a field")),
+            },
+            End => {
+                if !b.is_ascii_whitespace() {
+                    return Err(unexpected(b, "the end of the list response"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Tracks strings and nesting in a value. Returns true if `b` is the
+    /// delimiter after the value.
+    fn ends_value(&mut self, b: u8) -> bool {
+        if self.in_string {
+            match b {
+                _ if self.escape => self.escape = false,
+                b'\\' => self.escape = true,
+                b'"' => self.in_string = false,
+                _ => {}
+            }
+            return false;
+        }
+        match b {
+            b'"' => self.in_string = true,
+            b'{' | b'[' => self.nesting += 1,
+            b'}' | b']' | b',' if self.nesting == 0 => return true,
+            b'}' | b']' => self.nesting -= 1,
+            _ => {}
+        }
+        false
+    }
+}
+
+fn unexpected(b: u8, context: &str) -> String {
+    format!(
+        "unexpected character `{}` while parsing {context}",
+        b.escape_ascii()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use test_case::test_case;
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    const INPUT: &str = r#" {
+        "secrets": [
+            {"name": "a", "labels": {"k": "v}]"}},
+            {"name": "b\"}", "versions": [1, 2, {"x": []}]}
+        ],
+        "nextPageToken" : "token-123",
+        "totalSize": 2,
+        "unreachable": ["x", "y"]
+    } "#;
+
+    fn scan(chunk_size: usize) -> std::result::Result<(Vec<Value>, Value), String> {
+        let mut scanner = Scanner::new("secrets");
+        let mut raw = Vec::new();
+        for chunk in INPUT.as_bytes().chunks(chunk_size) {
+            scanner.push(chunk, &mut raw)?;
+        }
+        let items = raw
+            .iter()
+            .map(|r| serde_json::from_slice::<Value>(r).unwrap())
+            .collect();
+        let rest = serde_json::from_slice::<Value>(&scanner.finish()?).unwrap();
+        Ok((items, rest))
+    }
+
+    #[test_case(1)]
+    #[test_case(7)]
+    #[test_case(INPUT.len())]
+    fn scanner(chunk_size: usize) -> TestResult {
+        let (items, rest) = scan(chunk_size)?;
+        assert_eq!(
+            items,
+            vec![
+                json!({"name": "a", "labels": {"k": "v}]"}}),
+                json!({"name": "b\"}", "versions": [1, 2, {"x": []}]}),
+            ]
+        );
+        assert_eq!(
+            rest,
+            json!({"nextPageToken": "token-123", "totalSize": 2, "unreachable": ["x", "y"]})
+        );
+        Ok(())
+    }
+
+    #[test_case("{}", 0, json!({}))]
+    #[test_case(r#"{"secrets": []}"#, 0, json!({}))]
+    #[test_case(r#"{"secrets": ["a", "b"]}"#, 2, json!({}))]
+    #[test_case(r#"{"nextPageToken": "t", "secrets": [{}]}"#, 1, json!({"nextPageToken": "t"}))]
+    #[test_case(r#"{"secrets": null}"#, 0, json!({"secrets": null}))]
+    fn scanner_edge_cases(input: &str, count: usize, want: Value) -> TestResult {
+        let mut scanner = Scanner::new("secrets");
+        let mut raw = Vec::new();
+        scanner.push(input.as_bytes(), &mut raw)?;
+        assert_eq!(raw.len(), count, "{raw:?}");
+        let rest = serde_json::from_slice::<Value>(&scanner.finish()?)?;
+        assert_eq!(rest, want);
+        Ok(())
+    }
+
+    #[test_case("[]")]
+    #[test_case(r#"{"secrets": [}"#)]
+    #[test_case(r#"{"a": }"#)]
+    #[test_case(r#"{"a" 1}"#)]
+    #[test_case(r#"{"a": 1} x"#)]
+    #[test_case(r#"{"a": 1, }"#)]
+    fn scanner_errors(input: &str) {
+        let mut scanner = Scanner::new("secrets");
+        let mut raw = Vec::new();
+        let got = scanner.push(input.as_bytes(), &mut raw);
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test]
+    fn scanner_incomplete() -> TestResult {
+        let mut scanner = Scanner::new("secrets");
+        let mut raw = Vec::new();
+        scanner.push(br#"{"secrets": [{"name": "a"}, {"name""#, &mut raw)?;
+        assert_eq!(raw.len(), 1);
+        assert!(scanner.finish().is_err());
+        Ok(())
+    }
+
+    #[derive(Debug, Default, serde::Deserialize)]
+    #[serde(default, rename_all = "camelCase")]
+    struct TestResponse {
+        secrets: Vec<TestItem>,
+        next_page_token: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    struct TestItem {
+        name: String,
+    }
+
+    impl PageableResponse for TestResponse {
+        type PageItem = TestItem;
+
+        fn items(self) -> Vec<TestItem> {
+            self.secrets
+        }
+
+        fn next_page_token(&self) -> String {
+            self.next_page_token.clone()
+        }
+    }
+
+    fn response(body: &'static str) -> reqwest::Response {
+        reqwest::Response::from(http::Response::new(body))
+    }
+
+    #[tokio::test]
+    async fn list_stream() -> TestResult {
+        let mut stream = ListStream::<TestResponse>::new(
+            response(r#"{"secrets": [{"name": "a"}, {"name": "b"}], "nextPageToken": "t"}"#),
+            "secrets",
+        );
+        assert_eq!(stream.next_page_token(), None);
+        let mut names = Vec::new();
+        while let Some(item) = stream.next().await {
+            names.push(item?.name);
+        }
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(stream.next_page_token(), Some("t"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_stream_error() -> TestResult {
+        let mut stream =
+            ListStream::<TestResponse>::new(response(r#"{"secrets": [{"name": 1}]}"#), "secrets");
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::Serde, "{err:?}");
+        assert!(stream.next().await.is_none());
+        assert_eq!(stream.next_page_token(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_items() -> TestResult {
+        let execute = |token: String| async move {
+            let body = match token.as_str() {
+                "" => r#"{"secrets": [{"name": "a"}], "nextPageToken": "p2"}"#,
+                "p2" => r#"{"secrets": [], "nextPageToken": "p3"}"#,
+                "p3" => r#"{"secrets": [{"name": "b"}, {"name": "c"}]}"#,
+                _ => return Err(Error::other(format!("unexpected token {token}"))),
+            };
+            Ok(ListStream::<TestResponse>::new(response(body), "secrets"))
+        };
+        let mut items = ListItems::new(String::new(), execute);
+        let mut names = Vec::new();
+        while let Some(item) = items.next().await {
+            names.push(item?.name);
+        }
+        assert_eq!(names, vec!["a", "b", "c"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_items_error() -> TestResult {
+        let execute = |token: String| async move {
+            match token.as_str() {
+                "" => Ok(ListStream::<TestResponse>::new(
+                    response(r#"{"secrets": [{"name": "a"}], "nextPageToken": "p2"}"#),
+                    "secrets",
+                )),
+                _ => Err(Error::other("simulated failure")),
+            }
+        };
+        let mut items = ListItems::new(String::new(), execute);
+        assert_eq!(
+            items.next().await.transpose()?.map(|i| i.name).as_deref(),
+            Some("a")
+        );
+        assert!(items.next().await.unwrap().is_err());
+        assert!(items.next().await.is_none());
+        Ok(())
+    }
+}
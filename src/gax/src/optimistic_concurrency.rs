@@ -0,0 +1,297 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to update resources using optimistic concurrency control.
+//!
+//! Many resources, such as Secret Manager secrets and IAM policies, include an
+//! `etag` field. The service rejects updates where the `etag` does not match
+//! the current version of the resource, typically with `ABORTED` or
+//! `FAILED_PRECONDITION`. Applications should read the resource again, apply
+//! their changes to the new version, and retry the update.
+//!
+//! [ReadModifyWrite] implements this loop. The application provides three
+//! functions: one to read the resource, one to apply the changes, and one to
+//! update the resource. The update must send the `etag` returned by the read.
+//!
+//! # Example
+//! ```
+//! # use gcp_sdk_gax::optimistic_concurrency::ReadModifyWrite;
+//! # use gcp_sdk_gax::Result;
+//! #[derive(Clone, Debug)]
+//! struct Resource {
+//!     etag: String,
+//!     labels: Vec<String>,
+//! }
+//! async fn read() -> Result<Resource> {
+//!     // In a real application this would call `get_*()` on a client.
+//!     Ok(Resource { etag: "v1".into(), labels: Vec::new() })
+//! }
+//! async fn update(resource: Resource) -> Result<Resource> {
+//!     // In a real application this would call `update_*()` on a client, the
+//!     // service verifies the `etag` field.
+//!     Ok(resource)
+//! }
+//! async fn add_label() -> Result<Resource> {
+//!     ReadModifyWrite::new()
+//!         .run(read, |mut r| { r.labels.push("new".into()); Ok(r) }, update)
+//!         .await
+//! }
+//! ```
+
+use crate::backoff_policy::{BackoffPolicy, BackoffPolicyArg, ExponentialBackoff};
+use crate::error::Error;
+use crate::retry_policy::{RetryFlow, RetryPolicy, RetryPolicyArg, RetryPolicyExt};
+use crate::Result;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Retries a read-modify-write loop on concurrency conflicts.
+///
+/// The policy continues the loop if the error status is `ABORTED` or
+/// `FAILED_PRECONDITION`. The HTTP status codes `409 Conflict` and
+/// `412 Precondition Failed` map to these statuses. All other errors are
+/// permanent. The calls in the loop use their own retry policies for
+/// transient errors.
+///
+/// This policy never stops the loop. Decorate it, e.g., with
+/// [with_attempt_limit][crate::retry_policy::RetryPolicyExt::with_attempt_limit],
+/// to limit the number of attempts.
+#[derive(Clone, Debug)]
+pub struct ConflictRetry;
+
+impl RetryPolicy for ConflictRetry {
+    fn on_error(
+        &self,
+        _loop_start: std::time::Instant,
+        _attempt_count: u32,
+        _idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        if is_conflict(&error) {
+            RetryFlow::Continue(error)
+        } else {
+            RetryFlow::Permanent(error)
+        }
+    }
+}
+
+fn is_conflict(error: &Error) -> bool {
+    error
+        .status()
+        .and_then(|s| s.status)
+        .is_some_and(|s| s == "ABORTED" || s == "FAILED_PRECONDITION")
+}
+
+/// Reads a resource, applies changes to it, and updates it, retrying on
+/// concurrency conflicts.
+///
+/// By default, the loop makes at most 5 attempts, using [ConflictRetry] and
+/// the default [ExponentialBackoff] policy.
+#[derive(Clone, Debug)]
+pub struct ReadModifyWrite {
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+}
+
+impl ReadModifyWrite {
+    /// Creates a new instance with the default policies.
+    pub fn new() -> Self {
+        Self {
+            retry_policy: Arc::new(ConflictRetry.with_attempt_limit(5)),
+            backoff_policy: Arc::new(ExponentialBackoff::default()),
+        }
+    }
+
+    /// Sets the policy to retry the loop.
+    pub fn with_retry_policy<V: Into<RetryPolicyArg>>(mut self, v: V) -> Self {
+        self.retry_policy = v.into().0;
+        self
+    }
+
+    /// Sets the backoff policy between attempts.
+    pub fn with_backoff_policy<V: Into<BackoffPolicyArg>>(mut self, v: V) -> Self {
+        self.backoff_policy = v.into().0;
+        self
+    }
+
+    /// Runs the read-modify-write loop.
+    ///
+    /// Each attempt calls `read` to get the current version of the resource,
+    /// then `modify` to apply the changes, and finally `update` with the
+    /// result. Errors from `modify` stop the loop, unless they are
+    /// conflicts. Returns the result of the first successful `update`.
+    pub async fn run<T, R, RF, M, U, UF>(self, read: R, modify: M, update: U) -> Result<T>
+    where
+        R: Fn() -> RF,
+        RF: Future<Output = Result<T>>,
+        M: Fn(T) -> Result<T>,
+        U: Fn(T) -> UF,
+        UF: Future<Output = Result<T>>,
+    {
+        let (read, modify, update) = (&read, &modify, &update);
+        let inner = |_| async move {
+            let current = read().await?;
+            update(modify(current)?).await
+        };
+        crate::retry_loop_internal::retry_loop(
+            inner,
+            tokio::time::sleep,
+            // The `etag` makes the update safe to retry.
+            true,
+            self.retry_policy,
+            self.backoff_policy,
+            None,
+            None,
+        )
+        .await
+    }
+}
+
+impl Default for ReadModifyWrite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::HttpError;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use test_case::test_case;
+
+    fn http_error(code: u16, status: &str) -> Error {
+        let payload = serde_json::json!({"error": {
+            "code": code,
+            "status": status,
+            "message": "test-only",
+        }});
+        let payload = bytes::Bytes::from_owner(payload.to_string());
+        Error::rpc(HttpError::new(code, HashMap::new(), Some(payload)))
+    }
+
+    fn test_backoff() -> ExponentialBackoff {
+        crate::backoff_policy::ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_maximum_delay(Duration::from_millis(1))
+            .build()
+            .unwrap()
+    }
+
+    #[test_case(http_error(409, "ABORTED"), true)]
+    #[test_case(http_error(400, "FAILED_PRECONDITION"), true)]
+    #[test_case(Error::rpc(HttpError::new(412, HashMap::new(), None)), true)]
+    #[test_case(http_error(404, "NOT_FOUND"), false)]
+    #[test_case(http_error(503, "UNAVAILABLE"), false)]
+    #[test_case(Error::io("err"), false)]
+    fn conflict_retry(error: Error, want: bool) {
+        let flow = ConflictRetry.on_error(std::time::Instant::now(), 1, true, error);
+        assert_eq!(flow.is_continue(), want, "{flow:?}");
+    }
+
+    #[tokio::test]
+    async fn success() -> Result<()> {
+        let got = ReadModifyWrite::new()
+            .run(
+                || async { Ok(1) },
+                |v| Ok(v + 1),
+                |v| async move { Ok(v * 10) },
+            )
+            .await?;
+        assert_eq!(got, 20);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_on_conflict() -> Result<()> {
+        let version = Mutex::new(0);
+        let updates = Mutex::new(Vec::new());
+        let got = ReadModifyWrite::new()
+            .with_backoff_policy(test_backoff())
+            .run(
+                || {
+                    let mut v = version.lock().unwrap();
+                    *v += 1;
+                    let current = *v;
+                    async move { Ok(current) }
+                },
+                |v| Ok(v * 10),
+                |v| {
+                    updates.lock().unwrap().push(v);
+                    async move {
+                        if v < 30 {
+                            return Err(http_error(409, "ABORTED"));
+                        }
+                        Ok(v)
+                    }
+                },
+            )
+            .await?;
+        assert_eq!(got, 30);
+        assert_eq!(*updates.lock().unwrap(), vec![10, 20, 30]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn attempt_limit() {
+        let count = Mutex::new(0);
+        let got = ReadModifyWrite::new()
+            .with_retry_policy(ConflictRetry.with_attempt_limit(3))
+            .with_backoff_policy(test_backoff())
+            .run(
+                || async { Ok(()) },
+                Ok,
+                |_| {
+                    *count.lock().unwrap() += 1;
+                    async { Err::<(), _>(http_error(409, "ABORTED")) }
+                },
+            )
+            .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*count.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn permanent_errors() {
+        let count = Mutex::new(0);
+        let got = ReadModifyWrite::new()
+            .run(
+                || async { Ok(()) },
+                |_| Err(Error::other("bad change")),
+                |v| {
+                    *count.lock().unwrap() += 1;
+                    async move { Ok(v) }
+                },
+            )
+            .await;
+        let err = got.unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::Other, "{err:?}");
+        assert_eq!(*count.lock().unwrap(), 0);
+
+        let got = ReadModifyWrite::new()
+            .run(
+                || async { Err::<(), _>(http_error(404, "NOT_FOUND")) },
+                Ok,
+                |v| async move { Ok(v) },
+            )
+            .await;
+        let err = got.unwrap_err();
+        assert_eq!(
+            err.status().and_then(|s| s.status).as_deref(),
+            Some("NOT_FOUND")
+        );
+    }
+}
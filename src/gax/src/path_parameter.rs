@@ -35,6 +35,17 @@ where
     }
 }
 
+/// Returns true if `value` matches the template of a path variable.
+///
+/// The template is the part after the `=` in a `google.api.http` path
+/// variable, such as `projects/*/secrets/*`. A `*` matches a single, non-empty
+/// segment and a `**` matches any number of segments. The generated clients
+/// use this function to select the HTTP binding for a request.
+pub fn matches(value: &str, template: &str) -> bool {
+    use crate::routing_parameter::{matches, segments};
+    matches(&segments(template), &segments(value))
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("missing required parameter {0}")]
@@ -44,6 +55,7 @@ pub enum Error {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_case::test_case;
     type Result = std::result::Result<(), Error>;
 
     #[test]
@@ -62,6 +74,25 @@ mod tests {
         Ok(())
     }
 
+    #[test_case("projects/p/secrets/s", "projects/*/secrets/*", true)]
+    #[test_case("projects/p/locations/l/secrets/s", "projects/*/secrets/*", false)]
+    #[test_case(
+        "projects/p/locations/l/secrets/s",
+        "projects/*/locations/*/secrets/*",
+        true
+    )]
+    #[test_case("projects/p", "projects/*", true)]
+    #[test_case("projects/", "projects/*", false)]
+    #[test_case("locations", "locations", true)]
+    #[test_case("p", "*", true)]
+    #[test_case("", "*", false)]
+    #[test_case("projects/p/secrets/s", "*", false)]
+    #[test_case("projects/p/secrets/s", "**", true)]
+    #[test_case("projects/p/secrets/s", "projects/*/**", true)]
+    fn match_template(value: &str, template: &str, want: bool) {
+        assert_eq!(matches(value, template), want, "{value} {template}");
+    }
+
     #[test]
     fn required() -> Result {
         let v = "value".to_string();
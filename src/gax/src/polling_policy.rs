@@ -0,0 +1,452 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines traits for polling policies and some common implementations.
+//!
+//! Some RPCs start a [long-running operation] (LRO). The client libraries
+//! poll these operations until they complete. The polling policy determines
+//! if the polling loop continues after an error, and how long the loop may
+//! wait for the operation to complete. The delay between polling attempts is
+//! controlled by a [BackoffPolicy][crate::backoff_policy::BackoffPolicy].
+//!
+//! # Example:
+//! ```
+//! # use gcp_sdk_gax::polling_policy::*;
+//! // Poll for at most 10 minutes, whatever the number of attempts.
+//! let d = std::time::Duration::from_secs(600);
+//! let policy = Aip194Strict.with_time_limit(d);
+//! ```
+//!
+//! [long-running operation]: https://google.aip.dev/151
+
+use crate::clock::{Clock, TokioClock};
+use crate::error::Error;
+use crate::retry_policy::{RetryFlow, RetryPolicy};
+use std::sync::Arc;
+
+/// Determines how errors and in-progress operations are handled in the
+/// polling loop.
+pub trait PollingPolicy: Send + Sync + std::fmt::Debug {
+    /// Query the polling policy after an error.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the polling loop started, as reported by
+    ///   [TokioClock][crate::clock::TokioClock].
+    /// * `attempt_count` - the number of polling attempts. This method is
+    ///   called after the first attempt, so the value is always non-zero.
+    /// * `error` - the last error when polling the operation.
+    fn on_error(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        error: Error,
+    ) -> RetryFlow;
+
+    /// Query the polling policy after the operation is still in progress.
+    ///
+    /// Returns `None` if the polling loop should continue. Returns an error
+    /// if the loop should stop, for example, if the time limit for the
+    /// polling loop is exhausted.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the polling loop started.
+    /// * `attempt_count` - the number of polling attempts.
+    /// * `operation_name` - the name of the long-running operation.
+    fn on_in_progress(
+        &self,
+        _loop_start: std::time::Instant,
+        _attempt_count: u32,
+        _operation_name: &str,
+    ) -> Option<Error> {
+        None
+    }
+}
+
+/// A helper type to use [PollingPolicy] in client and request options.
+#[derive(Clone)]
+pub struct PollingPolicyArg(pub(crate) Arc<dyn PollingPolicy>);
+
+impl<T> std::convert::From<T> for PollingPolicyArg
+where
+    T: PollingPolicy + 'static,
+{
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl std::convert::From<Arc<dyn PollingPolicy>> for PollingPolicyArg {
+    fn from(value: Arc<dyn PollingPolicy>) -> Self {
+        Self(value)
+    }
+}
+
+impl std::convert::From<PollingPolicyArg> for Arc<dyn PollingPolicy> {
+    fn from(value: PollingPolicyArg) -> Self {
+        value.0
+    }
+}
+
+/// Extension trait for [PollingPolicy].
+pub trait PollingPolicyExt: PollingPolicy + Sized {
+    /// Decorate a [PollingPolicy] to limit the total elapsed time in the
+    /// polling loop.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_gax::polling_policy::*;
+    /// use std::time::Duration;
+    /// use std::time::Instant;
+    /// let policy = Aip194Strict.with_time_limit(Duration::ZERO);
+    /// assert!(policy.on_in_progress(Instant::now(), 1, "op").is_some());
+    /// ```
+    fn with_time_limit(self, maximum_duration: std::time::Duration) -> LimitedElapsedTime<Self> {
+        LimitedElapsedTime::custom(self, maximum_duration)
+    }
+
+    /// Decorate a [PollingPolicy] to limit the number of polling attempts.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_gax::polling_policy::*;
+    /// use std::time::Instant;
+    /// let policy = Aip194Strict.with_attempt_limit(3);
+    /// assert!(policy.on_in_progress(Instant::now(), 2, "op").is_none());
+    /// assert!(policy.on_in_progress(Instant::now(), 3, "op").is_some());
+    /// ```
+    fn with_attempt_limit(self, maximum_attempts: u32) -> LimitedAttemptCount<Self> {
+        LimitedAttemptCount::custom(self, maximum_attempts)
+    }
+}
+
+impl<T: PollingPolicy> PollingPolicyExt for T {}
+
+/// A polling policy that strictly follows [AIP-194].
+///
+/// Polling an operation is always idempotent. This policy continues the
+/// polling loop on any error that [retry_policy::Aip194Strict] considers
+/// retryable for idempotent RPCs.
+///
+/// This policy must be decorated to limit the number of polling attempts or
+/// the duration of the polling loop.
+///
+/// [AIP-194]: https://google.aip.dev/194
+/// [retry_policy::Aip194Strict]: crate::retry_policy::Aip194Strict
+#[derive(Clone, Debug)]
+pub struct Aip194Strict;
+
+impl PollingPolicy for Aip194Strict {
+    fn on_error(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        error: Error,
+    ) -> RetryFlow {
+        crate::retry_policy::Aip194Strict.on_error(loop_start, attempt_count, true, error)
+    }
+}
+
+/// A polling policy that continues on any error.
+///
+/// This policy must be decorated to limit the number of polling attempts or
+/// the duration of the polling loop.
+#[derive(Clone, Debug)]
+pub struct AlwaysContinue;
+
+impl PollingPolicy for AlwaysContinue {
+    fn on_error(
+        &self,
+        _loop_start: std::time::Instant,
+        _attempt_count: u32,
+        error: Error,
+    ) -> RetryFlow {
+        RetryFlow::Continue(error)
+    }
+}
+
+/// A polling policy decorator that limits the total time in the polling loop.
+///
+/// # Parameters
+/// * `P` - the inner polling policy, defaults to [Aip194Strict].
+#[derive(Debug)]
+pub struct LimitedElapsedTime<P = Aip194Strict>
+where
+    P: PollingPolicy,
+{
+    inner: P,
+    maximum_duration: std::time::Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl LimitedElapsedTime {
+    /// Creates a new instance, with the default inner policy.
+    pub fn new(maximum_duration: std::time::Duration) -> Self {
+        Self {
+            inner: Aip194Strict,
+            maximum_duration,
+            clock: Arc::new(TokioClock),
+        }
+    }
+}
+
+impl<P> LimitedElapsedTime<P>
+where
+    P: PollingPolicy,
+{
+    /// Creates a new instance with a custom inner policy.
+    pub fn custom(inner: P, maximum_duration: std::time::Duration) -> Self {
+        Self {
+            inner,
+            maximum_duration,
+            clock: Arc::new(TokioClock),
+        }
+    }
+
+    /// Changes the clock used to measure the elapsed time.
+    ///
+    /// The default is [TokioClock].
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    fn expired(&self, loop_start: std::time::Instant) -> bool {
+        self.clock.now().saturating_duration_since(loop_start) >= self.maximum_duration
+    }
+}
+
+impl<P> PollingPolicy for LimitedElapsedTime<P>
+where
+    P: PollingPolicy + 'static,
+{
+    fn on_error(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        error: Error,
+    ) -> RetryFlow {
+        match self.inner.on_error(loop_start, attempt_count, error) {
+            RetryFlow::Continue(e) if self.expired(loop_start) => RetryFlow::Exhausted(e),
+            flow => flow,
+        }
+    }
+
+    fn on_in_progress(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        operation_name: &str,
+    ) -> Option<Error> {
+        self.inner
+            .on_in_progress(loop_start, attempt_count, operation_name)
+            .or_else(|| {
+                self.expired(loop_start).then(|| {
+                    Error::other(format!(
+                        "exhausted polling time limit ({:?}) for operation {operation_name}",
+                        self.maximum_duration
+                    ))
+                })
+            })
+    }
+}
+
+/// A polling policy decorator that limits the number of polling attempts.
+///
+/// # Parameters
+/// * `P` - the inner polling policy, defaults to [Aip194Strict].
+#[derive(Debug)]
+pub struct LimitedAttemptCount<P = Aip194Strict>
+where
+    P: PollingPolicy,
+{
+    inner: P,
+    maximum_attempts: u32,
+}
+
+impl LimitedAttemptCount {
+    /// Creates a new instance, with the default inner policy.
+    pub fn new(maximum_attempts: u32) -> Self {
+        Self {
+            inner: Aip194Strict,
+            maximum_attempts,
+        }
+    }
+}
+
+impl<P> LimitedAttemptCount<P>
+where
+    P: PollingPolicy,
+{
+    /// Creates a new instance with a custom inner policy.
+    pub fn custom(inner: P, maximum_attempts: u32) -> Self {
+        Self {
+            inner,
+            maximum_attempts,
+        }
+    }
+}
+
+impl<P> PollingPolicy for LimitedAttemptCount<P>
+where
+    P: PollingPolicy + 'static,
+{
+    fn on_error(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        error: Error,
+    ) -> RetryFlow {
+        match self.inner.on_error(loop_start, attempt_count, error) {
+            RetryFlow::Continue(e) if attempt_count >= self.maximum_attempts => {
+                RetryFlow::Exhausted(e)
+            }
+            flow => flow,
+        }
+    }
+
+    fn on_in_progress(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+        operation_name: &str,
+    ) -> Option<Error> {
+        self.inner
+            .on_in_progress(loop_start, attempt_count, operation_name)
+            .or_else(|| {
+                (attempt_count >= self.maximum_attempts).then(|| {
+                    Error::other(format!(
+                        "exhausted polling attempt limit ({}) for operation {operation_name}",
+                        self.maximum_attempts
+                    ))
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::rpc::Status;
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    fn transient() -> Error {
+        Error::io("transient".to_string())
+    }
+
+    fn http_error(code: i32, status: &str) -> Error {
+        let s = Status {
+            code,
+            message: "test-only".to_string(),
+            status: Some(status.to_string()),
+            ..Default::default()
+        };
+        let payload = serde_json::json!({"error": s}).to_string();
+        let payload = bytes::Bytes::from_owner(payload);
+        let http = crate::error::HttpError::new(code as u16, HashMap::new(), Some(payload));
+        Error::rpc(http)
+    }
+
+    #[test]
+    fn aip194_strict() {
+        let p = Aip194Strict;
+        let now = Instant::now();
+        assert!(p.on_error(now, 1, transient()).is_continue());
+        assert!(p
+            .on_error(now, 1, http_error(503, "UNAVAILABLE"))
+            .is_continue());
+        assert!(p
+            .on_error(now, 1, http_error(403, "PERMISSION_DENIED"))
+            .is_permanent());
+        assert!(p.on_error(now, 1, Error::other("test")).is_permanent());
+        assert!(p.on_in_progress(now, 100, "op").is_none());
+    }
+
+    #[test]
+    fn always_continue() {
+        let p = AlwaysContinue;
+        let now = Instant::now();
+        assert!(p.on_error(now, 1, transient()).is_continue());
+        assert!(p.on_error(now, 1, Error::other("test")).is_continue());
+        assert!(p.on_in_progress(now, 100, "op").is_none());
+    }
+
+    #[test]
+    fn limited_elapsed_time() {
+        let p = AlwaysContinue.with_time_limit(Duration::from_secs(60));
+        let now = Instant::now();
+        assert!(p.on_error(now, 1, transient()).is_continue());
+        assert!(p.on_in_progress(now, 1, "op").is_none());
+
+        let start = now - Duration::from_secs(120);
+        assert!(p.on_error(start, 1, transient()).is_exhausted());
+        let err = p.on_in_progress(start, 1, "test-op");
+        assert!(
+            err.as_ref()
+                .is_some_and(|e| e.to_string().contains("test-op")),
+            "{err:?}"
+        );
+
+        let p = LimitedElapsedTime::new(Duration::from_secs(60));
+        assert!(p.on_error(now, 1, Error::other("test")).is_permanent());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limited_elapsed_time_with_paused_clock() {
+        let p = AlwaysContinue.with_time_limit(Duration::from_secs(60));
+        let start = TokioClock.now();
+        assert!(p.on_in_progress(start, 1, "op").is_none());
+        tokio::time::advance(Duration::from_secs(59)).await;
+        assert!(p.on_in_progress(start, 2, "op").is_none());
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(p.on_in_progress(start, 3, "op").is_some());
+        assert!(p.on_error(start, 3, transient()).is_exhausted());
+    }
+
+    #[test]
+    fn limited_attempt_count() {
+        let p = AlwaysContinue.with_attempt_limit(3);
+        let now = Instant::now();
+        assert!(p.on_error(now, 1, transient()).is_continue());
+        assert!(p.on_error(now, 2, transient()).is_continue());
+        assert!(p.on_error(now, 3, transient()).is_exhausted());
+        assert!(p.on_in_progress(now, 2, "op").is_none());
+        assert!(p.on_in_progress(now, 3, "op").is_some());
+
+        let p = LimitedAttemptCount::new(3);
+        assert!(p.on_error(now, 1, Error::other("test")).is_permanent());
+    }
+
+    #[test]
+    fn decorators_compose() {
+        let p = AlwaysContinue
+            .with_attempt_limit(3)
+            .with_time_limit(Duration::from_secs(60));
+        let now = Instant::now();
+        assert!(p.on_in_progress(now, 1, "op").is_none());
+        assert!(p.on_in_progress(now, 3, "op").is_some());
+        let start = now - Duration::from_secs(120);
+        assert!(p.on_in_progress(start, 1, "op").is_some());
+    }
+
+    // Verify `PollingPolicyArg` can be converted from the desired types.
+    #[test]
+    fn polling_policy_arg() {
+        let policy = LimitedAttemptCount::new(3);
+        let _ = PollingPolicyArg::from(policy);
+
+        let policy: Arc<dyn PollingPolicy> = Arc::new(LimitedAttemptCount::new(3));
+        let _ = PollingPolicyArg::from(policy);
+    }
+}
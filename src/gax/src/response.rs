@@ -0,0 +1,146 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types to return the response metadata with the response body.
+//!
+//! Most applications only need the response body. Some applications also need
+//! the metadata of a successful response, such as the response headers. For
+//! example, to log the `x-goog-request-id` header, or to examine headers
+//! returned by a service.
+//!
+//! # Example
+//! ```
+//! # use gcp_sdk_gax::response::Response;
+//! fn log_response(response: &Response<String>) {
+//!     let id = response.headers().get("x-goog-request-id");
+//!     println!("status={}, request id={id:?}", response.status());
+//!     println!("body={}", response.body());
+//! }
+//! ```
+
+/// The metadata of a successful response.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct Parts {
+    /// The HTTP status code.
+    pub status: http::StatusCode,
+
+    /// The response headers.
+    pub headers: http::HeaderMap,
+}
+
+impl Parts {
+    /// Creates a new instance with a `200 OK` status and no headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the status code.
+    pub fn set_status(mut self, v: http::StatusCode) -> Self {
+        self.status = v;
+        self
+    }
+
+    /// Sets the response headers.
+    pub fn set_headers(mut self, v: http::HeaderMap) -> Self {
+        self.headers = v;
+        self
+    }
+}
+
+/// A successful response: the decoded body and the response metadata.
+#[derive(Clone, Debug)]
+pub struct Response<T> {
+    parts: Parts,
+    body: T,
+}
+
+impl<T> Response<T> {
+    /// Creates a response from its metadata and body.
+    pub fn from_parts(parts: Parts, body: T) -> Self {
+        Self { parts, body }
+    }
+
+    /// Returns the metadata and body.
+    pub fn into_parts(self) -> (Parts, T) {
+        (self.parts, self.body)
+    }
+
+    /// Returns the HTTP status code.
+    pub fn status(&self) -> http::StatusCode {
+        self.parts.status
+    }
+
+    /// Returns the response headers.
+    pub fn headers(&self) -> &http::HeaderMap {
+        &self.parts.headers
+    }
+
+    /// Returns the decoded response body.
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+
+    /// Returns the decoded response body, discarding the metadata.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+/// Creates a response with default metadata, mostly useful in tests.
+impl<T> From<T> for Response<T> {
+    fn from(body: T) -> Self {
+        Self::from_parts(Parts::default(), body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-goog-generation", http::HeaderValue::from_static("123"));
+        let parts = Parts::new()
+            .set_status(http::StatusCode::CREATED)
+            .set_headers(headers.clone());
+        assert_eq!(parts.status, http::StatusCode::CREATED);
+        assert_eq!(parts.headers, headers);
+
+        let parts = Parts::new();
+        assert_eq!(parts.status, http::StatusCode::OK);
+        assert!(parts.headers.is_empty(), "{parts:?}");
+    }
+
+    #[test]
+    fn response() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-goog-generation", http::HeaderValue::from_static("123"));
+        let response = Response::from_parts(Parts::new().set_headers(headers.clone()), "body");
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.headers(), &headers);
+        assert_eq!(response.body(), &"body");
+
+        let (parts, body) = response.clone().into_parts();
+        assert_eq!(parts.headers, headers);
+        assert_eq!(body, "body");
+        assert_eq!(response.into_body(), "body");
+
+        let response = Response::from(42);
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert!(response.headers().is_empty(), "{response:?}");
+        assert_eq!(response.into_body(), 42);
+    }
+}
@@ -0,0 +1,565 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::backoff_policy::BackoffPolicy;
+use crate::error::Error;
+use crate::retry_policy::{RetryFlow, RetryPolicy};
+use crate::retry_throttler::RetryThrottler;
+use crate::Result;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Runs the retry loop for a given function.
+///
+/// This functions calls an inner function as long as (1) the retry policy has
+/// not expired, (2) the inner function has not returned a successful request,
+/// and (3) the retry policy permits more attempts.
+///
+/// Between attempts the function sleeps using `sleep`. The client libraries
+/// use [tokio::time::sleep], tests can provide a function that records the
+/// delays and returns immediately. The loop start time, passed to the
+/// policies, comes from [TokioClock][crate::clock::TokioClock]. Either way, the
+/// retry loop never depends on the wall clock, and [tokio::time::pause]
+/// controls both the delays and the time limits.
+///
+/// The inner function receives the remaining time in the retry policy, if
+/// any. It should use this value to limit the duration of each attempt.
+///
+/// The retry throttler, if any, observes the result of each attempt. It may
+/// stop the loop before a retry attempt, in which case the loop returns the
+/// last error.
+///
+/// If the request has a deadline, no attempt runs past it. When the backoff
+/// delay would reach the deadline, the loop stops without sleeping. In that
+/// case, and if the deadline cuts a retry attempt short, the loop returns an
+/// [ErrorKind::DeadlineExceeded] error with the last error as its source. If
+/// the deadline cuts short the initial attempt there is no last error, and
+/// the loop returns an [ErrorKind::Timeout] error.
+///
+/// [ErrorKind::DeadlineExceeded]: crate::error::ErrorKind::DeadlineExceeded
+/// [ErrorKind::Timeout]: crate::error::ErrorKind::Timeout
+pub async fn retry_loop<F, S, Response, FutureResponse, SleepFuture>(
+    inner: F,
+    sleep: S,
+    idempotent: bool,
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+    retry_throttler: Option<Arc<dyn RetryThrottler>>,
+    deadline: Option<std::time::Instant>,
+) -> Result<Response>
+where
+    F: Fn(Option<Duration>) -> FutureResponse,
+    FutureResponse: Future<Output = Result<Response>>,
+    S: Fn(Duration) -> SleepFuture,
+    SleepFuture: Future<Output = ()>,
+{
+    let loop_start = crate::clock::now();
+    let mut attempt_count = 0;
+    let mut last_error = None;
+    loop {
+        let remaining_time = retry_policy.remaining_time(loop_start, attempt_count);
+        attempt_count += 1;
+        let result = match deadline {
+            None => inner(remaining_time).await,
+            Some(d) => match tokio::time::timeout_at(d.into(), inner(remaining_time)).await {
+                Ok(r) => r,
+                Err(elapsed) => {
+                    return Err(match last_error {
+                        Some(e) => Error::deadline_exceeded(e),
+                        None => Error::timeout(elapsed),
+                    });
+                }
+            },
+        };
+        let flow = match result {
+            Ok(r) => {
+                if let Some(t) = &retry_throttler {
+                    t.on_success();
+                }
+                return Ok(r);
+            }
+            Err(e) => retry_policy.on_error(loop_start, attempt_count, idempotent, e),
+        };
+        if let Some(t) = &retry_throttler {
+            t.on_retry_failure(&flow);
+        }
+        match flow {
+            RetryFlow::Permanent(e) | RetryFlow::Exhausted(e) => return Err(e),
+            RetryFlow::Continue(e) => {
+                if retry_throttler
+                    .as_ref()
+                    .is_some_and(|t| t.throttle_retry_attempt())
+                {
+                    return Err(e);
+                }
+                let delay = match deadline {
+                    None => backoff_policy.on_failure(loop_start, attempt_count),
+                    Some(d) => {
+                        let remaining = d.saturating_duration_since(crate::clock::now());
+                        let delay = backoff_policy.on_failure_with_remaining_time(
+                            loop_start,
+                            attempt_count,
+                            remaining,
+                        );
+                        // An attempt after the delay would have no time left.
+                        if delay >= remaining {
+                            return Err(Error::deadline_exceeded(e));
+                        }
+                        delay
+                    }
+                };
+                last_error = Some(e);
+                sleep(delay).await;
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    mockall::mock! {
+        #[derive(Debug)]
+        RetryPolicy {}
+        impl RetryPolicy for RetryPolicy {
+            fn on_error(&self, loop_start: std::time::Instant, attempt_count: u32, idempotent: bool, error: Error) -> RetryFlow;
+            fn remaining_time(&self, loop_start: std::time::Instant, attempt_count: u32) -> Option<Duration>;
+        }
+    }
+
+    mockall::mock! {
+        #[derive(Debug)]
+        BackoffPolicy {}
+        impl BackoffPolicy for BackoffPolicy {
+            fn on_failure(&self, loop_start: std::time::Instant, attempt_count: u32) -> Duration;
+        }
+    }
+
+    fn transient() -> Error {
+        Error::io("transient".to_string())
+    }
+
+    #[tokio::test]
+    async fn immediate_success() -> TestResult {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy
+            .expect_remaining_time()
+            .times(1)
+            .returning(|_, _| None);
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy.expect_on_failure().never();
+
+        let inner = |_: Option<Duration>| async { Ok("success".to_string()) };
+        let sleep = |_: Duration| async { panic!("unexpected sleep") };
+        let response = retry_loop(
+            inner,
+            sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await?;
+        assert_eq!(response, "success");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn success_after_retries() -> TestResult {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy
+            .expect_remaining_time()
+            .times(3)
+            .returning(|_, _| Some(Duration::from_secs(30)));
+        retry_policy
+            .expect_on_error()
+            .times(2)
+            .returning(|_, _, _, e| RetryFlow::Continue(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .times(2)
+            .returning(|_, count| Duration::from_millis(10 * count as u64));
+
+        let calls = Mutex::new(Vec::new());
+        let inner = |remaining: Option<Duration>| {
+            let mut calls = calls.lock().unwrap();
+            calls.push(remaining);
+            let result = if calls.len() < 3 {
+                Err(transient())
+            } else {
+                Ok("success".to_string())
+            };
+            async move { result }
+        };
+        let delays = Mutex::new(Vec::new());
+        let sleep = |d: Duration| {
+            delays.lock().unwrap().push(d);
+            async {}
+        };
+        let response = retry_loop(
+            inner,
+            sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await?;
+        assert_eq!(response, "success");
+        assert_eq!(
+            calls.lock().unwrap().as_slice(),
+            &[Some(Duration::from_secs(30)); 3]
+        );
+        assert_eq!(
+            delays.lock().unwrap().as_slice(),
+            &[Duration::from_millis(10), Duration::from_millis(20)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn permanent_error() -> TestResult {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy
+            .expect_remaining_time()
+            .times(1)
+            .returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .times(1)
+            .returning(|_, _, _, e| RetryFlow::Permanent(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy.expect_on_failure().never();
+
+        let inner = |_: Option<Duration>| async { Err::<String, Error>(transient()) };
+        let sleep = |_: Duration| async { panic!("unexpected sleep") };
+        let response = retry_loop(
+            inner,
+            sleep,
+            false,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await;
+        assert!(response.is_err(), "{response:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exhausted_error() -> TestResult {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy
+            .expect_remaining_time()
+            .times(3)
+            .returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .times(3)
+            .returning(|_, count, _, e| {
+                if count < 3 {
+                    RetryFlow::Continue(e)
+                } else {
+                    RetryFlow::Exhausted(e)
+                }
+            });
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .times(2)
+            .returning(|_, _| Duration::from_millis(10));
+
+        let inner = |_: Option<Duration>| async { Err::<String, Error>(transient()) };
+        let sleep = |_: Duration| async {};
+        let response = retry_loop(
+            inner,
+            sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await;
+        assert!(response.is_err(), "{response:?}");
+        Ok(())
+    }
+
+    async fn throttled_loop(calls: &Mutex<u32>, throttler: Arc<dyn RetryThrottler>) -> Result<u32> {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy.expect_remaining_time().returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .returning(|_, _, _, e| RetryFlow::Continue(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .returning(|_, _| Duration::from_millis(10));
+        let inner = |_: Option<Duration>| {
+            let mut calls = calls.lock().unwrap();
+            *calls += 1;
+            let result = if *calls < 3 {
+                Err(transient())
+            } else {
+                Ok(*calls)
+            };
+            async move { result }
+        };
+        retry_loop(
+            inner,
+            |_| async {},
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            Some(throttler),
+            None,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn throttled() -> TestResult {
+        use crate::retry_throttler::TokenBucketBuilder;
+        let throttler: Arc<dyn RetryThrottler> = Arc::new(
+            TokenBucketBuilder::new()
+                .with_max_tokens(4.0)
+                .with_token_ratio(1.0)
+                .build()?,
+        );
+
+        // Two failures drain the bucket to half. The second failure stops the
+        // retry loop.
+        let calls = Mutex::new(0);
+        let response = throttled_loop(&calls, throttler.clone()).await;
+        assert!(response.is_err(), "{response:?}");
+        assert_eq!(*calls.lock().unwrap(), 2);
+
+        // The initial attempt is not throttled, and a success adds a token.
+        let calls = Mutex::new(2);
+        let response = throttled_loop(&calls, throttler.clone()).await?;
+        assert_eq!(response, 3);
+        assert!(!throttler.throttle_retry_attempt());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn deadline_exceeded() -> TestResult {
+        use crate::error::ErrorKind;
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy.expect_remaining_time().returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .returning(|_, _, _, e| RetryFlow::Continue(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .returning(|_, count| Duration::from_secs(10 * count as u64));
+
+        // Each attempt takes 5s.
+        let calls = Mutex::new(0);
+        let inner = |_: Option<Duration>| {
+            *calls.lock().unwrap() += 1;
+            async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Err::<String, Error>(transient())
+            }
+        };
+        let start = tokio::time::Instant::now();
+        let response = retry_loop(
+            inner,
+            tokio::time::sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            Some((start + Duration::from_secs(60)).into_std()),
+        )
+        .await;
+        // The attempts end at 5s, 20s, and 45s. After the third attempt the
+        // next backoff (30s) would reach the deadline, so the loop gives up
+        // without sleeping.
+        assert_eq!(*calls.lock().unwrap(), 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(45));
+        let err = response.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DeadlineExceeded, "{err:?}");
+        let source = err.as_inner::<Error>().unwrap();
+        assert_eq!(source.kind(), ErrorKind::Io, "{source:?}");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn deadline_cuts_retry_attempt() -> TestResult {
+        use crate::error::ErrorKind;
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy.expect_remaining_time().returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .returning(|_, _, _, e| RetryFlow::Continue(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .returning(|_, _| Duration::from_secs(10));
+
+        // The first attempt fails after 5s, the retry attempt never completes.
+        let calls = Mutex::new(0);
+        let inner = |_: Option<Duration>| {
+            let count = {
+                let mut calls = calls.lock().unwrap();
+                *calls += 1;
+                *calls
+            };
+            async move {
+                if count > 1 {
+                    std::future::pending::<()>().await;
+                }
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Err::<String, Error>(transient())
+            }
+        };
+        let start = tokio::time::Instant::now();
+        let response = retry_loop(
+            inner,
+            tokio::time::sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            Some((start + Duration::from_secs(30)).into_std()),
+        )
+        .await;
+        assert_eq!(*calls.lock().unwrap(), 2);
+        assert_eq!(start.elapsed(), Duration::from_secs(30));
+        let err = response.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DeadlineExceeded, "{err:?}");
+        let source = err.as_inner::<Error>().unwrap();
+        assert_eq!(source.kind(), ErrorKind::Io, "{source:?}");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn deadline_cuts_initial_attempt() -> TestResult {
+        use crate::error::ErrorKind;
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy.expect_remaining_time().returning(|_, _| None);
+        let backoff_policy = MockBackoffPolicy::new();
+
+        let inner = |_: Option<Duration>| async {
+            std::future::pending::<()>().await;
+            Ok::<String, Error>("never".to_string())
+        };
+        let start = tokio::time::Instant::now();
+        let response = retry_loop(
+            inner,
+            tokio::time::sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            Some((start + Duration::from_secs(30)).into_std()),
+        )
+        .await;
+        assert_eq!(start.elapsed(), Duration::from_secs(30));
+        let err = response.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Timeout, "{err:?}");
+        Ok(())
+    }
+
+    // Verify the loop works with `tokio::time::sleep` when the runtime clock
+    // is paused: the backoff delays complete without waiting in real time.
+    #[tokio::test(start_paused = true)]
+    async fn tokio_sleep_with_paused_clock() -> TestResult {
+        let mut retry_policy = MockRetryPolicy::new();
+        retry_policy.expect_remaining_time().returning(|_, _| None);
+        retry_policy
+            .expect_on_error()
+            .returning(|_, _, _, e| RetryFlow::Continue(e));
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .returning(|_, _| Duration::from_secs(3600));
+
+        let count = Mutex::new(0);
+        let inner = |_: Option<Duration>| {
+            let mut count = count.lock().unwrap();
+            *count += 1;
+            let result = if *count < 5 {
+                Err(transient())
+            } else {
+                Ok(*count)
+            };
+            async move { result }
+        };
+        let start = tokio::time::Instant::now();
+        let wall = std::time::Instant::now();
+        let response = retry_loop(
+            inner,
+            tokio::time::sleep,
+            true,
+            Arc::new(retry_policy),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await?;
+        assert_eq!(response, 5);
+        assert_eq!(start.elapsed(), Duration::from_secs(4 * 3600));
+        assert!(wall.elapsed() < Duration::from_secs(60), "{wall:?}");
+        Ok(())
+    }
+
+    // Verify time-based policies use the runtime clock: with the clock paused,
+    // the time limit expires after the backoff delays, without waiting in
+    // real time.
+    #[tokio::test(start_paused = true)]
+    async fn time_limit_with_paused_clock() -> TestResult {
+        use crate::retry_policy::{AlwaysRetry, RetryPolicyExt};
+        let mut backoff_policy = MockBackoffPolicy::new();
+        backoff_policy
+            .expect_on_failure()
+            .returning(|_, _| Duration::from_secs(60));
+
+        let calls = Mutex::new(0);
+        let inner = |_: Option<Duration>| {
+            *calls.lock().unwrap() += 1;
+            async { Err::<String, Error>(transient()) }
+        };
+        let start = tokio::time::Instant::now();
+        let wall = std::time::Instant::now();
+        let response = retry_loop(
+            inner,
+            tokio::time::sleep,
+            true,
+            Arc::new(AlwaysRetry.with_time_limit(Duration::from_secs(300))),
+            Arc::new(backoff_policy),
+            None,
+            None,
+        )
+        .await;
+        assert!(response.is_err(), "{response:?}");
+        // The attempts at 0s, 60s, ..., 240s continue, the attempt at 300s
+        // exhausts the policy.
+        assert_eq!(*calls.lock().unwrap(), 6);
+        assert_eq!(start.elapsed(), Duration::from_secs(300));
+        assert!(wall.elapsed() < Duration::from_secs(60), "{wall:?}");
+        Ok(())
+    }
+}
@@ -38,8 +38,10 @@
 //!
 //! [idempotent]: https://en.wikipedia.org/wiki/Idempotence
 
+use crate::clock::{Clock, TokioClock};
 use crate::error::rpc::Status;
 use crate::error::{Error, HttpError};
+use crate::retry_throttler::{RetryThrottler, RetryThrottlerArg};
 use std::sync::Arc;
 
 /// The result of a retry policy decision.
@@ -93,7 +95,10 @@ pub trait RetryPolicy: Send + Sync + std::fmt::Debug {
     /// Query the retry policy after an error.
     ///
     /// # Parameters
-    /// * `loop_start` - when the retry loop started.
+    /// * `loop_start` - when the retry loop started, as reported by
+    ///   [TokioClock]. Time-based policies should compare it with the time
+    ///   reported by a [Clock], so tests can control the passage of time
+    ///   via [tokio::time::pause].
     /// * `attempt_count` - the number of attempts. This includes the initial
     ///   attempt. This method called after the first attempt, so the
     ///   value is always non-zero.
@@ -125,6 +130,15 @@ pub trait RetryPolicy: Send + Sync + std::fmt::Debug {
     ) -> Option<std::time::Duration> {
         None
     }
+
+    /// The retry throttler configured with this policy, if any.
+    ///
+    /// The retry loop uses this throttler instead of the throttler in the
+    /// client configuration. Policies that decorate other policies should
+    /// return the throttler of the inner policy.
+    fn retry_throttler(&self) -> Option<Arc<dyn RetryThrottler>> {
+        None
+    }
 }
 
 /// A helper type to use [RetryPolicy] in client and request options.
@@ -198,6 +212,35 @@ pub trait RetryPolicyExt: RetryPolicy + Sized {
     fn with_attempt_limit(self, maximum_attempts: u32) -> LimitedAttemptCount<Self> {
         LimitedAttemptCount::custom(self, maximum_attempts)
     }
+
+    /// Decorate a [RetryPolicy] with a retry throttler.
+    ///
+    /// The throttler stops retry attempts when too many attempts are failing,
+    /// see the [retry_throttler][crate::retry_throttler] module for details.
+    /// It replaces any throttler set in the client configuration. The policy
+    /// passes through the results from the inner policy.
+    ///
+    /// A throttler is only effective if it observes many requests. Use the
+    /// policy in the client configuration, or share the throttler with an
+    /// `Arc<dyn RetryThrottler>`, rather than creating a new throttler for
+    /// each request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_gax::retry_policy::*;
+    /// # use gcp_sdk_gax::retry_throttler::TokenBucket;
+    /// # use gcp_sdk_gax::options;
+    /// fn customize_retry_policy(config: options::ClientConfig) -> options::ClientConfig {
+    ///     config.set_retry_policy(
+    ///         Aip194Strict
+    ///             .with_retry_throttler(TokenBucket::default())
+    ///             .with_attempt_limit(5),
+    ///     )
+    /// }
+    /// ```
+    fn with_retry_throttler<V: Into<RetryThrottlerArg>>(self, v: V) -> Throttled<Self> {
+        Throttled::custom(self, v)
+    }
 }
 
 impl<T: RetryPolicy> RetryPolicyExt for T {}
@@ -245,7 +288,9 @@ impl RetryPolicy for Aip194Strict {
         }
         use crate::error::ErrorKind;
         match error.kind() {
-            ErrorKind::Rpc | ErrorKind::Io => {
+            // Timeouts from the retry loop deadline never reach the policy,
+            // only the per-attempt timeouts do.
+            ErrorKind::Rpc | ErrorKind::Io | ErrorKind::Timeout => {
                 if idempotent {
                     RetryFlow::Continue(error)
                 } else {
@@ -258,6 +303,7 @@ impl RetryPolicy for Aip194Strict {
                 RetryFlow::Continue(error)
             }
             ErrorKind::Serde => RetryFlow::Permanent(error),
+            ErrorKind::DeadlineExceeded => RetryFlow::Permanent(error),
             ErrorKind::Other => RetryFlow::Permanent(error),
         }
     }
@@ -265,10 +311,7 @@ impl RetryPolicy for Aip194Strict {
 
 // A helper function to simplify `Api194Strict::on_error()`:
 fn match_status_code_string(http: &HttpError, code: &str) -> bool {
-    Status::try_from(http)
-        .ok()
-        .map(|v| v.status.as_deref() == Some(code))
-        .unwrap_or(false)
+    Status::from_http_error(http).status.as_deref() == Some(code)
 }
 
 /// A retry policy that retries all errors.
@@ -328,6 +371,7 @@ where
 {
     inner: P,
     maximum_duration: std::time::Duration,
+    clock: Arc<dyn Clock>,
 }
 
 impl LimitedElapsedTime {
@@ -354,6 +398,7 @@ impl LimitedElapsedTime {
         Self {
             inner: Aip194Strict,
             maximum_duration,
+            clock: Arc::new(TokioClock),
         }
     }
 }
@@ -386,8 +431,27 @@ where
         Self {
             inner,
             maximum_duration,
+            clock: Arc::new(TokioClock),
         }
     }
+
+    /// Changes the clock used to measure the elapsed time.
+    ///
+    /// The default is [TokioClock]. Applications rarely need to change this,
+    /// it is intended for tests that control the passage of time directly.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_gax::retry_policy::*;
+    /// # use gcp_sdk_gax::clock::TokioClock;
+    /// let d = std::time::Duration::from_secs(10);
+    /// let policy = AlwaysRetry.with_time_limit(d).with_clock(TokioClock);
+    /// assert!(policy.remaining_time(std::time::Instant::now(), 0) <= Some(d));
+    /// ```
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
 }
 
 impl<P> RetryPolicy for LimitedElapsedTime<P>
@@ -405,7 +469,7 @@ where
             RetryFlow::Permanent(e) => RetryFlow::Permanent(e),
             RetryFlow::Exhausted(e) => RetryFlow::Exhausted(e),
             RetryFlow::Continue(e) => {
-                if std::time::Instant::now() >= start + self.maximum_duration {
+                if self.clock.now() >= start + self.maximum_duration {
                     RetryFlow::Exhausted(e)
                 } else {
                     RetryFlow::Continue(e)
@@ -420,12 +484,16 @@ where
         attempt_count: u32,
     ) -> Option<std::time::Duration> {
         let deadline = loop_start + self.maximum_duration;
-        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let remaining = deadline.saturating_duration_since(self.clock.now());
         if let Some(inner) = self.inner.remaining_time(loop_start, attempt_count) {
             return Some(std::cmp::min(remaining, inner));
         }
         Some(remaining)
     }
+
+    fn retry_throttler(&self) -> Option<Arc<dyn RetryThrottler>> {
+        self.inner.retry_throttler()
+    }
 }
 
 /// A retry policy decorator that limits the number of attempts.
@@ -535,6 +603,76 @@ where
     ) -> Option<std::time::Duration> {
         self.inner.remaining_time(loop_start, attempt_count)
     }
+
+    fn retry_throttler(&self) -> Option<Arc<dyn RetryThrottler>> {
+        self.inner.retry_throttler()
+    }
+}
+
+/// A retry policy decorator that configures a retry throttler.
+///
+/// This policy decorates an inner policy and returns the throttler from
+/// `retry_throttler()`. The retry loop uses this throttler instead of the
+/// throttler in the client configuration. The `on_error()` and
+/// `remaining_time()` functions return the results of the inner policy.
+///
+/// # Parameters
+/// * `P` - the inner retry policy.
+#[derive(Debug)]
+pub struct Throttled<P = Aip194Strict>
+where
+    P: RetryPolicy,
+{
+    inner: P,
+    throttler: Arc<dyn RetryThrottler>,
+}
+
+impl<P> Throttled<P>
+where
+    P: RetryPolicy,
+{
+    /// Creates a new instance with a custom inner policy.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_gax::retry_policy::*;
+    /// # use gcp_sdk_gax::retry_throttler::TokenBucket;
+    /// let policy = Throttled::custom(Aip194Strict, TokenBucket::default());
+    /// assert!(policy.retry_throttler().is_some());
+    /// ```
+    pub fn custom<V: Into<RetryThrottlerArg>>(inner: P, throttler: V) -> Self {
+        Self {
+            inner,
+            throttler: throttler.into().0,
+        }
+    }
+}
+
+impl<P> RetryPolicy for Throttled<P>
+where
+    P: RetryPolicy,
+{
+    fn on_error(
+        &self,
+        start: std::time::Instant,
+        count: u32,
+        idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        self.inner.on_error(start, count, idempotent, error)
+    }
+
+    fn remaining_time(
+        &self,
+        loop_start: std::time::Instant,
+        attempt_count: u32,
+    ) -> Option<std::time::Duration> {
+        self.inner.remaining_time(loop_start, attempt_count)
+    }
+
+    fn retry_throttler(&self) -> Option<Arc<dyn RetryThrottler>> {
+        Some(self.throttler.clone())
+    }
 }
 
 #[cfg(test)]
@@ -577,6 +715,8 @@ mod tests {
         let now = std::time::Instant::now();
         assert!(p.on_error(now, 0, true, unavailable()).is_continue());
         assert!(p.on_error(now, 0, false, unavailable()).is_permanent());
+        assert!(p.on_error(now, 0, true, unavailable_html()).is_continue());
+        assert!(p.on_error(now, 0, false, unavailable_html()).is_permanent());
 
         assert!(p.on_error(now, 0, true, permission_denied()).is_permanent());
         assert!(p
@@ -603,6 +743,12 @@ mod tests {
         assert!(p
             .on_error(now, 0, false, Error::serde("err".to_string()))
             .is_permanent());
+        assert!(p
+            .on_error(now, 0, true, Error::timeout("err".to_string()))
+            .is_continue());
+        assert!(p
+            .on_error(now, 0, false, Error::timeout("err".to_string()))
+            .is_permanent());
         assert!(p
             .on_error(now, 0, true, Error::other("err".to_string()))
             .is_permanent());
@@ -673,6 +819,13 @@ mod tests {
         from_status(status)
     }
 
+    fn unavailable_html() -> Error {
+        use std::collections::HashMap;
+        let payload = bytes::Bytes::from_static(b"<html><body>Unavailable</body></html>");
+        let http = crate::error::HttpError::new(503, HashMap::new(), Some(payload));
+        Error::rpc(http)
+    }
+
     fn permission_denied() -> Error {
         let mut status = Status::default();
         status.code = 403;
@@ -833,6 +986,40 @@ mod tests {
         assert!(remaining <= Some(Duration::from_secs(10)), "{remaining:?}");
     }
 
+    #[test]
+    fn test_limited_time_with_clock() {
+        #[derive(Debug)]
+        struct FixedClock(std::time::Instant);
+        impl Clock for FixedClock {
+            fn now(&self) -> std::time::Instant {
+                self.0
+            }
+            fn sleep(&self, _duration: Duration) -> crate::clock::SleepFuture {
+                Box::pin(async {})
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let policy = AlwaysRetry
+            .with_time_limit(Duration::from_secs(60))
+            .with_clock(FixedClock(start + Duration::from_secs(50)));
+        assert_eq!(
+            policy.remaining_time(start, 0),
+            Some(Duration::from_secs(10))
+        );
+        assert!(policy
+            .on_error(start, 1, true, Error::other("err".to_string()))
+            .is_continue());
+
+        let policy = AlwaysRetry
+            .with_time_limit(Duration::from_secs(60))
+            .with_clock(FixedClock(start + Duration::from_secs(60)));
+        assert_eq!(policy.remaining_time(start, 0), Some(Duration::ZERO));
+        assert!(policy
+            .on_error(start, 1, true, Error::other("err".to_string()))
+            .is_exhausted());
+    }
+
     #[test]
     fn test_limited_attempt_count_on_error() {
         let mut mock = MockPolicy::new();
@@ -909,4 +1096,40 @@ mod tests {
         let rf = policy.on_error(now, 1, false, Error::other("err".to_string()));
         assert!(rf.is_exhausted());
     }
+
+    #[test]
+    fn test_throttled_forwards() {
+        use crate::retry_throttler::TokenBucket;
+        let mut mock = MockPolicy::new();
+        mock.expect_on_error()
+            .times(1)
+            .returning(|_, _, _, e| RetryFlow::Permanent(e));
+        mock.expect_remaining_time()
+            .times(1)
+            .returning(|_, _| Some(Duration::from_secs(10)));
+        let policy = Throttled::custom(mock, TokenBucket::default());
+        let now = std::time::Instant::now();
+
+        let rf = policy.on_error(now, 1, true, Error::other("err".to_string()));
+        assert!(rf.is_permanent());
+        assert_eq!(policy.remaining_time(now, 1), Some(Duration::from_secs(10)));
+        assert!(policy.retry_throttler().is_some());
+    }
+
+    #[test]
+    fn test_retry_throttler_forwarded_by_decorators() {
+        use crate::retry_throttler::TokenBucket;
+        let throttler: Arc<dyn RetryThrottler> = Arc::new(TokenBucket::default());
+        let policy = Aip194Strict
+            .with_retry_throttler(throttler.clone())
+            .with_time_limit(Duration::from_secs(60))
+            .with_attempt_limit(5);
+        let got = policy.retry_throttler().unwrap();
+        assert!(Arc::ptr_eq(&got, &throttler));
+
+        let policy = Aip194Strict
+            .with_time_limit(Duration::from_secs(60))
+            .with_attempt_limit(5);
+        assert!(policy.retry_throttler().is_none());
+    }
 }
@@ -0,0 +1,280 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the trait for retry throttlers and a common implementation.
+//!
+//! Retry policies and backoff policies apply to a single request. When a
+//! service is broadly failing, each request in the application retries
+//! independently, and the retry attempts increase the load on the service
+//! just when it is least able to handle it.
+//!
+//! A retry throttler is shared by all the requests made by a client. It
+//! observes the results of the attempts, and stops the retry loops when too
+//! many attempts are failing. The initial attempt of each request is never
+//! throttled.
+//!
+//! # Example:
+//! ```
+//! # use gcp_sdk_gax::retry_throttler::*;
+//! # use gcp_sdk_gax::options;
+//! fn customize_retry_throttler(config: options::ClientConfig) -> options::ClientConfig {
+//!     let throttler = TokenBucketBuilder::new()
+//!         .with_max_tokens(20.0)
+//!         .with_token_ratio(0.5)
+//!         .build();
+//!     config.set_retry_throttler(throttler.unwrap())
+//! }
+//! ```
+//!
+//! The throttler can also be configured with the retry policy, see
+//! [with_retry_throttler()][crate::retry_policy::RetryPolicyExt::with_retry_throttler].
+
+use crate::error::Error;
+use crate::retry_policy::RetryFlow;
+use std::sync::{Arc, Mutex};
+
+/// Determines if the retry loop can make more attempts.
+///
+/// Implementations of this trait are shared by all the requests in a client,
+/// they must be safe to use from multiple threads.
+pub trait RetryThrottler: Send + Sync + std::fmt::Debug {
+    /// Returns true if the retry loop should stop before the next attempt.
+    ///
+    /// The retry loop only queries the throttler if the retry policy
+    /// determined the error is retryable. If the retry loop stops, the request
+    /// fails with the error from the last attempt.
+    fn throttle_retry_attempt(&self) -> bool;
+
+    /// Called when an attempt fails.
+    ///
+    /// The `flow` parameter is the decision made by the retry policy for this
+    /// error.
+    fn on_retry_failure(&self, flow: &RetryFlow);
+
+    /// Called when an attempt succeeds.
+    fn on_success(&self);
+}
+
+/// A helper type to use [RetryThrottler] in client options and retry policies.
+///
+/// Converting from an `Arc<dyn RetryThrottler>` shares the throttler, for
+/// example, between several clients.
+#[derive(Clone, Debug)]
+pub struct RetryThrottlerArg(pub(crate) Arc<dyn RetryThrottler>);
+
+impl<T> std::convert::From<T> for RetryThrottlerArg
+where
+    T: RetryThrottler + 'static,
+{
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl std::convert::From<Arc<dyn RetryThrottler>> for RetryThrottlerArg {
+    fn from(value: Arc<dyn RetryThrottler>) -> Self {
+        Self(value)
+    }
+}
+
+/// Configure and build a [TokenBucket] retry throttler.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_gax::retry_throttler::*;
+/// let throttler = TokenBucketBuilder::new()
+///     .with_max_tokens(20.0)
+///     .with_token_ratio(0.5)
+///     .build();
+/// assert!(throttler.is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TokenBucketBuilder {
+    max_tokens: f64,
+    token_ratio: f64,
+}
+
+impl TokenBucketBuilder {
+    /// Creates a builder with the default parameters.
+    pub fn new() -> Self {
+        Self {
+            max_tokens: 100.0,
+            token_ratio: 0.1,
+        }
+    }
+
+    /// Change the number of tokens in the bucket.
+    pub fn with_max_tokens<V: Into<f64>>(mut self, v: V) -> Self {
+        self.max_tokens = v.into();
+        self
+    }
+
+    /// Change the number of tokens added to the bucket on each success.
+    pub fn with_token_ratio<V: Into<f64>>(mut self, v: V) -> Self {
+        self.token_ratio = v.into();
+        self
+    }
+
+    /// Creates a new throttler, validating the parameters.
+    pub fn build(self) -> Result<TokenBucket, Error> {
+        if self.max_tokens.is_nan() || self.max_tokens < 1.0 {
+            return Err(Error::other(format!(
+                "max_tokens ({}) must be at least 1.0",
+                self.max_tokens
+            )));
+        }
+        if self.token_ratio.is_nan()
+            || self.token_ratio <= 0.0
+            || self.token_ratio > self.max_tokens
+        {
+            return Err(Error::other(format!(
+                "token_ratio ({}) must be greater than 0.0 and at most max_tokens ({})",
+                self.token_ratio, self.max_tokens
+            )));
+        }
+        Ok(TokenBucket {
+            max_tokens: self.max_tokens,
+            token_ratio: self.token_ratio,
+            tokens: Mutex::new(self.max_tokens),
+        })
+    }
+}
+
+impl Default for TokenBucketBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Throttles retries using a token bucket.
+///
+/// This implements the same algorithm as [gRPC retry throttling]. The bucket
+/// starts full, with `max_tokens`. Each failed attempt with a retryable error
+/// removes one token, and each successful attempt adds `token_ratio` tokens,
+/// up to `max_tokens`. Retry attempts are throttled while the bucket is at
+/// most half full.
+///
+/// Permanent errors, such as `NOT_FOUND`, do not remove tokens. These errors
+/// say nothing about the health of the service.
+///
+/// [gRPC retry throttling]: https://github.com/grpc/proposal/blob/master/A6-client-retries.md#throttling-retry-attempts-and-hedged-rpcs
+#[derive(Debug)]
+pub struct TokenBucket {
+    max_tokens: f64,
+    token_ratio: f64,
+    tokens: Mutex<f64>,
+}
+
+impl Default for TokenBucket {
+    fn default() -> Self {
+        TokenBucketBuilder::new()
+            .build()
+            .expect("statically configured policy should succeed")
+    }
+}
+
+impl RetryThrottler for TokenBucket {
+    fn throttle_retry_attempt(&self) -> bool {
+        *self.tokens.lock().unwrap() <= self.max_tokens / 2.0
+    }
+
+    fn on_retry_failure(&self, flow: &RetryFlow) {
+        if flow.is_permanent() {
+            return;
+        }
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens - 1.0).max(0.0);
+    }
+
+    fn on_success(&self) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens + self.token_ratio).min(self.max_tokens);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    fn transient() -> Error {
+        Error::io("transient".to_string())
+    }
+
+    #[test]
+    fn builder_defaults() -> Result {
+        let t = TokenBucketBuilder::new().build()?;
+        assert_eq!(t.max_tokens, 100.0);
+        assert_eq!(t.token_ratio, 0.1);
+        assert_eq!(*t.tokens.lock().unwrap(), 100.0);
+        let t = TokenBucketBuilder::default().build()?;
+        assert_eq!(t.max_tokens, 100.0);
+        let t = TokenBucket::default();
+        assert_eq!(t.token_ratio, 0.1);
+        Ok(())
+    }
+
+    #[test]
+    fn builder_validation() {
+        let t = TokenBucketBuilder::new().with_max_tokens(0.5).build();
+        assert!(t.is_err(), "{t:?}");
+        let t = TokenBucketBuilder::new().with_max_tokens(f64::NAN).build();
+        assert!(t.is_err(), "{t:?}");
+        let t = TokenBucketBuilder::new().with_token_ratio(0.0).build();
+        assert!(t.is_err(), "{t:?}");
+        let t = TokenBucketBuilder::new()
+            .with_max_tokens(10.0)
+            .with_token_ratio(11.0)
+            .build();
+        assert!(t.is_err(), "{t:?}");
+    }
+
+    #[test]
+    fn throttle() -> Result {
+        let t = TokenBucketBuilder::new()
+            .with_max_tokens(10.0)
+            .with_token_ratio(0.5)
+            .build()?;
+        assert!(!t.throttle_retry_attempt());
+        for _ in 0..4 {
+            t.on_retry_failure(&RetryFlow::Continue(transient()));
+        }
+        assert!(!t.throttle_retry_attempt());
+        t.on_retry_failure(&RetryFlow::Exhausted(transient()));
+        assert!(t.throttle_retry_attempt());
+
+        // Permanent errors do not change the bucket.
+        t.on_success();
+        t.on_retry_failure(&RetryFlow::Permanent(transient()));
+        assert!(!t.throttle_retry_attempt());
+        Ok(())
+    }
+
+    #[test]
+    fn bounds() -> Result {
+        let t = TokenBucketBuilder::new()
+            .with_max_tokens(4.0)
+            .with_token_ratio(1.0)
+            .build()?;
+        for _ in 0..10 {
+            t.on_retry_failure(&RetryFlow::Continue(transient()));
+        }
+        assert_eq!(*t.tokens.lock().unwrap(), 0.0);
+        for _ in 0..10 {
+            t.on_success();
+        }
+        assert_eq!(*t.tokens.lock().unwrap(), 4.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines helpers to compute the routing header.
+//!
+//! Many Google Cloud services route requests using the `x-goog-request-params`
+//! header. The header contains `key=value` pairs, separated by `&`, where the
+//! values are URL-encoded. The generated clients compute these pairs from the
+//! request fields, as described in [AIP-4222]:
+//! - If the RPC has no `google.api.routing` annotation, each field used in the
+//!   HTTP path becomes a routing parameter.
+//! - Otherwise, each `google.api.routing_parameter` extracts a value from a
+//!   request field. When several parameters use the same key the last one that
+//!   matches wins.
+//!
+//! The types are not intended for application developers to use. They are
+//! public because we will generate many crates (roughly one per service), and
+//! most of these crates will use these helpers.
+//!
+//! [AIP-4222]: https://google.aip.dev/client-libraries/4222
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC};
+
+/// The name of the routing header.
+pub const ROUTING_HEADER: &str = "x-goog-request-params";
+
+// Encode everything except the unreserved characters from RFC 3986.
+const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Accumulates the routing parameters for a request.
+#[derive(Clone, Debug, Default)]
+pub struct RoutingParameters {
+    params: Vec<(String, String)>,
+}
+
+impl RoutingParameters {
+    /// Creates an empty set of routing parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the full value of a field as a routing parameter.
+    ///
+    /// Empty values are ignored.
+    pub fn add(mut self, key: &str, value: &str) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Adds a routing parameter extracted from `value` using `template`.
+    ///
+    /// The template is the `path_template` of a `google.api.routing_parameter`
+    /// annotation, such as `{routing_id=projects/*}/**`. The key is the name
+    /// of the template variable. The parameter is ignored if the value does not
+    /// match the template, or if the extracted value is empty.
+    pub fn add_matching(mut self, value: &str, template: &str) -> Self {
+        if let Some((key, v)) = extract(value, template) {
+            self.set(key, &v);
+        }
+        self
+    }
+
+    /// Returns the value of the routing header, if there are any parameters.
+    pub fn header_value(&self) -> Option<String> {
+        if self.params.is_empty() {
+            return None;
+        }
+        let pairs: Vec<String> = self
+            .params
+            .iter()
+            .map(|(k, v)| {
+                format!(
+                    "{k}={}",
+                    percent_encoding::utf8_percent_encode(v, ENCODE_SET)
+                )
+            })
+            .collect();
+        Some(pairs.join("&"))
+    }
+
+    /// Adds the routing header to a request builder, if there are any
+    /// parameters.
+    pub fn apply(self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.header_value() {
+            Some(v) => builder.header(ROUTING_HEADER, v),
+            None => builder,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+        match self.params.iter_mut().find(|(k, _)| k == key) {
+            Some(p) => p.1 = value.to_string(),
+            None => self.params.push((key.to_string(), value.to_string())),
+        }
+    }
+}
+
+/// Extracts the value captured by the variable in `template`.
+///
+/// Returns the variable name and the captured value, or `None` if `value`
+/// does not match the template.
+fn extract<'t>(value: &str, template: &'t str) -> Option<(&'t str, String)> {
+    let start = template.find('{')?;
+    let end = start + template[start..].find('}')?;
+    let prefix = match &template[..start] {
+        "" => Vec::new(),
+        p => segments(p.strip_suffix('/')?),
+    };
+    let suffix = match &template[end + 1..] {
+        "" => Vec::new(),
+        s => segments(s.strip_prefix('/')?),
+    };
+    let (key, pattern) = template[start + 1..end]
+        .split_once('=')
+        .unwrap_or((&template[start + 1..end], "*"));
+    let pattern = segments(pattern);
+
+    let value = segments(value);
+    for i in 0..=value.len() {
+        if !matches(&prefix, &value[..i]) {
+            continue;
+        }
+        for j in i..=value.len() {
+            if matches(&pattern, &value[i..j]) && matches(&suffix, &value[j..]) {
+                return Some((key, value[i..j].join("/")));
+            }
+        }
+    }
+    None
+}
+
+pub(crate) fn segments(path: &str) -> Vec<&str> {
+    path.split('/').collect()
+}
+
+pub(crate) fn matches(pattern: &[&str], value: &[&str]) -> bool {
+    match (pattern.first(), value.first()) {
+        (None, None) => true,
+        (Some(&"**"), _) => (0..=value.len()).any(|n| matches(&pattern[1..], &value[n..])),
+        (Some(&"*"), Some(v)) => !v.is_empty() && matches(&pattern[1..], &value[1..]),
+        (Some(p), Some(v)) => p == v && matches(&pattern[1..], &value[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("projects/p", "{name}", None; "single segment variable")]
+    #[test_case("p", "{name}", Some("p"); "single segment")]
+    #[test_case("projects/p/secrets/s", "{name=**}", Some("projects/p/secrets/s"); "match all")]
+    #[test_case("projects/p/instances/i/tables/t", "{name=projects/*}/**", Some("projects/p"); "prefix")]
+    #[test_case("projects/p", "{name=projects/*}/**", Some("projects/p"); "empty suffix")]
+    #[test_case("projects/p/instances/i/tables/t", "projects/*/{name=instances/*}/**", Some("instances/i"); "middle")]
+    #[test_case("projects/p/instances/i/tables/t", "{name=projects/*/instances/*}/tables/*", Some("projects/p/instances/i"); "full")]
+    #[test_case("projects//instances/i", "{name=projects/*}/**", None; "empty segment")]
+    #[test_case("organizations/o", "{name=projects/*}/**", None; "mismatch")]
+    #[test_case("projects/p", "{name=projects/*}", Some("projects/p"); "exact")]
+    #[test_case("projects/p/other", "{name=projects/*}", None; "trailing data")]
+    fn extract_value(value: &str, template: &str, want: Option<&str>) {
+        let got = extract(value, template);
+        assert_eq!(
+            got.as_ref().map(|(_, v)| v.as_str()),
+            want,
+            "{value} {template}"
+        );
+        if let Some((key, _)) = got {
+            assert_eq!(key, "name");
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let params = RoutingParameters::new().add("name", "");
+        assert_eq!(params.header_value(), None);
+        let params = RoutingParameters::new().add_matching("", "{name=**}");
+        assert_eq!(params.header_value(), None);
+    }
+
+    #[test]
+    fn implicit() {
+        let params = RoutingParameters::new()
+            .add("parent", "projects/p")
+            .add("secret.name", "my secret");
+        assert_eq!(
+            params.header_value().as_deref(),
+            Some("parent=projects%2Fp&secret.name=my%20secret")
+        );
+    }
+
+    #[test]
+    fn explicit_last_match_wins() {
+        let params = RoutingParameters::new()
+            .add_matching("projects/p/instances/i", "{routing_id=projects/*}/**")
+            .add_matching(
+                "projects/p/instances/i",
+                "{routing_id=projects/*/instances/*}",
+            )
+            .add_matching("projects/p/instances/i", "{routing_id=organizations/*}/**")
+            .add_matching("profile", "{app_profile_id=**}");
+        assert_eq!(
+            params.header_value().as_deref(),
+            Some("routing_id=projects%2Fp%2Finstances%2Fi&app_profile_id=profile")
+        );
+    }
+
+    #[test]
+    fn apply() -> Result<(), Box<dyn std::error::Error>> {
+        let client = reqwest::Client::new();
+        let builder = client.get("https://example.com");
+        let request = RoutingParameters::new().apply(builder).build()?;
+        assert!(request.headers().get(ROUTING_HEADER).is_none());
+
+        let builder = client.get("https://example.com");
+        let request = RoutingParameters::new()
+            .add("name", "projects/p")
+            .apply(builder)
+            .build()?;
+        let got = request.headers().get(ROUTING_HEADER).map(|v| v.to_str());
+        assert_eq!(got.transpose()?, Some("name=projects%2Fp"));
+        Ok(())
+    }
+}
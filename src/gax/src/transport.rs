@@ -0,0 +1,125 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the HTTP transport used by the clients.
+//!
+//! By default the clients send requests using their own [reqwest] client.
+//! Applications can replace the transport with
+//! [ClientConfig::set_transport][crate::options::ClientConfig::set_transport],
+//! for example, to use a `reqwest::Client` configured with a proxy, or to use
+//! a fake transport in tests.
+//!
+//! The transport receives the request after all the
+//! [interceptors][crate::interceptor] run. It is called once per attempt, the
+//! client still applies the retry, backoff, and timeout policies.
+//!
+//! # Example
+//! ```
+//! # use gcp_sdk_gax::interceptor::{InterceptedRequest, InterceptedResponse};
+//! # use gcp_sdk_gax::options::ClientConfig;
+//! # use gcp_sdk_gax::transport::*;
+//! #[derive(Debug)]
+//! struct Fake;
+//! impl HttpTransport for Fake {
+//!     fn send(&self, _request: InterceptedRequest) -> TransportFuture<'_> {
+//!         Box::pin(async {
+//!             Ok(InterceptedResponse::new(http::StatusCode::OK, "{}"))
+//!         })
+//!     }
+//! }
+//! let config = ClientConfig::new().set_transport(Fake);
+//! ```
+//!
+//! [reqwest]: https://docs.rs/reqwest
+
+use crate::interceptor::{InterceptedRequest, InterceptedResponse};
+use crate::Result;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The future returned by [HttpTransport::send].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<InterceptedResponse>> + Send + 'a>>;
+
+/// Sends HTTP requests on behalf of a client.
+///
+/// Implementations should return all responses, including error responses,
+/// as an [InterceptedResponse]. The client converts them to errors as needed.
+/// Return an error only when no response was received, e.g., if the
+/// connection failed. Use [Error::io][crate::error::Error::io] for these
+/// errors, the default retry policies treat them as transient.
+pub trait HttpTransport: Send + Sync + std::fmt::Debug {
+    /// Sends a request and returns the full response.
+    fn send(&self, request: InterceptedRequest) -> TransportFuture<'_>;
+}
+
+/// Sends requests using an application-provided [reqwest::Client].
+///
+/// The response body is read in full before it is returned to the client.
+#[cfg(feature = "unstable-sdk-client")]
+impl HttpTransport for reqwest::Client {
+    fn send(&self, request: InterceptedRequest) -> TransportFuture<'_> {
+        use crate::http_client::send_error;
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            let response = builder.send().await.map_err(send_error)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await.map_err(send_error)?;
+            Ok(InterceptedResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Fake;
+    impl HttpTransport for Fake {
+        fn send(&self, request: InterceptedRequest) -> TransportFuture<'_> {
+            Box::pin(async move {
+                Ok(InterceptedResponse::new(
+                    http::StatusCode::OK,
+                    request.body.unwrap_or_default(),
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn dyn_transport() -> Result<()> {
+        let transport: std::sync::Arc<dyn HttpTransport> = std::sync::Arc::new(Fake);
+        let request = InterceptedRequest {
+            method: http::Method::POST,
+            url: "https://example.com/v1/test".to_string(),
+            headers: http::HeaderMap::new(),
+            body: Some(bytes::Bytes::from_static(b"{}")),
+        };
+        let response = transport.send(request).await?;
+        assert_eq!(response.status, http::StatusCode::OK);
+        assert_eq!(response.body, "{}");
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the retry loop backoff can be driven by a paused tokio clock.
+
+use gax::backoff_policy::*;
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gax::retry_policy::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+use std::time::Duration;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_with_paused_clock() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let backoff = ExponentialBackoffBuilder::new()
+        .with_initial_delay(Duration::from_secs(10))
+        .with_maximum_delay(Duration::from_secs(60))
+        .build()?;
+    let mut options = RequestOptions::default();
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(3));
+    options.set_backoff_policy(backoff);
+
+    let start = tokio::time::Instant::now();
+    let wall = std::time::Instant::now();
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    assert!(response.is_err(), "{response:?}");
+
+    // Two backoff periods: the first between 5s and 10s, the second between
+    // 10s and 20s.
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_secs(15), "{elapsed:?}");
+    assert!(wall.elapsed() < Duration::from_secs(15), "{wall:?}");
+
+    Ok(())
+}
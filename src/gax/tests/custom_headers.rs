@@ -0,0 +1,57 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn custom_headers_and_query_params() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("from-library", "v1")]);
+    let mut options = RequestOptions::default();
+    options.add_header("x-goog-preview", "enabled");
+    options.add_query_param("preview", "true");
+    let response: serde_json::Value = client.execute(builder, Some(json!({})), options).await?;
+    assert_eq!(response["headers"]["x-goog-preview"], "enabled");
+    assert_eq!(
+        response["query"],
+        json!({"from-library": "v1", "preview": "true"})
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn invalid_header() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let mut options = RequestOptions::default();
+    options.add_header("bad header name", "value");
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    assert!(response.is_err(), "{response:?}");
+    Ok(())
+}
@@ -0,0 +1,146 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::error::HttpError;
+use gax::http_client::ReqwestClient;
+use gax::interceptor::*;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Debug)]
+struct AddHeader(&'static str);
+
+impl Interceptor for AddHeader {
+    fn on_request(
+        &self,
+        request: &mut InterceptedRequest,
+    ) -> gax::Result<Option<InterceptedResponse>> {
+        request
+            .headers
+            .insert("x-test-header", http::HeaderValue::from_static(self.0));
+        request.headers.remove("authorization");
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Observer(Arc<Mutex<Vec<(String, u16)>>>);
+
+impl Interceptor for Observer {
+    fn on_response(
+        &self,
+        request: &InterceptedRequest,
+        status: http::StatusCode,
+        _headers: &http::HeaderMap,
+    ) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((request.url.clone(), status.as_u16()));
+    }
+}
+
+#[derive(Debug)]
+struct Canned(InterceptedResponse);
+
+impl Interceptor for Canned {
+    fn on_request(
+        &self,
+        _request: &mut InterceptedRequest,
+    ) -> gax::Result<Option<InterceptedResponse>> {
+        Ok(Some(self.0.clone()))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn modify_and_observe() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let observer = Observer::default();
+    let observed = observer.0.clone();
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .add_interceptor(AddHeader("from-interceptor"))
+        .add_interceptor(observer);
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("a", "b")]);
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    let headers = &response["headers"];
+    assert_eq!(headers["x-test-header"], "from-interceptor");
+    assert_eq!(headers.get("authorization"), None, "{response}");
+    assert_eq!(response["query"], json!({"a": "b"}));
+
+    let observed = observed.lock().unwrap();
+    assert_eq!(observed.len(), 1);
+    assert_eq!(observed[0], (format!("{endpoint}/echo?a=b"), 200));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn short_circuit() -> Result<()> {
+    let observer = Observer::default();
+    let observed = observer.0.clone();
+    let response = InterceptedResponse::new(http::StatusCode::OK, r#"{"name": "canned"}"#);
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .add_interceptor(Canned(response))
+        .add_interceptor(AddHeader("never-called"))
+        .add_interceptor(observer);
+    // Nothing is listening on this endpoint.
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    assert_eq!(response, json!({"name": "canned"}));
+    assert_eq!(observed.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn inject_error() -> Result<()> {
+    let status = json!({"error": {
+        "code": 503,
+        "status": "UNAVAILABLE",
+        "message": "injected",
+    }});
+    let response =
+        InterceptedResponse::new(http::StatusCode::SERVICE_UNAVAILABLE, status.to_string());
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .add_interceptor(Canned(response));
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let err = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await
+        .unwrap_err();
+    let inner = err.as_inner::<HttpError>().unwrap();
+    assert_eq!(inner.status_code(), 503);
+    Ok(())
+}
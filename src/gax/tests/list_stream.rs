@@ -0,0 +1,126 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::extract::Query;
+use gax::http_client::ReqwestClient;
+use gax::list_stream::ListItems;
+use gax::options::*;
+use gax::paginator::PageableResponse;
+use gcp_sdk_gax as gax;
+use std::collections::HashMap;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ListResponse {
+    items: Vec<Item>,
+    next_page_token: String,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+struct Item {
+    name: String,
+}
+
+impl PageableResponse for ListResponse {
+    type PageItem = Item;
+
+    fn items(self) -> Vec<Item> {
+        self.items
+    }
+
+    fn next_page_token(&self) -> String {
+        self.next_page_token.clone()
+    }
+}
+
+async fn start() -> Result<(String, tokio::task::JoinHandle<()>)> {
+    let handler = |Query(query): Query<HashMap<String, String>>| async move {
+        let page = query
+            .get("pageToken")
+            .and_then(|t| t.parse::<usize>().ok())
+            .unwrap_or(0);
+        let items = (0..1000)
+            .map(|i| serde_json::json!({"name": format!("item-{page}-{i}")}))
+            .collect::<Vec<_>>();
+        let mut body = serde_json::json!({"items": items});
+        if page < 2 {
+            body["nextPageToken"] = serde_json::json!(format!("{}", page + 1));
+        }
+        axum::Json(body)
+    };
+    let app = axum::Router::new().route("/list", axum::routing::get(handler));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let server = tokio::spawn(async {
+        axum::serve(listener, app).await.unwrap();
+    });
+    Ok((format!("http://{addr}"), server))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_items() -> Result<()> {
+    let (endpoint, _server) = start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let execute = move |token: String| {
+        let client = client.clone();
+        async move {
+            let builder = client
+                .builder(reqwest::Method::GET, "/list".into())
+                .query(&[("pageToken", token)]);
+            client
+                .execute_list_stream::<serde_json::Value, ListResponse>(
+                    builder,
+                    None,
+                    RequestOptions::default(),
+                    "items",
+                )
+                .await
+        }
+    };
+    let mut items = ListItems::new(String::new(), execute);
+    let mut names = Vec::new();
+    while let Some(item) = items.next().await {
+        names.push(item?.name);
+    }
+    assert_eq!(names.len(), 3000);
+    assert_eq!(names[0], "item-0-0");
+    assert_eq!(names[1999], "item-1-999");
+    assert_eq!(names[2999], "item-2-999");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_stream_error() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let response = client
+        .execute_list_stream::<serde_json::Value, ListResponse>(
+            builder,
+            None,
+            RequestOptions::default(),
+            "items",
+        )
+        .await;
+    let err = response.unwrap_err();
+    assert!(err.as_inner::<gax::error::HttpError>().is_some(), "{err:?}");
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the HTTP client records OpenTelemetry metrics.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+use opentelemetry_sdk::testing::metrics::InMemoryMetricExporter;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_metrics() -> Result<()> {
+    let exporter = InMemoryMetricExporter::default();
+    let reader =
+        PeriodicReader::builder(exporter.clone(), opentelemetry_sdk::runtime::Tokio).build();
+    let provider = SdkMeterProvider::builder().with_reader(reader).build();
+    opentelemetry::global::set_meter_provider(provider.clone());
+
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let _ = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await;
+    assert!(response.is_err(), "{response:?}");
+
+    provider.force_flush()?;
+    let mut names = exporter
+        .get_finished_metrics()?
+        .into_iter()
+        .flat_map(|rm| rm.scope_metrics)
+        .filter(|sm| sm.scope.name() == "gcp-sdk-gax")
+        .flat_map(|sm| sm.metrics)
+        .map(|m| m.name.to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(
+        names,
+        vec![
+            "gcp.client.operation.attempts",
+            "gcp.client.operation.duration",
+            "http.client.request.body.size",
+            "http.client.request.duration",
+            "http.client.response.body.size",
+        ]
+    );
+
+    Ok(())
+}
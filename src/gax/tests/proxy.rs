@@ -0,0 +1,151 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the HTTP client sends requests through the configured proxies.
+//!
+//! The echo server acts as the proxy. The target endpoint refuses all
+//! connections, so the requests only succeed if they go through the proxy.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const TARGET: &str = "http://127.0.0.1:1";
+
+fn test_config() -> ClientConfig {
+    ClientConfig::default().set_credential(auth::Credential::test_credentials())
+}
+
+async fn echo(config: ClientConfig) -> gax::Result<serde_json::Value> {
+    let client = ReqwestClient::new(config, TARGET).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn explicit_proxy() -> Result<()> {
+    let (proxy, _server) = echo_server::start().await?;
+
+    let response = echo(test_config().set_proxy(&proxy)).await?;
+    assert!(
+        response["headers"].get("proxy-authorization").is_none(),
+        "{response:?}"
+    );
+
+    let config = test_config()
+        .set_proxy(&proxy)
+        .set_proxy_credentials("user", "pass");
+    let response = echo(config).await?;
+    // base64("user:pass") == "dXNlcjpwYXNz"
+    assert_eq!(
+        response["headers"]["proxy-authorization"],
+        json!("Basic dXNlcjpwYXNz")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn explicit_no_proxy() -> Result<()> {
+    let (proxy, _server) = echo_server::start().await?;
+
+    let config = test_config().set_proxy(&proxy).set_no_proxy("127.0.0.1");
+    let response = echo(config).await;
+    assert!(response.is_err(), "{response:?}");
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn environment_proxy() -> Result<()> {
+    let (proxy, _server) = echo_server::start().await?;
+
+    // This test must run serially because `std::env::remove_var` and
+    // `std::env::set_var` are unsafe otherwise.
+    unsafe {
+        std::env::set_var("HTTP_PROXY", &proxy);
+        std::env::remove_var("NO_PROXY");
+        std::env::remove_var("no_proxy");
+    }
+    let response = echo(test_config()).await;
+    let disabled = echo(test_config().disable_proxy()).await;
+    unsafe {
+        std::env::remove_var("HTTP_PROXY");
+    }
+
+    let response = response?;
+    assert!(response["headers"].is_object(), "{response:?}");
+    assert!(disabled.is_err(), "{disabled:?}");
+    Ok(())
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn default_credentials_use_proxy() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+    // A proxy that records the requests and rejects them all.
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorder = requests.clone();
+    let app = axum::Router::new().fallback(move |request: axum::extract::Request| async move {
+        let line = format!("{} {}", request.method(), request.uri());
+        recorder.lock().unwrap().push(line);
+        axum::http::StatusCode::BAD_GATEWAY
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let proxy = format!("http://{}", listener.local_addr()?);
+    let _server = tokio::spawn(async {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("authorized-user.json");
+    let contents = json!({
+        "type": "authorized_user",
+        "client_id": "test-only-client-id",
+        "client_secret": "test-only-client-secret",
+        "refresh_token": "test-only-refresh-token",
+    });
+    std::fs::write(&path, contents.to_string())?;
+
+    // This test must run serially because `std::env::remove_var` and
+    // `std::env::set_var` are unsafe otherwise.
+    unsafe {
+        std::env::set_var("GOOGLE_APPLICATION_CREDENTIALS", &path);
+    }
+    let response = echo(ClientConfig::default().set_proxy(&proxy)).await;
+    unsafe {
+        std::env::remove_var("GOOGLE_APPLICATION_CREDENTIALS");
+    }
+
+    // The proxy rejects the token request, so the request fails.
+    assert!(response.is_err(), "{response:?}");
+    let requests = requests.lock().unwrap().clone();
+    assert!(
+        requests
+            .iter()
+            .any(|r| r.starts_with("CONNECT oauth2.googleapis.com:443")),
+        "{requests:?}"
+    );
+    Ok(())
+}
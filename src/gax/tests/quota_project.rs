@@ -0,0 +1,107 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const QUOTA_PROJECT_VAR: &str = "GOOGLE_CLOUD_QUOTA_PROJECT";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial_test::serial]
+async fn test_quota_project_default() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    unsafe {
+        std::env::remove_var(QUOTA_PROJECT_VAR);
+    }
+
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    let got = get_header_value(&response, "x-goog-user-project");
+    assert_eq!(got, None);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial_test::serial]
+async fn test_quota_project_from_config() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    unsafe {
+        std::env::set_var(QUOTA_PROJECT_VAR, "from-env");
+    }
+
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .set_quota_project_id("from-config");
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    let got = get_header_value(&response, "x-goog-user-project");
+    assert_eq!(got.as_deref(), Some("from-config"));
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let mut options = RequestOptions::default();
+    options.set_quota_project_id("from-request");
+    let response: serde_json::Value = client.execute(builder, Some(json!({})), options).await?;
+    let got = get_header_value(&response, "x-goog-user-project");
+    assert_eq!(got.as_deref(), Some("from-request"));
+
+    unsafe {
+        std::env::remove_var(QUOTA_PROJECT_VAR);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial_test::serial]
+async fn test_quota_project_from_env() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    unsafe {
+        std::env::set_var(QUOTA_PROJECT_VAR, "from-env");
+    }
+
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    let got = get_header_value(&response, "x-goog-user-project");
+    assert_eq!(got.as_deref(), Some("from-env"));
+
+    unsafe {
+        std::env::remove_var(QUOTA_PROJECT_VAR);
+    }
+    Ok(())
+}
+
+fn get_header_value(response: &serde_json::Value, name: &str) -> Option<String> {
+    response
+        .get("headers")
+        .and_then(|h| h.get(name))
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+}
@@ -0,0 +1,86 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::error::HttpError;
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gax::retry_policy::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_request_id_is_unique() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let builder = client.builder(reqwest::Method::GET, "/echo".into());
+        let response: serde_json::Value = client
+            .execute(builder, Some(json!({})), RequestOptions::default())
+            .await?;
+        let id = response
+            .get("headers")
+            .and_then(|h| h.get("x-goog-request-id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        let id = id.ok_or("missing x-goog-request-id header")?;
+        assert!(uuid::Uuid::parse_str(&id).is_ok(), "{id}");
+        ids.push(id);
+    }
+    assert_ne!(ids[0], ids[1]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_request_id_in_error() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let mut options = RequestOptions::default();
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(3));
+    options.set_backoff_policy(test_backoff()?);
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    let error = response.err().ok_or("expected an error")?;
+    let id = error.request_id().ok_or("missing request id in error")?;
+    assert!(uuid::Uuid::parse_str(id).is_ok(), "{id}");
+    assert!(format!("{error}").contains(id), "{error}");
+
+    // The last attempt sent the same id.
+    let http = error
+        .as_inner::<HttpError>()
+        .ok_or("expected an HttpError")?;
+    assert_eq!(
+        http.headers().get("x-goog-request-id").map(String::as_str),
+        Some(id)
+    );
+    Ok(())
+}
+
+fn test_backoff() -> Result<gax::backoff_policy::ExponentialBackoff> {
+    let backoff = gax::backoff_policy::ExponentialBackoffBuilder::new()
+        .with_initial_delay(std::time::Duration::from_millis(1))
+        .with_maximum_delay(std::time::Duration::from_millis(1))
+        .build()?;
+    Ok(backoff)
+}
@@ -0,0 +1,207 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the HTTP client logs requests and responses, with redaction.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+type Fields = HashMap<String, String>;
+
+/// Captures the fields of all the events.
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Arc<Mutex<Vec<Fields>>>,
+}
+
+impl Recorder {
+    fn events(&self, message: &str) -> Vec<Fields> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|f| f.get("message").map(String::as_str) == Some(message))
+            .cloned()
+            .collect()
+    }
+}
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S: tracing::Subscriber> Layer<S> for Recorder {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        event.record(&mut Visitor(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+}
+
+fn test_config() -> ClientConfig {
+    ClientConfig::default().set_credential(auth::Credential::test_credentials())
+}
+
+#[tokio::test]
+async fn request_logging() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let client = ReqwestClient::new(test_config().enable_request_logging(), &endpoint).await?;
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("a", "b")]);
+    let body = json!({"name": "test", "payload": {"data": "c2VjcmV0"}});
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(body),
+            RequestOptions::default(),
+        )
+        .await?;
+    // The response is not changed by the logging.
+    assert_eq!(response["query"], json!({"a": "b"}));
+
+    let requests = recorder.events("sending request");
+    assert_eq!(requests.len(), 1, "{requests:?}");
+    let request = &requests[0];
+    assert_eq!(request.get("method").map(String::as_str), Some("GET"));
+    assert_eq!(
+        request.get("url").cloned(),
+        Some(format!("{endpoint}/echo?a=b"))
+    );
+    let headers = request.get("headers").unwrap();
+    assert!(
+        headers.contains(r#"("authorization", "[censored]")"#),
+        "{headers}"
+    );
+    let body = request.get("body").unwrap();
+    let body = serde_json::from_str::<serde_json::Value>(body)?;
+    assert_eq!(body, json!({"name": "test", "payload": "[censored]"}));
+
+    let responses = recorder.events("received response");
+    assert_eq!(responses.len(), 1, "{responses:?}");
+    let response = &responses[0];
+    assert_eq!(response.get("status").map(String::as_str), Some("200"));
+    let body = response.get("body").unwrap();
+    assert!(body.contains(r#""query":{"a":"b"}"#), "{body}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_logging_disabled() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let client = ReqwestClient::new(test_config().disable_request_logging(), &endpoint).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let _ = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+    assert!(recorder.events("sending request").is_empty());
+    assert!(recorder.events("received response").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_logging_debug_disabled() -> Result<()> {
+    use tracing_subscriber::filter::LevelFilter;
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let subscriber = tracing_subscriber::registry()
+        .with(recorder.clone())
+        .with(LevelFilter::INFO);
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let client = ReqwestClient::new(test_config().enable_request_logging(), &endpoint).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let _ = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+    assert!(recorder.events("sending request").is_empty());
+    assert!(recorder.events("received response").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_logging_stream() -> Result<()> {
+    #[derive(Debug, Default, serde::Deserialize)]
+    struct Page {
+        #[serde(default)]
+        items: Vec<serde_json::Value>,
+    }
+    impl gax::paginator::PageableResponse for Page {
+        type PageItem = serde_json::Value;
+        fn items(self) -> Vec<serde_json::Value> {
+            self.items
+        }
+        fn next_page_token(&self) -> String {
+            String::new()
+        }
+    }
+
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let client = ReqwestClient::new(test_config().enable_request_logging(), &endpoint).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let mut stream = client
+        .execute_list_stream::<serde_json::Value, Page>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+            "items",
+        )
+        .await?;
+    // The response is logged before the body is read, without the body.
+    let responses = recorder.events("received response");
+    assert_eq!(responses.len(), 1, "{responses:?}");
+    let response = &responses[0];
+    assert_eq!(response.get("status").map(String::as_str), Some("200"));
+    assert_eq!(response.get("body").map(String::as_str), Some(""));
+    let item = stream.next().await.transpose()?;
+    assert!(item.is_none(), "{item:?}");
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::http_client::ReqwestClient;
+use gax::interceptor::{InterceptedRequest, InterceptedResponse};
+use gax::options::*;
+use gax::transport::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn execute_with_metadata() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("a", "b")]);
+    let response = client
+        .execute_with_metadata::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+    assert_eq!(response.status(), http::StatusCode::OK);
+    assert!(
+        response.headers().contains_key("content-type"),
+        "{response:?}"
+    );
+    assert_eq!(response.body()["query"], json!({"a": "b"}));
+    Ok(())
+}
+
+#[derive(Debug)]
+struct WithHeaders;
+
+impl HttpTransport for WithHeaders {
+    fn send(&self, _request: InterceptedRequest) -> TransportFuture<'_> {
+        Box::pin(async {
+            let mut response = InterceptedResponse::new(http::StatusCode::OK, r#"{"k": "v"}"#);
+            response
+                .headers
+                .insert("x-goog-generation", http::HeaderValue::from_static("123"));
+            Ok(response)
+        })
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn execute_with_metadata_custom_headers() -> Result<()> {
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .set_transport(WithHeaders);
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let response = client
+        .execute_with_metadata::<serde_json::Value, serde_json::Value>(
+            builder,
+            None,
+            RequestOptions::default(),
+        )
+        .await?;
+    assert_eq!(
+        response.headers().get("x-goog-generation"),
+        Some(&http::HeaderValue::from_static("123"))
+    );
+    let (parts, body) = response.into_parts();
+    assert_eq!(parts.status, http::StatusCode::OK);
+    assert_eq!(body, json!({"k": "v"}));
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use gax::clock::{Clock, TokioClock};
 use gax::http_client::ReqwestClient;
 use gax::options::*;
 use gcp_sdk_gax as gax;
@@ -127,7 +128,143 @@ async fn test_timeout_expires() -> Result<()> {
                     r
                 );
                 let err = r.err().unwrap();
-                assert_eq!(err.kind(), ErrorKind::Io);
+                assert_eq!(err.kind(), ErrorKind::Timeout, "{err:?}");
+                break;
+            },
+            _ = interval.tick() => { },
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_attempt_timeout_retried() -> Result<()> {
+    use gax::error::ErrorKind;
+    use gax::retry_policy::{AlwaysRetry, RetryPolicyExt};
+    let (endpoint, server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let delay = Duration::from_millis(200);
+    let timeout = Duration::from_millis(150);
+    let mut interval = tokio::time::interval(Duration::from_millis(10));
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("delay_ms", format!("{}", delay.as_millis()))]);
+    let mut options = test_options(&timeout);
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(3));
+    let response =
+        client.execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options);
+
+    tokio::pin!(server);
+    tokio::pin!(response);
+    let start = tokio::time::Instant::now();
+    loop {
+        tokio::select! {
+            _ = &mut server => {  },
+            r = &mut response => {
+                let err = r.err().unwrap();
+                assert_eq!(err.kind(), ErrorKind::Timeout, "{err:?}");
+                // Each of the three attempts times out.
+                let elapsed = start.elapsed();
+                assert!(elapsed >= timeout * 3, "{elapsed:?}");
+                break;
+            },
+            _ = interval.tick() => { },
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_overall_timeout_expires() -> Result<()> {
+    use gax::error::ErrorKind;
+    use gax::retry_policy::{AlwaysRetry, RetryPolicyExt};
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    // The request always fails, and the retry policy never stops. Only the
+    // overall timeout can terminate the retry loop.
+    let mut options = RequestOptions::default();
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(u32::MAX));
+    options.set_timeout(Duration::from_secs(30));
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let start = tokio::time::Instant::now();
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    let err = response.err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Timeout, "{err:?}");
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_secs(30), "{elapsed:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backoff_exceeds_timeout() -> Result<()> {
+    use gax::backoff_policy::ExponentialBackoffBuilder;
+    use gax::error::{ErrorKind, HttpError};
+    use gax::retry_policy::{AlwaysRetry, RetryPolicyExt};
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    // The first backoff delay exceeds the overall timeout. The retry loop
+    // stops without sleeping, and reports the deadline with the last error.
+    let mut options = RequestOptions::default();
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(u32::MAX));
+    options.set_backoff_policy(
+        ExponentialBackoffBuilder::new()
+            .with_initial_delay(Duration::from_secs(60))
+            .with_maximum_delay(Duration::from_secs(60))
+            .build()?,
+    );
+    let timeout = Duration::from_secs(30);
+    options.set_timeout(timeout);
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let start = std::time::Instant::now();
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    let elapsed = start.elapsed();
+    assert!(elapsed < timeout, "{elapsed:?}");
+    let err = response.err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::DeadlineExceeded, "{err:?}");
+    // The last error is available as the source.
+    assert!(err.as_inner::<HttpError>().is_some(), "{err:?}");
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_deadline_expires() -> Result<()> {
+    let (endpoint, server) = echo_server::start().await?;
+    let config = ClientConfig::default().set_credential(auth::Credential::test_credentials());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let delay = Duration::from_millis(200);
+    let mut interval = tokio::time::interval(Duration::from_millis(10));
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("delay_ms", format!("{}", delay.as_millis()))]);
+    let mut options = RequestOptions::default();
+    options.set_deadline(TokioClock.now() + Duration::from_millis(150));
+    let response =
+        client.execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options);
+
+    tokio::pin!(server);
+    tokio::pin!(response);
+    loop {
+        tokio::select! {
+            _ = &mut server => {  },
+            r = &mut response => {
+                use gax::error::ErrorKind;
+                let err = r.err().unwrap();
+                assert_eq!(err.kind(), ErrorKind::Timeout, "{err:?}");
                 break;
             },
             _ = interval.tick() => { },
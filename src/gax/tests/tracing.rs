@@ -0,0 +1,183 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the HTTP client creates a tracing span for each request attempt.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gax::retry_policy::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+type Fields = HashMap<String, String>;
+
+/// Captures the name and fields of all the spans.
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<(Id, Fields)>>>,
+}
+
+impl Recorder {
+    fn request_spans(&self) -> Vec<Fields> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, f)| f.clone())
+            .filter(|f| f.get("name").map(String::as_str) == Some("http_request"))
+            .collect()
+    }
+}
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S: tracing::Subscriber> Layer<S> for Recorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        fields.insert("name".to_string(), attrs.metadata().name().to_string());
+        attrs.record(&mut Visitor(&mut fields));
+        self.spans.lock().unwrap().push((id.clone(), fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some((_, fields)) = spans.iter_mut().rev().find(|(i, _)| i == id) {
+            values.record(&mut Visitor(fields));
+        }
+    }
+}
+
+fn test_config() -> ClientConfig {
+    ClientConfig::default().set_credential(auth::Credential::test_credentials())
+}
+
+#[tokio::test]
+async fn test_request_span() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let client = ReqwestClient::new(test_config(), &endpoint).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let _ = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+
+    let spans = recorder.request_spans();
+    assert_eq!(spans.len(), 1, "{spans:?}");
+    let span = &spans[0];
+    assert_eq!(
+        span.get("method").map(String::as_str),
+        Some("GET"),
+        "{span:?}"
+    );
+    assert_eq!(
+        span.get("url").cloned(),
+        Some(format!("{endpoint}/echo")),
+        "{span:?}"
+    );
+    assert_eq!(
+        span.get("attempt").map(String::as_str),
+        Some("1"),
+        "{span:?}"
+    );
+    assert_eq!(
+        span.get("status_code").map(String::as_str),
+        Some("200"),
+        "{span:?}"
+    );
+    assert!(span.contains_key("latency_ms"), "{span:?}");
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_request_span_per_attempt() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let client = ReqwestClient::new(test_config(), &endpoint).await?;
+    let mut options = RequestOptions::default();
+    options.set_retry_policy(AlwaysRetry.with_attempt_limit(3));
+    let builder = client.builder(reqwest::Method::GET, "/error".into());
+    let response = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, Some(json!({})), options)
+        .await;
+    assert!(response.is_err(), "{response:?}");
+
+    let spans = recorder.request_spans();
+    let attempts = spans
+        .iter()
+        .map(|s| s.get("attempt").cloned().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(attempts, vec!["1", "2", "3"], "{spans:?}");
+    for span in spans {
+        assert_eq!(
+            span.get("status_code").map(String::as_str),
+            Some("400"),
+            "{span:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_request_spans_disabled() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let config = test_config().disable_request_spans();
+    let client = ReqwestClient::new(config, &endpoint).await?;
+    let builder = client.builder(reqwest::Method::GET, "/echo".into());
+    let _ = client
+        .execute::<serde_json::Value, serde_json::Value>(
+            builder,
+            Some(json!({})),
+            RequestOptions::default(),
+        )
+        .await?;
+
+    let spans = recorder.request_spans();
+    assert!(spans.is_empty(), "{spans:?}");
+
+    Ok(())
+}
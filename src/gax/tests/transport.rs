@@ -0,0 +1,217 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gax::error::HttpError;
+use gax::http_client::ReqwestClient;
+use gax::interceptor::*;
+use gax::options::*;
+use gax::transport::*;
+use gcp_sdk_gax as gax;
+use serde_json::json;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Records the requests and returns canned responses, in order.
+#[derive(Debug, Default)]
+struct Fake {
+    requests: Arc<Mutex<Vec<InterceptedRequest>>>,
+    responses: Mutex<VecDeque<gax::Result<InterceptedResponse>>>,
+}
+
+impl Fake {
+    fn new<I: IntoIterator<Item = gax::Result<InterceptedResponse>>>(responses: I) -> Self {
+        Self {
+            requests: Arc::default(),
+            responses: Mutex::new(responses.into_iter().collect()),
+        }
+    }
+}
+
+impl HttpTransport for Fake {
+    fn send(&self, request: InterceptedRequest) -> TransportFuture<'_> {
+        self.requests.lock().unwrap().push(request);
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected request");
+        Box::pin(async move { response })
+    }
+}
+
+#[derive(Debug)]
+struct AddHeader;
+
+impl Interceptor for AddHeader {
+    fn on_request(
+        &self,
+        request: &mut InterceptedRequest,
+    ) -> gax::Result<Option<InterceptedResponse>> {
+        request
+            .headers
+            .insert("x-test-header", http::HeaderValue::from_static("value"));
+        Ok(None)
+    }
+}
+
+fn unavailable() -> InterceptedResponse {
+    let status = json!({"error": {
+        "code": 503,
+        "status": "UNAVAILABLE",
+        "message": "try-again",
+    }});
+    InterceptedResponse::new(http::StatusCode::SERVICE_UNAVAILABLE, status.to_string())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fake_transport() -> Result<()> {
+    let fake = Fake::new([Ok(InterceptedResponse::new(
+        http::StatusCode::OK,
+        r#"{"name": "response"}"#,
+    ))]);
+    let requests = fake.requests.clone();
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .add_interceptor(AddHeader)
+        .set_transport(fake);
+    // Nothing is listening on this endpoint.
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client
+        .builder(reqwest::Method::POST, "/v1/test".into())
+        .query(&[("a", "b")]);
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({"k": "v"})), RequestOptions::default())
+        .await?;
+    assert_eq!(response, json!({"name": "response"}));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.method, http::Method::POST);
+    assert_eq!(request.url, "http://127.0.0.1:1/v1/test?a=b");
+    assert_eq!(
+        request.headers.get("x-test-header"),
+        Some(&http::HeaderValue::from_static("value"))
+    );
+    assert!(request.headers.contains_key("authorization"), "{request:?}");
+    let body = serde_json::from_slice::<serde_json::Value>(request.body.as_ref().unwrap())?;
+    assert_eq!(body, json!({"k": "v"}));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fake_transport_error() -> Result<()> {
+    let fake = Fake::new([Ok(unavailable())]);
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .set_transport(fake);
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let err = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, None, RequestOptions::default())
+        .await
+        .unwrap_err();
+    let inner = err.as_inner::<HttpError>().unwrap();
+    assert_eq!(inner.status_code(), 503);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fake_transport_html_error() -> Result<()> {
+    let html = "<html><body><h1>502 Bad Gateway</h1></body></html>";
+    let fake = Fake::new([Ok(InterceptedResponse::new(
+        http::StatusCode::BAD_GATEWAY,
+        html,
+    ))]);
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .set_transport(fake);
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let err = client
+        .execute::<serde_json::Value, serde_json::Value>(builder, None, RequestOptions::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), gax::error::ErrorKind::Rpc, "{err:?}");
+    let inner = err.as_inner::<HttpError>().unwrap();
+    assert_eq!(inner.status_code(), 502);
+    assert_eq!(inner.payload().map(|b| b.as_ref()), Some(html.as_bytes()));
+    let status = err.status().unwrap();
+    assert_eq!(status.code, 502);
+    assert_eq!(status.status.as_deref(), Some("INTERNAL"));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fake_transport_retry() -> Result<()> {
+    use gax::backoff_policy::ExponentialBackoffBuilder;
+    use gax::retry_policy::{Aip194Strict, RetryPolicyExt};
+    use std::time::Duration;
+
+    let fake = Fake::new([
+        Err(gax::error::Error::io("connection reset")),
+        Ok(unavailable()),
+        Ok(InterceptedResponse::new(http::StatusCode::OK, "{}")),
+    ]);
+    let requests = fake.requests.clone();
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .set_retry_policy(Aip194Strict.with_attempt_limit(5))
+        .set_backoff_policy(
+            ExponentialBackoffBuilder::new()
+                .with_initial_delay(Duration::from_millis(1))
+                .with_maximum_delay(Duration::from_millis(1))
+                .build()?,
+        )
+        .set_transport(fake);
+    let client = ReqwestClient::new(config, "http://127.0.0.1:1").await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let response: serde_json::Value = client
+        .execute(
+            builder,
+            None::<serde_json::Value>,
+            RequestOptions::default(),
+        )
+        .await?;
+    assert_eq!(response, json!({}));
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reqwest_transport() -> Result<()> {
+    let (endpoint, _server) = echo_server::start().await?;
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::test_credentials())
+        .add_interceptor(AddHeader)
+        .set_transport(reqwest::Client::new());
+    let client = ReqwestClient::new(config, &endpoint).await?;
+
+    let builder = client
+        .builder(reqwest::Method::GET, "/echo".into())
+        .query(&[("a", "b")]);
+    let response: serde_json::Value = client
+        .execute(builder, Some(json!({})), RequestOptions::default())
+        .await?;
+    assert_eq!(response["headers"]["x-test-header"], "value");
+    assert_eq!(response["query"], json!({"a": "b"}));
+    Ok(())
+}
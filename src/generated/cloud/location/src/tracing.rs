@@ -54,4 +54,8 @@ where
     ) -> Result<crate::model::Location> {
         self.inner.get_location(req, options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
@@ -28,6 +28,8 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
         req: crate::model::GetLocationRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<crate::model::Location>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::Locations] also implement [Locations].
@@ -50,4 +52,9 @@ impl<T: crate::traits::Locations> Locations for T {
     ) -> crate::Result<crate::model::Location> {
         T::get_location(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
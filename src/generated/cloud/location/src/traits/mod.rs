@@ -54,4 +54,11 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
             "unimplemented",
         )))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
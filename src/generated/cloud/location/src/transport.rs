@@ -45,6 +45,32 @@ impl crate::traits::Locations for Locations {
         req: crate::model::ListLocationsRequest,
         options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListLocationsResponse> {
+        let primary_matches = gax::path_parameter::matches(&req.name, "locations");
+        if !primary_matches && gax::path_parameter::matches(&req.name, "projects/*") {
+            let builder = self
+                .inner
+                .builder(reqwest::Method::GET, format!("/v1/{}/locations", req.name))
+                .query(&[("alt", "json")])
+                .header(
+                    "x-goog-api-client",
+                    reqwest::header::HeaderValue::from_static(
+                        &crate::info::X_GOOG_API_CLIENT_HEADER,
+                    ),
+                );
+            let builder = gax::routing_parameter::RoutingParameters::new()
+                .add("name", &req.name)
+                .apply(builder);
+            let builder =
+                gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
+            let builder = gax::query_parameter::add(builder, "pageSize", &req.page_size)
+                .map_err(Error::other)?;
+            let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
+                .map_err(Error::other)?;
+            return self
+                .inner
+                .execute(builder, None::<gax::http_client::NoBody>, options)
+                .await;
+        }
         let builder = self
             .inner
             .builder(reqwest::Method::GET, format!("/v1/{}", req.name))
@@ -53,6 +79,9 @@ impl crate::traits::Locations for Locations {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
         let builder =
@@ -77,8 +106,15 @@ impl crate::traits::Locations for Locations {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner
             .execute(builder, None::<gax::http_client::NoBody>, options)
             .await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
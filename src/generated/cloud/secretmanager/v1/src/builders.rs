@@ -65,16 +65,32 @@ impl ListSecrets {
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(
         self,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(
+        self,
     ) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(
+        self,
+    ) -> gax::paginator::ItemPaginator<crate::model::ListSecretsResponse, gax::error::Error> {
+        self.by_page().items()
+    }
+
     /// Sets the value of `parent`.
     pub fn set_parent<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.parent = v.into();
@@ -345,16 +361,34 @@ impl ListSecretVersions {
     pub async fn stream(
         self,
     ) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error>
+    {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(
+        self,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, gax::error::Error>
     {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(
+        self,
+    ) -> gax::paginator::ItemPaginator<crate::model::ListSecretVersionsResponse, gax::error::Error>
+    {
+        self.by_page().items()
+    }
+
     /// Sets the value of `parent`.
     pub fn set_parent<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.parent = v.into();
@@ -782,16 +816,33 @@ impl ListLocations {
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(
         self,
+    ) -> gax::paginator::Paginator<location::model::ListLocationsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(
+        self,
     ) -> gax::paginator::Paginator<location::model::ListLocationsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(
+        self,
+    ) -> gax::paginator::ItemPaginator<location::model::ListLocationsResponse, gax::error::Error>
+    {
+        self.by_page().items()
+    }
+
     /// Sets the value of `name`.
     pub fn set_name<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.name = v.into();
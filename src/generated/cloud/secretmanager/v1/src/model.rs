@@ -174,21 +174,69 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `replication`.
+    pub fn set_or_clear_replication<T: Into<crate::model::Replication>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.replication = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `create_time`.
     pub fn set_create_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.create_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `labels`.
-    pub fn set_labels<T: Into<std::collections::HashMap<String, String>>>(mut self, v: T) -> Self {
-        self.labels = v.into();
+    pub fn set_labels<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `labels`.
+    pub fn insert_labels<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.labels.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `topics`.
-    pub fn set_topics<T: Into<Vec<crate::model::Topic>>>(mut self, v: T) -> Self {
-        self.topics = v.into();
+    pub fn set_topics<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Topic>,
+    {
+        self.topics = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `topics`.
+    pub fn add_topics<T: Into<crate::model::Topic>>(mut self, v: T) -> Self {
+        self.topics.push(v.into());
         self
     }
 
@@ -204,21 +252,43 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `rotation`.
+    pub fn set_or_clear_rotation<T: Into<crate::model::Rotation>>(mut self, v: Option<T>) -> Self {
+        self.rotation = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `version_aliases`.
-    pub fn set_version_aliases<T: Into<std::collections::HashMap<String, i64>>>(
-        mut self,
-        v: T,
-    ) -> Self {
-        self.version_aliases = v.into();
+    pub fn set_version_aliases<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<i64>,
+    {
+        self.version_aliases = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `version_aliases`.
+    pub fn insert_version_aliases<K: Into<String>, V: Into<i64>>(mut self, k: K, v: V) -> Self {
+        self.version_aliases.insert(k.into(), v.into());
         self
     }
 
     /// Sets the value of `annotations`.
-    pub fn set_annotations<T: Into<std::collections::HashMap<String, String>>>(
-        mut self,
-        v: T,
-    ) -> Self {
-        self.annotations = v.into();
+    pub fn set_annotations<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.annotations = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `annotations`.
+    pub fn insert_annotations<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.annotations.insert(k.into(), v.into());
         self
     }
 
@@ -228,6 +298,27 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `version_destroy_ttl`.
+    pub fn set_or_clear_version_destroy_ttl<T: Into<wkt::Duration>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.version_destroy_ttl = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `version_destroy_ttl` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_version_destroy_ttl<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.version_destroy_ttl = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<
         T: Into<Option<crate::model::CustomerManagedEncryption>>,
@@ -239,6 +330,17 @@ impl Secret {
         self
     }
 
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<
+        T: Into<crate::model::CustomerManagedEncryption>,
+    >(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `expiration`.
     pub fn set_expiration<T: Into<Option<crate::model::secret::Expiration>>>(
         mut self,
@@ -387,12 +489,48 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `create_time`.
+    pub fn set_or_clear_create_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.create_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `create_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_create_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.create_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `destroy_time`.
     pub fn set_destroy_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.destroy_time = v.into();
         self
     }
 
+    /// Sets or clears the value of `destroy_time`.
+    pub fn set_or_clear_destroy_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `state`.
     pub fn set_state<T: Into<crate::model::secret_version::State>>(mut self, v: T) -> Self {
         self.state = v.into();
@@ -408,6 +546,15 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `replication_status`.
+    pub fn set_or_clear_replication_status<T: Into<crate::model::ReplicationStatus>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.replication_status = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `etag`.
     pub fn set_etag<T: Into<String>>(mut self, v: T) -> Self {
         self.etag = v.into();
@@ -426,6 +573,27 @@ impl SecretVersion {
         self
     }
 
+    /// Sets or clears the value of `scheduled_destroy_time`.
+    pub fn set_or_clear_scheduled_destroy_time<T: Into<wkt::Timestamp>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.scheduled_destroy_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `scheduled_destroy_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_scheduled_destroy_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.scheduled_destroy_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `customer_managed_encryption`.
     pub fn set_customer_managed_encryption<
         T: Into<Option<crate::model::CustomerManagedEncryptionStatus>>,
@@ -436,6 +604,17 @@ impl SecretVersion {
         self.customer_managed_encryption = v.into();
         self
     }
+
+    /// Sets or clears the value of `customer_managed_encryption`.
+    pub fn set_or_clear_customer_managed_encryption<
+        T: Into<crate::model::CustomerManagedEncryptionStatus>,
+    >(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.customer_managed_encryption = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Defines additional types related to SecretVersion
@@ -555,6 +734,17 @@ pub mod replication {
             self.customer_managed_encryption = v.into();
             self
         }
+
+        /// Sets or clears the value of `customer_managed_encryption`.
+        pub fn set_or_clear_customer_managed_encryption<
+            T: Into<crate::model::CustomerManagedEncryption>,
+        >(
+            mut self,
+            v: Option<T>,
+        ) -> Self {
+            self.customer_managed_encryption = v.map(|x| x.into());
+            self
+        }
     }
 
     /// A replication policy that replicates the
@@ -579,11 +769,21 @@ pub mod replication {
 
     impl UserManaged {
         /// Sets the value of `replicas`.
-        pub fn set_replicas<T: Into<Vec<crate::model::replication::user_managed::Replica>>>(
+        pub fn set_replicas<T, V>(mut self, v: T) -> Self
+        where
+            T: IntoIterator<Item = V>,
+            V: Into<crate::model::replication::user_managed::Replica>,
+        {
+            self.replicas = v.into_iter().map(|i| i.into()).collect();
+            self
+        }
+
+        /// Appends a value to `replicas`.
+        pub fn add_replicas<T: Into<crate::model::replication::user_managed::Replica>>(
             mut self,
             v: T,
         ) -> Self {
-            self.replicas = v.into();
+            self.replicas.push(v.into());
             self
         }
     }
@@ -638,6 +838,17 @@ pub mod replication {
                 self.customer_managed_encryption = v.into();
                 self
             }
+
+            /// Sets or clears the value of `customer_managed_encryption`.
+            pub fn set_or_clear_customer_managed_encryption<
+                T: Into<crate::model::CustomerManagedEncryption>,
+            >(
+                mut self,
+                v: Option<T>,
+            ) -> Self {
+                self.customer_managed_encryption = v.map(|x| x.into());
+                self
+            }
         }
     }
 
@@ -761,6 +972,17 @@ pub mod replication_status {
             self.customer_managed_encryption = v.into();
             self
         }
+
+        /// Sets or clears the value of `customer_managed_encryption`.
+        pub fn set_or_clear_customer_managed_encryption<
+            T: Into<crate::model::CustomerManagedEncryptionStatus>,
+        >(
+            mut self,
+            v: Option<T>,
+        ) -> Self {
+            self.customer_managed_encryption = v.map(|x| x.into());
+            self
+        }
     }
 
     /// The replication status of a
@@ -787,13 +1009,23 @@ pub mod replication_status {
 
     impl UserManagedStatus {
         /// Sets the value of `replicas`.
-        pub fn set_replicas<
-            T: Into<Vec<crate::model::replication_status::user_managed_status::ReplicaStatus>>,
+        pub fn set_replicas<T, V>(mut self, v: T) -> Self
+        where
+            T: IntoIterator<Item = V>,
+            V: Into<crate::model::replication_status::user_managed_status::ReplicaStatus>,
+        {
+            self.replicas = v.into_iter().map(|i| i.into()).collect();
+            self
+        }
+
+        /// Appends a value to `replicas`.
+        pub fn add_replicas<
+            T: Into<crate::model::replication_status::user_managed_status::ReplicaStatus>,
         >(
             mut self,
             v: T,
         ) -> Self {
-            self.replicas = v.into();
+            self.replicas.push(v.into());
             self
         }
     }
@@ -841,6 +1073,17 @@ pub mod replication_status {
                 self.customer_managed_encryption = v.into();
                 self
             }
+
+            /// Sets or clears the value of `customer_managed_encryption`.
+            pub fn set_or_clear_customer_managed_encryption<
+                T: Into<crate::model::CustomerManagedEncryptionStatus>,
+            >(
+                mut self,
+                v: Option<T>,
+            ) -> Self {
+                self.customer_managed_encryption = v.map(|x| x.into());
+                self
+            }
         }
     }
 
@@ -976,11 +1219,50 @@ impl Rotation {
         self
     }
 
+    /// Sets or clears the value of `next_rotation_time`.
+    pub fn set_or_clear_next_rotation_time<T: Into<wkt::Timestamp>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.next_rotation_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `next_rotation_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_next_rotation_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.next_rotation_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `rotation_period`.
     pub fn set_rotation_period<T: Into<Option<wkt::Duration>>>(mut self, v: T) -> Self {
         self.rotation_period = v.into();
         self
     }
+
+    /// Sets or clears the value of `rotation_period`.
+    pub fn set_or_clear_rotation_period<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.rotation_period = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `rotation_period` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_rotation_period<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.rotation_period = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// A secret payload resource in the Secret Manager API. This contains the
@@ -995,7 +1277,7 @@ impl Rotation {
 pub struct SecretPayload {
     /// The secret data. Must be no larger than 64KiB.
     #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde_as(as = "wkt::internal::Base64")]
     pub data: bytes::Bytes,
 
     /// Optional. If specified,
@@ -1037,6 +1319,12 @@ impl SecretPayload {
         self.data_crc32c = v.into();
         self
     }
+
+    /// Sets or clears the value of `data_crc32c`.
+    pub fn set_or_clear_data_crc32c<T: Into<i64>>(mut self, v: Option<T>) -> Self {
+        self.data_crc32c = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1139,8 +1427,18 @@ pub struct ListSecretsResponse {
 
 impl ListSecretsResponse {
     /// Sets the value of `secrets`.
-    pub fn set_secrets<T: Into<Vec<crate::model::Secret>>>(mut self, v: T) -> Self {
-        self.secrets = v.into();
+    pub fn set_secrets<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Secret>,
+    {
+        self.secrets = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `secrets`.
+    pub fn add_secrets<T: Into<crate::model::Secret>>(mut self, v: T) -> Self {
+        self.secrets.push(v.into());
         self
     }
 
@@ -1221,6 +1519,12 @@ impl CreateSecretRequest {
         self.secret = v.into();
         self
     }
+
+    /// Sets or clears the value of `secret`.
+    pub fn set_or_clear_secret<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.secret = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1262,6 +1566,15 @@ impl AddSecretVersionRequest {
         self.payload = v.into();
         self
     }
+
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1391,8 +1704,18 @@ pub struct ListSecretVersionsResponse {
 
 impl ListSecretVersionsResponse {
     /// Sets the value of `versions`.
-    pub fn set_versions<T: Into<Vec<crate::model::SecretVersion>>>(mut self, v: T) -> Self {
-        self.versions = v.into();
+    pub fn set_versions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::SecretVersion>,
+    {
+        self.versions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `versions`.
+    pub fn add_versions<T: Into<crate::model::SecretVersion>>(mut self, v: T) -> Self {
+        self.versions.push(v.into());
         self
     }
 
@@ -1482,11 +1805,23 @@ impl UpdateSecretRequest {
         self
     }
 
+    /// Sets or clears the value of `secret`.
+    pub fn set_or_clear_secret<T: Into<crate::model::Secret>>(mut self, v: Option<T>) -> Self {
+        self.secret = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `update_mask`.
     pub fn set_update_mask<T: Into<Option<wkt::FieldMask>>>(mut self, v: T) -> Self {
         self.update_mask = v.into();
         self
     }
+
+    /// Sets or clears the value of `update_mask`.
+    pub fn set_or_clear_update_mask<T: Into<wkt::FieldMask>>(mut self, v: Option<T>) -> Self {
+        self.update_mask = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
@@ -1556,6 +1891,15 @@ impl AccessSecretVersionResponse {
         self.payload = v.into();
         self
     }
+
+    /// Sets or clears the value of `payload`.
+    pub fn set_or_clear_payload<T: Into<crate::model::SecretPayload>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.payload = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for
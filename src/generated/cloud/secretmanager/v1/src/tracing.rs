@@ -171,6 +171,10 @@ where
     ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
         self.inner.test_iam_permissions(req, options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
 
 /// Implements a [Locations](crate::traits::) decorator for logging and tracing.
@@ -212,4 +216,8 @@ where
     ) -> Result<location::model::Location> {
         self.inner.get_location(req, options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
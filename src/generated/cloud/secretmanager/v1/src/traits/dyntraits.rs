@@ -106,6 +106,8 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
         req: iam_v1::model::TestIamPermissionsRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<iam_v1::model::TestIamPermissionsResponse>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::SecretManagerService] also implement [SecretManagerService].
@@ -245,6 +247,11 @@ impl<T: crate::traits::SecretManagerService> SecretManagerService for T {
     ) -> crate::Result<iam_v1::model::TestIamPermissionsResponse> {
         T::test_iam_permissions(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
 
 /// A dyn-compatible, crate-private version of `Locations`.
@@ -261,6 +268,8 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
         req: location::model::GetLocationRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<location::model::Location>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::Locations] also implement [Locations].
@@ -283,4 +292,9 @@ impl<T: crate::traits::Locations> Locations for T {
     ) -> crate::Result<location::model::Location> {
         T::get_location(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
@@ -277,6 +277,13 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
             Error::other("unimplemented"),
         ))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
 
 /// Manages location-related information with an API service.
@@ -311,4 +318,11 @@ pub trait Locations: std::fmt::Debug + Send + Sync {
             "unimplemented",
         )))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
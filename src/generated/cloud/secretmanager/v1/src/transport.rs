@@ -53,6 +53,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
@@ -77,6 +80,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "secretId", &req.secret_id).map_err(Error::other)?;
         self.inner.execute(builder, Some(req.secret), options).await
@@ -98,6 +104,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -114,6 +123,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner
             .execute(builder, None::<gax::http_client::NoBody>, options)
             .await
@@ -140,6 +152,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add(
+                "secret.name",
+                &gax::path_parameter::PathParameter::required(&req.secret, "secret")
+                    .map_err(Error::other)?
+                    .name,
+            )
+            .apply(builder);
         let builder = gax::query_parameter::add(
             builder,
             "updateMask",
@@ -162,6 +182,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "etag", &req.etag).map_err(Error::other)?;
         self.inner
@@ -182,6 +205,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("parent", &req.parent)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
@@ -206,6 +232,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner
             .execute(builder, None::<gax::http_client::NoBody>, options)
             .await
@@ -224,6 +253,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner
             .execute(builder, None::<gax::http_client::NoBody>, options)
             .await
@@ -242,6 +274,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -258,6 +293,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -274,6 +312,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -293,6 +334,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -312,6 +356,9 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         let builder = gax::query_parameter::add(
             builder,
             "options",
@@ -339,8 +386,15 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
 
 /// Implements [Locations](crate::traits::) using a [gax::http_client::ReqwestClient].
@@ -378,6 +432,9 @@ impl crate::traits::Locations for Locations {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
         let builder =
@@ -402,8 +459,15 @@ impl crate::traits::Locations for Locations {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("name", &req.name)
+            .apply(builder);
         self.inner
             .execute(builder, None::<gax::http_client::NoBody>, options)
             .await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
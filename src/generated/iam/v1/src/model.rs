@@ -57,11 +57,23 @@ impl SetIamPolicyRequest {
         self
     }
 
+    /// Sets or clears the value of `policy`.
+    pub fn set_or_clear_policy<T: Into<crate::model::Policy>>(mut self, v: Option<T>) -> Self {
+        self.policy = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `update_mask`.
     pub fn set_update_mask<T: Into<Option<wkt::FieldMask>>>(mut self, v: T) -> Self {
         self.update_mask = v.into();
         self
     }
+
+    /// Sets or clears the value of `update_mask`.
+    pub fn set_or_clear_update_mask<T: Into<wkt::FieldMask>>(mut self, v: Option<T>) -> Self {
+        self.update_mask = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for `GetIamPolicy` method.
@@ -93,6 +105,15 @@ impl GetIamPolicyRequest {
         self.options = v.into();
         self
     }
+
+    /// Sets or clears the value of `options`.
+    pub fn set_or_clear_options<T: Into<crate::model::GetPolicyOptions>>(
+        mut self,
+        v: Option<T>,
+    ) -> Self {
+        self.options = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Request message for `TestIamPermissions` method.
@@ -122,8 +143,18 @@ impl TestIamPermissionsRequest {
     }
 
     /// Sets the value of `permissions`.
-    pub fn set_permissions<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.permissions = v.into();
+    pub fn set_permissions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.permissions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `permissions`.
+    pub fn add_permissions<T: Into<String>>(mut self, v: T) -> Self {
+        self.permissions.push(v.into());
         self
     }
 }
@@ -142,8 +173,18 @@ pub struct TestIamPermissionsResponse {
 
 impl TestIamPermissionsResponse {
     /// Sets the value of `permissions`.
-    pub fn set_permissions<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.permissions = v.into();
+    pub fn set_permissions<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.permissions = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `permissions`.
+    pub fn add_permissions<T: Into<String>>(mut self, v: T) -> Self {
+        self.permissions.push(v.into());
         self
     }
 }
@@ -318,7 +359,7 @@ pub struct Policy {
     /// you to overwrite a version `3` policy with a version `1` policy, and all of
     /// the conditions in the version `3` policy are lost.
     #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde_as(as = "wkt::internal::Base64")]
     pub etag: bytes::Bytes,
 }
 
@@ -330,14 +371,34 @@ impl Policy {
     }
 
     /// Sets the value of `bindings`.
-    pub fn set_bindings<T: Into<Vec<crate::model::Binding>>>(mut self, v: T) -> Self {
-        self.bindings = v.into();
+    pub fn set_bindings<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Binding>,
+    {
+        self.bindings = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `bindings`.
+    pub fn add_bindings<T: Into<crate::model::Binding>>(mut self, v: T) -> Self {
+        self.bindings.push(v.into());
         self
     }
 
     /// Sets the value of `audit_configs`.
-    pub fn set_audit_configs<T: Into<Vec<crate::model::AuditConfig>>>(mut self, v: T) -> Self {
-        self.audit_configs = v.into();
+    pub fn set_audit_configs<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::AuditConfig>,
+    {
+        self.audit_configs = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `audit_configs`.
+    pub fn add_audit_configs<T: Into<crate::model::AuditConfig>>(mut self, v: T) -> Self {
+        self.audit_configs.push(v.into());
         self
     }
 
@@ -430,8 +491,18 @@ impl Binding {
     }
 
     /// Sets the value of `members`.
-    pub fn set_members<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.members = v.into();
+    pub fn set_members<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.members = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `members`.
+    pub fn add_members<T: Into<String>>(mut self, v: T) -> Self {
+        self.members.push(v.into());
         self
     }
 
@@ -440,6 +511,12 @@ impl Binding {
         self.condition = v.into();
         self
     }
+
+    /// Sets or clears the value of `condition`.
+    pub fn set_or_clear_condition<T: Into<gtype::model::Expr>>(mut self, v: Option<T>) -> Self {
+        self.condition = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Specifies the audit configuration for a service.
@@ -519,11 +596,18 @@ impl AuditConfig {
     }
 
     /// Sets the value of `audit_log_configs`.
-    pub fn set_audit_log_configs<T: Into<Vec<crate::model::AuditLogConfig>>>(
-        mut self,
-        v: T,
-    ) -> Self {
-        self.audit_log_configs = v.into();
+    pub fn set_audit_log_configs<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::AuditLogConfig>,
+    {
+        self.audit_log_configs = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `audit_log_configs`.
+    pub fn add_audit_log_configs<T: Into<crate::model::AuditLogConfig>>(mut self, v: T) -> Self {
+        self.audit_log_configs.push(v.into());
         self
     }
 }
@@ -575,8 +659,18 @@ impl AuditLogConfig {
     }
 
     /// Sets the value of `exempted_members`.
-    pub fn set_exempted_members<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.exempted_members = v.into();
+    pub fn set_exempted_members<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.exempted_members = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `exempted_members`.
+    pub fn add_exempted_members<T: Into<String>>(mut self, v: T) -> Self {
+        self.exempted_members.push(v.into());
         self
     }
 }
@@ -636,17 +730,37 @@ pub struct PolicyDelta {
 
 impl PolicyDelta {
     /// Sets the value of `binding_deltas`.
-    pub fn set_binding_deltas<T: Into<Vec<crate::model::BindingDelta>>>(mut self, v: T) -> Self {
-        self.binding_deltas = v.into();
+    pub fn set_binding_deltas<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::BindingDelta>,
+    {
+        self.binding_deltas = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `binding_deltas`.
+    pub fn add_binding_deltas<T: Into<crate::model::BindingDelta>>(mut self, v: T) -> Self {
+        self.binding_deltas.push(v.into());
         self
     }
 
     /// Sets the value of `audit_config_deltas`.
-    pub fn set_audit_config_deltas<T: Into<Vec<crate::model::AuditConfigDelta>>>(
+    pub fn set_audit_config_deltas<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::AuditConfigDelta>,
+    {
+        self.audit_config_deltas = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `audit_config_deltas`.
+    pub fn add_audit_config_deltas<T: Into<crate::model::AuditConfigDelta>>(
         mut self,
         v: T,
     ) -> Self {
-        self.audit_config_deltas = v.into();
+        self.audit_config_deltas.push(v.into());
         self
     }
 }
@@ -703,6 +817,12 @@ impl BindingDelta {
         self.condition = v.into();
         self
     }
+
+    /// Sets or clears the value of `condition`.
+    pub fn set_or_clear_condition<T: Into<gtype::model::Expr>>(mut self, v: Option<T>) -> Self {
+        self.condition = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Defines additional types related to BindingDelta
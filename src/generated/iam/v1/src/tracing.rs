@@ -63,4 +63,8 @@ where
     ) -> Result<crate::model::TestIamPermissionsResponse> {
         self.inner.test_iam_permissions(req, options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
@@ -34,6 +34,8 @@ pub trait IAMPolicy: std::fmt::Debug + Send + Sync {
         req: crate::model::TestIamPermissionsRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<crate::model::TestIamPermissionsResponse>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::IAMPolicy] also implement [IAMPolicy].
@@ -65,4 +67,9 @@ impl<T: crate::traits::IAMPolicy> IAMPolicy for T {
     ) -> crate::Result<crate::model::TestIamPermissionsResponse> {
         T::test_iam_permissions(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
@@ -56,6 +56,9 @@ impl crate::traits::IAMPolicy for IAMPolicy {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -75,6 +78,9 @@ impl crate::traits::IAMPolicy for IAMPolicy {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
 
@@ -94,6 +100,13 @@ impl crate::traits::IAMPolicy for IAMPolicy {
                 "x-goog-api-client",
                 reqwest::header::HeaderValue::from_static(&crate::info::X_GOOG_API_CLIENT_HEADER),
             );
+        let builder = gax::routing_parameter::RoutingParameters::new()
+            .add("resource", &req.resource)
+            .apply(builder);
         self.inner.execute(builder, Some(req), options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
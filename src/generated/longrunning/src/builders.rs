@@ -65,16 +65,33 @@ impl ListOperations {
     #[cfg(feature = "unstable-stream")]
     pub async fn stream(
         self,
+    ) -> gax::paginator::Paginator<crate::model::ListOperationsResponse, gax::error::Error> {
+        self.by_page()
+    }
+
+    /// Streams each page in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_page(
+        self,
     ) -> gax::paginator::Paginator<crate::model::ListOperationsResponse, gax::error::Error> {
         let token = gax::paginator::extract_token(&self.0.request.page_token);
         let execute = move |token: String| {
-            let builder = self.clone();
-            builder.0.request.clone().set_page_token(token);
+            let mut builder = self.clone();
+            builder.0.request = builder.0.request.set_page_token(token);
             builder.send()
         };
         gax::paginator::Paginator::new(token, execute)
     }
 
+    /// Streams each item in the collection.
+    #[cfg(feature = "unstable-stream")]
+    pub fn by_item(
+        self,
+    ) -> gax::paginator::ItemPaginator<crate::model::ListOperationsResponse, gax::error::Error>
+    {
+        self.by_page().items()
+    }
+
     /// Sets the value of `name`.
     pub fn set_name<T: Into<String>>(mut self, v: T) -> Self {
         self.0.request.name = v.into();
@@ -63,6 +63,12 @@ impl Operation {
         self
     }
 
+    /// Sets or clears the value of `metadata`.
+    pub fn set_or_clear_metadata<T: Into<wkt::Any>>(mut self, v: Option<T>) -> Self {
+        self.metadata = v.map(|x| x.into());
+        self
+    }
+
     /// Sets the value of `done`.
     pub fn set_done<T: Into<bool>>(mut self, v: T) -> Self {
         self.done = v.into();
@@ -194,8 +200,18 @@ pub struct ListOperationsResponse {
 
 impl ListOperationsResponse {
     /// Sets the value of `operations`.
-    pub fn set_operations<T: Into<Vec<crate::model::Operation>>>(mut self, v: T) -> Self {
-        self.operations = v.into();
+    pub fn set_operations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::Operation>,
+    {
+        self.operations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `operations`.
+    pub fn add_operations<T: Into<crate::model::Operation>>(mut self, v: T) -> Self {
+        self.operations.push(v.into());
         self
     }
 
@@ -294,6 +310,24 @@ impl WaitOperationRequest {
         self.timeout = v.into();
         self
     }
+
+    /// Sets or clears the value of `timeout`.
+    pub fn set_or_clear_timeout<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.timeout = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `timeout` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_timeout<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.timeout = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// A message representing the message types used by a long-running operation.
@@ -72,4 +72,8 @@ where
     ) -> Result<wkt::Empty> {
         self.inner.cancel_operation(req, options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
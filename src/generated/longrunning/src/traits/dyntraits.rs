@@ -40,6 +40,8 @@ pub trait Operations: std::fmt::Debug + Send + Sync {
         req: crate::model::CancelOperationRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<wkt::Empty>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::Operations] also implement [Operations].
@@ -80,4 +82,9 @@ impl<T: crate::traits::Operations> Operations for T {
     ) -> crate::Result<wkt::Empty> {
         T::cancel_operation(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
@@ -98,4 +98,11 @@ pub trait Operations: std::fmt::Debug + Send + Sync {
     ) -> impl std::future::Future<Output = crate::Result<wkt::Empty>> + Send {
         std::future::ready::<crate::Result<wkt::Empty>>(Err(Error::other("unimplemented")))
     }
+
+    /// Returns a redacted snapshot of the client configuration, if available.
+    ///
+    /// The default implementation returns `None`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        None
+    }
 }
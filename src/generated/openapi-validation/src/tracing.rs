@@ -354,4 +354,8 @@ where
             .test_iam_permissions_by_project_and_location_and_secret(req, options)
             .await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        self.inner.effective_config()
+    }
 }
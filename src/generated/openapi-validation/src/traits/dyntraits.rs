@@ -208,6 +208,8 @@ pub trait SecretManagerService: std::fmt::Debug + Send + Sync {
         req: crate::model::TestIamPermissionsRequest,
         options: gax::options::RequestOptions,
     ) -> crate::Result<crate::model::TestIamPermissionsResponse>;
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig>;
 }
 
 /// All implementations of [crate::traits::SecretManagerService] also implement [SecretManagerService].
@@ -505,4 +507,9 @@ impl<T: crate::traits::SecretManagerService> SecretManagerService for T {
     ) -> crate::Result<crate::model::TestIamPermissionsResponse> {
         T::test_iam_permissions_by_project_and_location_and_secret(self, req, options).await
     }
+
+    /// Forwards the call to the implementation provided by `T`.
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        T::effective_config(self)
+    }
 }
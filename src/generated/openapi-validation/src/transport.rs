@@ -824,4 +824,8 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             );
         self.inner.execute(builder, Some(req), options).await
     }
+
+    fn effective_config(&self) -> Option<gax::http_client::EffectiveConfig> {
+        Some(self.inner.effective_config())
+    }
 }
@@ -94,11 +94,19 @@ impl ErrorInfo {
     }
 
     /// Sets the value of `metadata`.
-    pub fn set_metadata<T: Into<std::collections::HashMap<String, String>>>(
-        mut self,
-        v: T,
-    ) -> Self {
-        self.metadata = v.into();
+    pub fn set_metadata<T, K, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata = v.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+
+    /// Inserts a key and value into `metadata`.
+    pub fn insert_metadata<K: Into<String>, V: Into<String>>(mut self, k: K, v: V) -> Self {
+        self.metadata.insert(k.into(), v.into());
         self
     }
 }
@@ -132,6 +140,24 @@ impl RetryInfo {
         self.retry_delay = v.into();
         self
     }
+
+    /// Sets or clears the value of `retry_delay`.
+    pub fn set_or_clear_retry_delay<T: Into<wkt::Duration>>(mut self, v: Option<T>) -> Self {
+        self.retry_delay = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `retry_delay` from a [std::time::Duration], or any
+    /// other type convertible to [wkt::Duration].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_retry_delay<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Duration>,
+    {
+        self.retry_delay = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// Describes additional debugging info.
@@ -151,8 +177,18 @@ pub struct DebugInfo {
 
 impl DebugInfo {
     /// Sets the value of `stack_entries`.
-    pub fn set_stack_entries<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.stack_entries = v.into();
+    pub fn set_stack_entries<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.stack_entries = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `stack_entries`.
+    pub fn add_stack_entries<T: Into<String>>(mut self, v: T) -> Self {
+        self.stack_entries.push(v.into());
         self
     }
 
@@ -186,11 +222,18 @@ pub struct QuotaFailure {
 
 impl QuotaFailure {
     /// Sets the value of `violations`.
-    pub fn set_violations<T: Into<Vec<crate::model::quota_failure::Violation>>>(
-        mut self,
-        v: T,
-    ) -> Self {
-        self.violations = v.into();
+    pub fn set_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::quota_failure::Violation>,
+    {
+        self.violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `violations`.
+    pub fn add_violations<T: Into<crate::model::quota_failure::Violation>>(mut self, v: T) -> Self {
+        self.violations.push(v.into());
         self
     }
 }
@@ -254,11 +297,21 @@ pub struct PreconditionFailure {
 
 impl PreconditionFailure {
     /// Sets the value of `violations`.
-    pub fn set_violations<T: Into<Vec<crate::model::precondition_failure::Violation>>>(
+    pub fn set_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::precondition_failure::Violation>,
+    {
+        self.violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `violations`.
+    pub fn add_violations<T: Into<crate::model::precondition_failure::Violation>>(
         mut self,
         v: T,
     ) -> Self {
-        self.violations = v.into();
+        self.violations.push(v.into());
         self
     }
 }
@@ -328,11 +381,21 @@ pub struct BadRequest {
 
 impl BadRequest {
     /// Sets the value of `field_violations`.
-    pub fn set_field_violations<T: Into<Vec<crate::model::bad_request::FieldViolation>>>(
+    pub fn set_field_violations<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::bad_request::FieldViolation>,
+    {
+        self.field_violations = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `field_violations`.
+    pub fn add_field_violations<T: Into<crate::model::bad_request::FieldViolation>>(
         mut self,
         v: T,
     ) -> Self {
-        self.field_violations = v.into();
+        self.field_violations.push(v.into());
         self
     }
 }
@@ -438,6 +501,15 @@ pub mod bad_request {
             self.localized_message = v.into();
             self
         }
+
+        /// Sets or clears the value of `localized_message`.
+        pub fn set_or_clear_localized_message<T: Into<crate::model::LocalizedMessage>>(
+            mut self,
+            v: Option<T>,
+        ) -> Self {
+            self.localized_message = v.map(|x| x.into());
+            self
+        }
     }
 }
 
@@ -550,8 +622,18 @@ pub struct Help {
 
 impl Help {
     /// Sets the value of `links`.
-    pub fn set_links<T: Into<Vec<crate::model::help::Link>>>(mut self, v: T) -> Self {
-        self.links = v.into();
+    pub fn set_links<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::help::Link>,
+    {
+        self.links = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `links`.
+    pub fn add_links<T: Into<crate::model::help::Link>>(mut self, v: T) -> Self {
+        self.links.push(v.into());
         self
     }
 }
@@ -642,7 +724,7 @@ pub struct HttpRequest {
 
     /// The HTTP request body. If the body is not expected, it should be empty.
     #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde_as(as = "wkt::internal::Base64")]
     pub body: bytes::Bytes,
 }
 
@@ -660,8 +742,18 @@ impl HttpRequest {
     }
 
     /// Sets the value of `headers`.
-    pub fn set_headers<T: Into<Vec<crate::model::HttpHeader>>>(mut self, v: T) -> Self {
-        self.headers = v.into();
+    pub fn set_headers<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::HttpHeader>,
+    {
+        self.headers = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `headers`.
+    pub fn add_headers<T: Into<crate::model::HttpHeader>>(mut self, v: T) -> Self {
+        self.headers.push(v.into());
         self
     }
 
@@ -692,7 +784,7 @@ pub struct HttpResponse {
 
     /// The HTTP response body. If the body is not expected, it should be empty.
     #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde_as(as = "wkt::internal::Base64")]
     pub body: bytes::Bytes,
 }
 
@@ -710,8 +802,18 @@ impl HttpResponse {
     }
 
     /// Sets the value of `headers`.
-    pub fn set_headers<T: Into<Vec<crate::model::HttpHeader>>>(mut self, v: T) -> Self {
-        self.headers = v.into();
+    pub fn set_headers<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<crate::model::HttpHeader>,
+    {
+        self.headers = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `headers`.
+    pub fn add_headers<T: Into<crate::model::HttpHeader>>(mut self, v: T) -> Self {
+        self.headers.push(v.into());
         self
     }
 
@@ -798,8 +900,18 @@ impl Status {
     }
 
     /// Sets the value of `details`.
-    pub fn set_details<T: Into<Vec<wkt::Any>>>(mut self, v: T) -> Self {
-        self.details = v.into();
+    pub fn set_details<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<wkt::Any>,
+    {
+        self.details = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `details`.
+    pub fn add_details<T: Into<wkt::Any>>(mut self, v: T) -> Self {
+        self.details.push(v.into());
         self
     }
 }
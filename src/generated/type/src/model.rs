@@ -218,6 +218,12 @@ impl Color {
         self.alpha = v.into();
         self
     }
+
+    /// Sets or clears the value of `alpha`.
+    pub fn set_or_clear_alpha<T: Into<wkt::FloatValue>>(mut self, v: Option<T>) -> Self {
+        self.alpha = v.map(|x| x.into());
+        self
+    }
 }
 
 /// Represents a whole or partial calendar date, such as a birthday. The time of
@@ -692,11 +698,47 @@ impl Interval {
         self
     }
 
+    /// Sets or clears the value of `start_time`.
+    pub fn set_or_clear_start_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.start_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `start_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_start_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.start_time = Some(v.try_into()?);
+        Ok(self)
+    }
+
     /// Sets the value of `end_time`.
     pub fn set_end_time<T: Into<Option<wkt::Timestamp>>>(mut self, v: T) -> Self {
         self.end_time = v.into();
         self
     }
+
+    /// Sets or clears the value of `end_time`.
+    pub fn set_or_clear_end_time<T: Into<wkt::Timestamp>>(mut self, v: Option<T>) -> Self {
+        self.end_time = v.map(|x| x.into());
+        self
+    }
+
+    /// Sets the value of `end_time` from a [std::time::SystemTime], or
+    /// any other type convertible to [wkt::Timestamp].
+    ///
+    /// Returns an error if the value is out of range.
+    pub fn try_set_end_time<T>(mut self, v: T) -> std::result::Result<Self, T::Error>
+    where
+        T: TryInto<wkt::Timestamp>,
+    {
+        self.end_time = Some(v.try_into()?);
+        Ok(self)
+    }
 }
 
 /// An object that represents a latitude/longitude pair. This is expressed as a
@@ -1126,14 +1168,34 @@ impl PostalAddress {
     }
 
     /// Sets the value of `address_lines`.
-    pub fn set_address_lines<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.address_lines = v.into();
+    pub fn set_address_lines<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.address_lines = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `address_lines`.
+    pub fn add_address_lines<T: Into<String>>(mut self, v: T) -> Self {
+        self.address_lines.push(v.into());
         self
     }
 
     /// Sets the value of `recipients`.
-    pub fn set_recipients<T: Into<Vec<String>>>(mut self, v: T) -> Self {
-        self.recipients = v.into();
+    pub fn set_recipients<T, V>(mut self, v: T) -> Self
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.recipients = v.into_iter().map(|i| i.into()).collect();
+        self
+    }
+
+    /// Appends a value to `recipients`.
+    pub fn add_recipients<T: Into<String>>(mut self, v: T) -> Self {
+        self.recipients.push(v.into());
         self
     }
 
@@ -301,7 +301,7 @@ async fn get_all_secret_names(
     project_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut stream = client.list_secrets(project_id).stream().await;
+    let mut stream = client.list_secrets(project_id).by_page();
     while let Some(response) = stream.next().await {
         response?
             .secrets
@@ -308,9 +308,7 @@ async fn get_all_secret_names(
     let mut names = Vec::new();
     let mut stream = client
         .list_secrets(format!("projects/{project_id}"))
-        .stream()
-        .await
-        .items();
+        .by_item();
     while let Some(response) = stream.next().await {
         let item = response?;
         names.push(item.name);
@@ -333,9 +331,7 @@ async fn cleanup_stale_secrets(
     let mut stale_secrets = Vec::new();
     let mut stream = client
         .list_secrets(format!("projects/{project_id}"))
-        .stream()
-        .await
-        .items();
+        .by_item();
     while let Some(secret) = stream.next().await {
         let secret = secret?;
         if secret
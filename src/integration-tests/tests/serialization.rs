@@ -30,6 +30,55 @@ mod serialization {
         Ok(())
     }
 
+    #[test]
+    fn optional_fields_presence() -> Result<()> {
+        // proto3 `optional` fields distinguish "set to the default" from "not
+        // set", both in the JSON representation and in the update masks.
+        let payload = sm::model::SecretPayload::default();
+        let got = serde_json::to_value(&payload)?;
+        assert_eq!(got, serde_json::json!({}));
+        let mask = wkt::FieldMask::from_set_fields(&payload)?;
+        assert!(mask.paths.is_empty(), "{mask:?}");
+
+        let payload = sm::model::SecretPayload::default().set_data_crc32c(0);
+        let got = serde_json::to_value(&payload)?;
+        assert_eq!(got, serde_json::json!({"dataCrc32c": "0"}));
+        let mask = wkt::FieldMask::from_set_fields(&payload)?;
+        assert_eq!(mask.paths, vec!["data_crc32c"]);
+
+        let roundtrip = serde_json::from_value::<sm::model::SecretPayload>(got)?;
+        assert_eq!(roundtrip.data_crc32c, Some(0));
+        let roundtrip = serde_json::from_value::<sm::model::SecretPayload>(serde_json::json!({}))?;
+        assert_eq!(roundtrip.data_crc32c, None);
+        Ok(())
+    }
+
+    #[test]
+    fn typed_time_setters() -> Result<()> {
+        let secret = sm::model::Secret::default()
+            .try_set_version_destroy_ttl(std::time::Duration::from_secs(86400))?
+            .try_set_create_time(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1))?;
+        let got = serde_json::to_value(&secret)?;
+        let want = serde_json::json!({
+            "createTime": "1970-01-01T00:00:01Z",
+            "versionDestroyTtl": "86400s"
+        });
+        assert_eq!(got, want);
+
+        let secret = sm::model::Secret::default().try_set_version_destroy_ttl("1.5s")?;
+        assert_eq!(
+            secret.version_destroy_ttl,
+            Some(wkt::Duration::new(1, 500_000_000)?)
+        );
+
+        let got = sm::model::Secret::default().try_set_version_destroy_ttl("not-a-duration");
+        assert!(got.is_err(), "{got:?}");
+        let got = sm::model::Secret::default()
+            .try_set_version_destroy_ttl(std::time::Duration::from_secs(u64::MAX));
+        assert!(got.is_err(), "{got:?}");
+        Ok(())
+    }
+
     #[test]
     fn multiple_serde_attributes() -> Result<()> {
         let input = Test {
@@ -65,7 +114,7 @@ mod serialization {
     struct Test {
         #[serde(rename = "fancyName")]
         #[serde(skip_serializing_if = "bytes::Bytes::is_empty")]
-        #[serde_as(as = "serde_with::base64::Base64")]
+        #[serde_as(as = "wkt::internal::Base64")]
         f_bytes: bytes::Bytes,
 
         #[serde(rename = "fString")]
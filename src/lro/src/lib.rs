@@ -0,0 +1,519 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to poll [long-running operations] until they complete.
+//!
+//! Some Google Cloud services return a [longrunning::model::Operation] to
+//! represent work that may take a long time to complete. The [Poller] type
+//! starts such an operation and polls it until it completes, decoding the
+//! intermediate metadata and the final result into the types declared by the
+//! service.
+//!
+//! This helper lives in its own crate because the `google.longrunning`
+//! client depends on the `gax` crate, and the poller depends on the
+//! `google.longrunning` types.
+//!
+//! [long-running operations]: https://google.aip.dev/151
+
+use gax::backoff_policy::{BackoffPolicy, BackoffPolicyArg, ExponentialBackoff};
+use gax::clock::{Clock, TokioClock};
+use gax::error::Error;
+use gax::polling_policy::{Aip194Strict, PollingPolicy, PollingPolicyArg, PollingPolicyExt};
+use gax::retry_policy::RetryFlow;
+use longrunning::model::Operation;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The result type used by the client libraries.
+pub type Result<T> = std::result::Result<T, Error>;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type StartFn = Box<dyn FnOnce() -> BoxFuture<Result<Operation>> + Send>;
+type QueryFn = Box<dyn Fn(String) -> BoxFuture<Result<Operation>> + Send + Sync>;
+
+/// The default limit for the polling loop.
+const DEFAULT_POLLING_LIMIT: Duration = Duration::from_secs(600);
+
+/// The error returned when a long-running operation completes with an error.
+///
+/// The service reports the error as a [rpc::model::Status]. The poller wraps
+/// this status in a [gax::error::Error] of kind
+/// [Rpc][gax::error::ErrorKind::Rpc]. Use
+/// [Error::as_inner][gax::error::Error::as_inner] to recover it.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("the operation completed with an error, code={}, message={}", .status.code, .status.message)]
+pub struct OperationError {
+    status: rpc::model::Status,
+}
+
+impl OperationError {
+    /// Returns the status reported by the service.
+    pub fn status(&self) -> &rpc::model::Status {
+        &self.status
+    }
+}
+
+/// The result of polling a long-running operation.
+///
+/// # Parameters
+/// * `R` - the type of the operation result.
+/// * `M` - the type of the operation metadata.
+#[derive(Debug)]
+pub enum PollingResult<R, M> {
+    /// The operation is still in progress.
+    ///
+    /// Contains the operation metadata, if the service returned any and it
+    /// can be decoded as `M`.
+    InProgress(Option<M>),
+
+    /// The operation completed. This is the last result returned by
+    /// [Poller::poll].
+    ///
+    /// Contains the operation result, or the error if the operation failed,
+    /// or the polling loop was stopped by the polling policy.
+    Completed(Result<R>),
+
+    /// An error polling the operation. The polling policy determined this
+    /// error is recoverable, so the poller will try again.
+    PollingError(Error),
+}
+
+/// Starts a long-running operation and polls it until it completes.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_lro::*;
+/// use longrunning::model::Operation;
+/// # async fn sample() -> Result<()> {
+/// let start = || async {
+///     let response = wkt::Any::from(&wkt::Duration::clamp(60, 0)).unwrap();
+///     let result = longrunning::model::operation::Result::Response(response);
+///     Ok(Operation::default().set_done(true).set_result(result))
+/// };
+/// let query = |_name: String| async { Ok(Operation::default()) };
+/// let poller = Poller::<wkt::Duration, wkt::Empty>::new(start, query);
+/// let response = poller.until_done().await?;
+/// assert_eq!(response, wkt::Duration::clamp(60, 0));
+/// # Ok(()) }
+/// ```
+///
+/// # Parameters
+/// * `R` - the type of the operation result.
+/// * `M` - the type of the operation metadata.
+pub struct Poller<R, M> {
+    start: Option<StartFn>,
+    query: QueryFn,
+    operation: Option<String>,
+    polling_policy: Arc<dyn PollingPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+    loop_start: Instant,
+    attempt_count: u32,
+    done: bool,
+    _phantom: std::marker::PhantomData<fn() -> (R, M)>,
+}
+
+impl<R, M> Poller<R, M>
+where
+    R: serde::de::DeserializeOwned,
+    M: serde::de::DeserializeOwned,
+{
+    /// Creates a new poller.
+    ///
+    /// # Parameters
+    /// * `start` - a function to start the long-running operation, typically
+    ///   it calls the RPC that returns the operation.
+    /// * `query` - a function to query the status of the operation, given its
+    ///   name, typically it calls `GetOperation`.
+    pub fn new<S, SF, Q, QF>(start: S, query: Q) -> Self
+    where
+        S: FnOnce() -> SF + Send + 'static,
+        SF: Future<Output = Result<Operation>> + Send + 'static,
+        Q: Fn(String) -> QF + Send + Sync + 'static,
+        QF: Future<Output = Result<Operation>> + Send + 'static,
+    {
+        Self {
+            start: Some(Box::new(move || Box::pin(start()))),
+            query: Box::new(move |name| Box::pin(query(name))),
+            operation: None,
+            polling_policy: Arc::new(Aip194Strict.with_time_limit(DEFAULT_POLLING_LIMIT)),
+            backoff_policy: Arc::new(ExponentialBackoff::default()),
+            loop_start: TokioClock.now(),
+            attempt_count: 0,
+            done: false,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the polling policy.
+    ///
+    /// The default policy follows [AIP-194] and stops the polling loop after
+    /// 10 minutes.
+    ///
+    /// [AIP-194]: https://google.aip.dev/194
+    pub fn with_polling_policy<V: Into<PollingPolicyArg>>(mut self, v: V) -> Self {
+        let arg: PollingPolicyArg = v.into();
+        self.polling_policy = arg.into();
+        self
+    }
+
+    /// Sets the backoff policy, which controls the delay between polling
+    /// attempts in [until_done][Poller::until_done].
+    pub fn with_backoff_policy<V: Into<BackoffPolicyArg>>(mut self, v: V) -> Self {
+        let arg: BackoffPolicyArg = v.into();
+        self.backoff_policy = arg.into();
+        self
+    }
+
+    /// Returns the name of the operation, if it has started.
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation.as_deref()
+    }
+
+    /// Polls the operation once.
+    ///
+    /// The first call starts the operation. Returns `None` once the operation
+    /// has completed, that is, after a [PollingResult::Completed] value is
+    /// returned.
+    pub async fn poll(&mut self) -> Option<PollingResult<R, M>> {
+        if self.done {
+            return None;
+        }
+        if let Some(start) = self.start.take() {
+            self.loop_start = TokioClock.now();
+            return match start().await {
+                Ok(op) => Some(self.handle_operation(op)),
+                Err(e) => Some(self.complete(Err(e))),
+            };
+        }
+        let name = self.operation.clone().unwrap_or_default();
+        self.attempt_count += 1;
+        match (self.query)(name).await {
+            Ok(op) => Some(self.handle_operation(op)),
+            Err(e) => {
+                match self
+                    .polling_policy
+                    .on_error(self.loop_start, self.attempt_count, e)
+                {
+                    RetryFlow::Continue(e) => Some(PollingResult::PollingError(e)),
+                    RetryFlow::Permanent(e) | RetryFlow::Exhausted(e) => {
+                        Some(self.complete(Err(e)))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Polls the operation until it completes, sleeping between attempts.
+    ///
+    /// Returns the result of the operation, or the error that stopped the
+    /// polling loop.
+    pub async fn until_done(mut self) -> Result<R> {
+        while let Some(p) = self.poll().await {
+            match p {
+                PollingResult::Completed(r) => return r,
+                PollingResult::InProgress(_) | PollingResult::PollingError(_) => {
+                    let delay = self
+                        .backoff_policy
+                        .on_failure(self.loop_start, self.attempt_count);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+        Err(Error::other("the operation was already completed"))
+    }
+
+    /// Converts the poller into a stream of [PollingResult] values,
+    /// sleeping between attempts.
+    ///
+    /// The stream ends after the [PollingResult::Completed] value.
+    #[cfg(feature = "unstable-stream")]
+    pub fn into_stream(self) -> impl futures::Stream<Item = PollingResult<R, M>> {
+        futures::stream::unfold(Some(self), |poller| async move {
+            let mut poller = poller?;
+            let result = poller.poll().await?;
+            if !matches!(result, PollingResult::Completed(_)) {
+                let delay = poller
+                    .backoff_policy
+                    .on_failure(poller.loop_start, poller.attempt_count);
+                tokio::time::sleep(delay).await;
+            }
+            Some((result, Some(poller)))
+        })
+    }
+
+    fn handle_operation(&mut self, op: Operation) -> PollingResult<R, M> {
+        if op.done {
+            return self.complete(Self::decode_result(op));
+        }
+        if !op.name.is_empty() {
+            self.operation = Some(op.name.clone());
+        }
+        if let Some(e) =
+            self.polling_policy
+                .on_in_progress(self.loop_start, self.attempt_count, &op.name)
+        {
+            return self.complete(Err(e));
+        }
+        let metadata = op
+            .metadata
+            .as_ref()
+            .and_then(|a| a.try_into_message::<M>().ok());
+        PollingResult::InProgress(metadata)
+    }
+
+    fn complete(&mut self, result: Result<R>) -> PollingResult<R, M> {
+        self.done = true;
+        PollingResult::Completed(result)
+    }
+
+    fn decode_result(op: Operation) -> Result<R> {
+        use longrunning::model::operation::Result as OperationResult;
+        match op.result {
+            Some(OperationResult::Response(any)) => any
+                .try_into_message::<R>()
+                .map_err(|e| Error::serde(e.to_string())),
+            Some(OperationResult::Error(status)) => Err(Error::rpc(OperationError { status })),
+            Some(_) => Err(Error::other(format!(
+                "unexpected result in completed operation {}",
+                op.name
+            ))),
+            None => Err(Error::other(format!(
+                "operation {} completed without a result",
+                op.name
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gax::polling_policy::AlwaysContinue;
+    use longrunning::model::operation::Result as OperationResult;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    type TestPoller = Poller<wkt::Duration, wkt::Timestamp>;
+
+    fn in_progress(seconds: i64) -> Result<Operation> {
+        let metadata = wkt::Any::from(&wkt::Timestamp::clamp(seconds, 0)).unwrap();
+        Ok(Operation::default()
+            .set_name("test-only-operation")
+            .set_metadata(metadata))
+    }
+
+    fn done(seconds: i64) -> Result<Operation> {
+        let response = wkt::Any::from(&wkt::Duration::clamp(seconds, 0)).unwrap();
+        Ok(Operation::default()
+            .set_name("test-only-operation")
+            .set_done(true)
+            .set_result(OperationResult::Response(response)))
+    }
+
+    fn failed() -> Result<Operation> {
+        let status = rpc::model::Status::default()
+            .set_code(9)
+            .set_message("test-only failure");
+        Ok(Operation::default()
+            .set_name("test-only-operation")
+            .set_done(true)
+            .set_result(OperationResult::Error(status)))
+    }
+
+    fn transient() -> Error {
+        Error::io("test-only transient error".to_string())
+    }
+
+    #[tokio::test]
+    async fn poll_completed_on_start() -> TestResult {
+        let mut poller = TestPoller::new(|| async { done(42) }, |_| async { in_progress(0) });
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::Completed(Ok(d))) if *d == wkt::Duration::clamp(42, 0)),
+            "{p:?}"
+        );
+        assert!(poller.poll().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_start_error() -> TestResult {
+        let mut poller = TestPoller::new(
+            || async { Err(Error::other("start failed")) },
+            |_| async { in_progress(0) },
+        );
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::Completed(Err(_)))),
+            "{p:?}"
+        );
+        assert!(poller.poll().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_sequence() -> TestResult {
+        let count = Arc::new(AtomicU32::new(0));
+        let counter = count.clone();
+        let query = move |name: String| {
+            assert_eq!(name, "test-only-operation");
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match n {
+                    0 => Err(transient()),
+                    1 => in_progress(1),
+                    _ => done(42),
+                }
+            }
+        };
+        let mut poller = TestPoller::new(|| async { in_progress(0) }, query);
+
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::InProgress(Some(m))) if *m == wkt::Timestamp::clamp(0, 0)),
+            "{p:?}"
+        );
+        assert_eq!(poller.operation_name(), Some("test-only-operation"));
+        let p = poller.poll().await;
+        assert!(matches!(&p, Some(PollingResult::PollingError(_))), "{p:?}");
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::InProgress(Some(m))) if *m == wkt::Timestamp::clamp(1, 0)),
+            "{p:?}"
+        );
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::Completed(Ok(d))) if *d == wkt::Duration::clamp(42, 0)),
+            "{p:?}"
+        );
+        assert!(poller.poll().await.is_none());
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_permanent_error() -> TestResult {
+        let mut poller = TestPoller::new(
+            || async { in_progress(0) },
+            |_| async { Err(Error::other("permanent")) },
+        );
+        let p = poller.poll().await;
+        assert!(matches!(&p, Some(PollingResult::InProgress(_))), "{p:?}");
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::Completed(Err(_)))),
+            "{p:?}"
+        );
+        assert!(poller.poll().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_policy_exhausted() -> TestResult {
+        let mut poller = TestPoller::new(|| async { in_progress(0) }, |_| async { in_progress(1) })
+            .with_polling_policy(AlwaysContinue.with_attempt_limit(2));
+        let p = poller.poll().await;
+        assert!(matches!(&p, Some(PollingResult::InProgress(_))), "{p:?}");
+        let p = poller.poll().await;
+        assert!(matches!(&p, Some(PollingResult::InProgress(_))), "{p:?}");
+        let p = poller.poll().await;
+        assert!(
+            matches!(&p, Some(PollingResult::Completed(Err(_)))),
+            "{p:?}"
+        );
+        assert!(poller.poll().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_metadata_mismatch() -> TestResult {
+        let start = || async {
+            let metadata = wkt::Any::from(&wkt::FieldMask::default()).unwrap();
+            Ok(Operation::default().set_name("op").set_metadata(metadata))
+        };
+        let mut poller = TestPoller::new(start, |_| async { done(0) });
+        let p = poller.poll().await;
+        assert!(matches!(&p, Some(PollingResult::InProgress(None))), "{p:?}");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn until_done() -> TestResult {
+        let count = Arc::new(AtomicU32::new(0));
+        let counter = count.clone();
+        let query = move |_| {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match n {
+                    0 | 1 => in_progress(n as i64),
+                    _ => done(42),
+                }
+            }
+        };
+        let poller = TestPoller::new(|| async { in_progress(0) }, query);
+        let response = poller.until_done().await?;
+        assert_eq!(response, wkt::Duration::clamp(42, 0));
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn until_done_operation_error() -> TestResult {
+        let poller = TestPoller::new(|| async { in_progress(0) }, |_| async { failed() });
+        let err = poller.until_done().await.err().unwrap();
+        assert_eq!(err.kind(), gax::error::ErrorKind::Rpc, "{err:?}");
+        let inner = err.as_inner::<OperationError>().unwrap();
+        assert_eq!(inner.status().code, 9);
+        assert_eq!(inner.status().message, "test-only failure");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn until_done_missing_result() -> TestResult {
+        let poller = TestPoller::new(
+            || async { Ok(Operation::default().set_done(true)) },
+            |_| async { in_progress(0) },
+        );
+        let err = poller.until_done().await.err().unwrap();
+        assert_eq!(err.kind(), gax::error::ErrorKind::Other, "{err:?}");
+        Ok(())
+    }
+
+    #[cfg(feature = "unstable-stream")]
+    #[tokio::test(start_paused = true)]
+    async fn into_stream() -> TestResult {
+        use futures::StreamExt;
+        let count = Arc::new(AtomicU32::new(0));
+        let counter = count.clone();
+        let query = move |_| {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match n {
+                    0 => in_progress(1),
+                    _ => done(42),
+                }
+            }
+        };
+        let poller = TestPoller::new(|| async { in_progress(0) }, query);
+        let results = poller.into_stream().collect::<Vec<_>>().await;
+        assert_eq!(results.len(), 3, "{results:?}");
+        assert!(
+            matches!(&results[2], PollingResult::Completed(Ok(d)) if *d == wkt::Duration::clamp(42, 0)),
+            "{results:?}"
+        );
+        Ok(())
+    }
+}
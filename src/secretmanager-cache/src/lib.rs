@@ -0,0 +1,419 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to access Secret Manager payloads.
+//!
+//! [access_secret] fetches a secret version, validates its checksum, and
+//! returns the payload in a [SecretBytes], which zeroes the bytes when dropped.
+//!
+//! ```no_run
+//! # use gcp_sdk_secretmanager_cache::access_secret;
+//! # async fn sample() -> gax::Result<()> {
+//! let client = sm::client::SecretManagerService::new().await?;
+//! let value = access_secret(
+//!     &client,
+//!     "projects/my-project/secrets/my-secret/versions/latest",
+//! )
+//! .await?;
+//! println!("the secret has {} bytes", value.expose().len());
+//! # Ok(()) }
+//! ```
+//!
+//! # Caching
+//!
+//! Services often read the same secrets on every request. Calling
+//! [AccessSecretVersion] each time adds latency and consumes quota. The
+//! [CachedSecretAccessor] keeps the payloads in memory for a configurable
+//! time-to-live (TTL). The payloads are zeroed when they are evicted from the
+//! cache and the last [SecretBytes] referencing them is dropped.
+//!
+//! ```no_run
+//! # use gcp_sdk_secretmanager_cache::CachedSecretAccessor;
+//! # async fn sample() -> gax::Result<()> {
+//! let client = sm::client::SecretManagerService::new().await?;
+//! let cache = CachedSecretAccessor::new(client)
+//!     .with_ttl(std::time::Duration::from_secs(60));
+//! let value = cache
+//!     .access("projects/my-project/secrets/my-secret/versions/latest")
+//!     .await?;
+//! println!("the secret has {} bytes", value.expose().len());
+//! # Ok(()) }
+//! ```
+//!
+//! # Invalidation
+//!
+//! Secret versions referenced by an alias, such as `latest`, change when the
+//! secret is rotated. Applications that receive [rotation notifications]
+//! should call [CachedSecretAccessor::invalidate] with the secret name in the
+//! notification. This drops all the cached versions of the secret, and the
+//! next call to [CachedSecretAccessor::access] fetches the new payload.
+//!
+//! [AccessSecretVersion]: sm::client::SecretManagerService::access_secret_version
+//! [rotation notifications]: https://cloud.google.com/secret-manager/docs/event-notifications
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use zeroize::Zeroizing;
+
+/// The default time-to-live for cached payloads.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+/// A secret payload returned by [access_secret] and [CachedSecretAccessor].
+///
+/// The payload may be shared with a cache, cloning this type is cheap. The
+/// bytes are zeroed once the cache and all the clones release them. The
+/// [Debug] implementation does not print the payload.
+#[derive(Clone)]
+pub struct SecretBytes(Arc<Zeroizing<Vec<u8>>>);
+
+impl SecretBytes {
+    /// Returns the payload bytes.
+    pub fn expose(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl std::fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SecretBytes").field(&"[censored]").finish()
+    }
+}
+
+/// The checksum of a secret payload does not match its contents.
+///
+/// [access_secret] returns this error, wrapped in a [gax::error::Error], when
+/// the payload was corrupted in transit.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("the payload for {name} is corrupted, expected crc32c={expected}, got={actual}")]
+#[non_exhaustive]
+pub struct ChecksumMismatch {
+    /// The secret version name, as returned by the service.
+    pub name: String,
+    /// The checksum returned by the service.
+    pub expected: i64,
+    /// The checksum of the payload received by the client.
+    pub actual: i64,
+}
+
+/// Fetches the payload for the secret version `name` using
+/// [AccessSecretVersion].
+///
+/// The client already decodes the payload from its base64 representation.
+/// If the service includes a CRC32C checksum of the payload, this function
+/// validates it and returns a [ChecksumMismatch] error if it does not match.
+///
+/// [AccessSecretVersion]: sm::client::SecretManagerService::access_secret_version
+pub async fn access_secret(
+    client: &sm::client::SecretManagerService,
+    name: impl Into<String>,
+) -> gax::Result<SecretBytes> {
+    let response = client.access_secret_version(name).send().await?;
+    let Some(payload) = response.payload else {
+        return Ok(SecretBytes(Arc::new(Zeroizing::new(Vec::new()))));
+    };
+    let data = Zeroizing::new(Vec::from(payload.data));
+    if let Some(expected) = payload.data_crc32c {
+        let actual = crc32c::crc32c(&data) as i64;
+        if actual != expected {
+            return Err(gax::error::Error::other(ChecksumMismatch {
+                name: response.name,
+                expected,
+                actual,
+            }));
+        }
+    }
+    Ok(SecretBytes(Arc::new(data)))
+}
+
+/// Caches the payloads returned by [AccessSecretVersion].
+///
+/// The cache is keyed by the secret version name, as provided by the
+/// application. Note that `projects/p/secrets/s/versions/latest` and
+/// `projects/p/secrets/s/versions/3` are cached independently, even if they
+/// refer to the same version.
+///
+/// Cloning this type is cheap, the clones share the same cache.
+///
+/// [AccessSecretVersion]: sm::client::SecretManagerService::access_secret_version
+#[derive(Clone, Debug)]
+pub struct CachedSecretAccessor {
+    client: sm::client::SecretManagerService,
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: SecretBytes,
+    expires: Instant,
+}
+
+impl CachedSecretAccessor {
+    /// Creates a new cache using `client` to fetch the payloads.
+    ///
+    /// The cached payloads expire after [DEFAULT_TTL].
+    pub fn new(client: sm::client::SecretManagerService) -> Self {
+        Self {
+            client,
+            ttl: DEFAULT_TTL,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Changes the time-to-live for cached payloads.
+    ///
+    /// A zero TTL disables caching.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns the payload for the secret version `name`.
+    ///
+    /// The payload is fetched using [access_secret] if it is not in the cache,
+    /// or if the cached value has expired. Errors are not cached.
+    pub async fn access(&self, name: impl Into<String>) -> gax::Result<SecretBytes> {
+        let name = name.into();
+        if let Some(value) = self.lookup(&name) {
+            return Ok(value);
+        }
+        let value = access_secret(&self.client, &name).await?;
+        if !self.ttl.is_zero() {
+            let entry = Entry {
+                value: value.clone(),
+                expires: Instant::now() + self.ttl,
+            };
+            self.entries.lock().unwrap().insert(name, entry);
+        }
+        Ok(value)
+    }
+
+    /// Drops the cached payloads for `name`.
+    ///
+    /// `name` can be a secret version, e.g.
+    /// `projects/p/secrets/s/versions/latest`, or a secret, e.g.
+    /// `projects/p/secrets/s`. In the latter case all the cached versions of
+    /// the secret are dropped. Use this function when the application receives
+    /// a rotation notification for the secret.
+    pub fn invalidate(&self, name: &str) {
+        let prefix = format!("{name}/versions/");
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|k, _| k != name && !k.starts_with(&prefix));
+    }
+
+    /// Drops all the cached payloads.
+    pub fn invalidate_all(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn lookup(&self, name: &str) -> Option<SecretBytes> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(name) {
+            Some(e) if e.expires > Instant::now() => Some(e.value.clone()),
+            Some(_) => {
+                entries.remove(name);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    // A stub that returns the request name and the number of calls as the
+    // payload.
+    #[derive(Debug, Default)]
+    struct FakeSecretManager {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl sm::traits::SecretManagerService for FakeSecretManager {
+        async fn access_secret_version(
+            &self,
+            req: sm::model::AccessSecretVersionRequest,
+            _options: gax::options::RequestOptions,
+        ) -> gax::Result<sm::model::AccessSecretVersionResponse> {
+            let count = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if req.name.contains("missing") {
+                return Err(gax::error::Error::other("simulated failure"));
+            }
+            let data = format!("{}#{count}", req.name);
+            let mut checksum = crc32c::crc32c(data.as_bytes()) as i64;
+            if req.name.contains("corrupted") {
+                checksum += 1;
+            }
+            let payload = sm::model::SecretPayload::default()
+                .set_data(bytes::Bytes::from(data))
+                .set_data_crc32c(checksum);
+            Ok(sm::model::AccessSecretVersionResponse::default()
+                .set_name(req.name)
+                .set_payload(payload))
+        }
+    }
+
+    fn new_cache() -> (CachedSecretAccessor, Arc<AtomicUsize>) {
+        let stub = FakeSecretManager::default();
+        let calls = stub.calls.clone();
+        let client = sm::client::SecretManagerService::from_stub(stub);
+        (CachedSecretAccessor::new(client), calls)
+    }
+
+    const LATEST: &str = "projects/p/secrets/s/versions/latest";
+    const V1: &str = "projects/p/secrets/s/versions/1";
+    const OTHER: &str = "projects/p/secrets/other/versions/latest";
+
+    #[tokio::test]
+    async fn cached() -> TestResult {
+        let (cache, calls) = new_cache();
+        let value = cache.access(LATEST).await?;
+        assert_eq!(value.expose(), format!("{LATEST}#1").as_bytes());
+        let value = cache.access(LATEST).await?;
+        assert_eq!(value.expose(), format!("{LATEST}#1").as_bytes());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let value = cache.access(V1).await?;
+        assert_eq!(value.expose(), format!("{V1}#2").as_bytes());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expires() -> TestResult {
+        let (cache, calls) = new_cache();
+        let cache = cache.with_ttl(Duration::from_secs(60));
+        cache.access(LATEST).await?;
+        tokio::time::advance(Duration::from_secs(30)).await;
+        cache.access(LATEST).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        let value = cache.access(LATEST).await?;
+        assert_eq!(value.expose(), format!("{LATEST}#2").as_bytes());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn zero_ttl() -> TestResult {
+        let (cache, calls) = new_cache();
+        let cache = cache.with_ttl(Duration::ZERO);
+        cache.access(LATEST).await?;
+        cache.access(LATEST).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalidate() -> TestResult {
+        let (cache, calls) = new_cache();
+        for name in [LATEST, V1, OTHER] {
+            cache.access(name).await?;
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        cache.invalidate(V1);
+        cache.access(V1).await?;
+        cache.access(LATEST).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+        // Invalidating the secret drops all its versions, but not other
+        // secrets with the same prefix.
+        cache.invalidate("projects/p/secrets/s");
+        cache.access(V1).await?;
+        cache.access(LATEST).await?;
+        cache.access(OTHER).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+        cache.invalidate("projects/p/secrets/o");
+        cache.access(OTHER).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+
+        cache.invalidate_all();
+        cache.access(OTHER).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 7);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_not_cached() -> TestResult {
+        let (cache, calls) = new_cache();
+        let name = "projects/p/secrets/missing/versions/latest";
+        assert!(cache.access(name).await.is_err());
+        assert!(cache.access(name).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn access() -> TestResult {
+        let (cache, _) = new_cache();
+        let value = access_secret(&cache.client, LATEST).await?;
+        assert_eq!(value.expose(), format!("{LATEST}#1").as_bytes());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn access_without_checksum() -> TestResult {
+        #[derive(Debug)]
+        struct NoChecksum;
+        impl sm::traits::SecretManagerService for NoChecksum {
+            async fn access_secret_version(
+                &self,
+                _req: sm::model::AccessSecretVersionRequest,
+                _options: gax::options::RequestOptions,
+            ) -> gax::Result<sm::model::AccessSecretVersionResponse> {
+                let payload = sm::model::SecretPayload::default().set_data("value");
+                Ok(sm::model::AccessSecretVersionResponse::default().set_payload(payload))
+            }
+        }
+        let client = sm::client::SecretManagerService::from_stub(NoChecksum);
+        let value = access_secret(&client, LATEST).await?;
+        assert_eq!(value.expose(), b"value");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checksum_mismatch() -> TestResult {
+        let (cache, calls) = new_cache();
+        let name = "projects/p/secrets/corrupted/versions/latest";
+        let err = access_secret(&cache.client, name).await.unwrap_err();
+        let mismatch = err.as_inner::<ChecksumMismatch>().unwrap();
+        assert_eq!(mismatch.name, name);
+        assert_eq!(mismatch.expected, mismatch.actual + 1);
+
+        // Corrupted payloads are not cached.
+        assert!(cache.access(name).await.is_err());
+        assert!(cache.access(name).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn debug_is_censored() -> TestResult {
+        let (cache, _) = new_cache();
+        let value = cache.access(LATEST).await?;
+        let got = format!("{value:?}");
+        assert!(!got.contains(LATEST), "{got}");
+        Ok(())
+    }
+}
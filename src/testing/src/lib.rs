@@ -0,0 +1,335 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to test how applications handle failures in Google Cloud services.
+//!
+//! The client libraries retry some failed requests, back off between
+//! attempts, and give up after some time or number of attempts. Applications
+//! may want to verify how their code behaves in these cases. This crate
+//! provides a [FaultServer], a fake HTTP service that injects faults on
+//! specific attempts, and records the requests it receives.
+//!
+//! Configure the client to use the server [endpoint][FaultServer::endpoint]
+//! and [anonymous credentials][auth::Credential::anonymous]. For example:
+//!
+//! ```
+//! # use gcp_sdk_testing::*;
+//! # async fn sample() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = FaultServer::builder()
+//!     .fault(Fault::status(503, "UNAVAILABLE", "try again later"))
+//!     .fault(Fault::status(503, "UNAVAILABLE", "try again later"))
+//!     .response(serde_json::json!({"name": "projects/p/secrets/s"}))
+//!     .start()
+//!     .await?;
+//! // Use `server.endpoint()` as the client endpoint, then make a request ...
+//! assert_eq!(server.attempts(), 0);
+//! # Ok(()) }
+//! ```
+//!
+//! # Fake clock
+//!
+//! The client libraries use the [tokio] clock to wait between attempts. In
+//! tests, start the runtime with a paused clock, e.g. using
+//! `#[tokio::test(start_paused = true)]`. The runtime advances the clock
+//! whenever it has no work to do, so the backoff delays complete
+//! immediately. Note that the paused clock also advances while a request is
+//! waiting for the server, so do not combine it with attempt or operation
+//! timeouts. To test timeouts use [Fault::delay] with a real clock.
+//!
+//! # Stability
+//!
+//! This crate follows semantic versioning. The types are marked as
+//! `#[non_exhaustive]` so new kinds of faults can be added in minor releases.
+//!
+//! [auth::Credential::anonymous]: https://docs.rs/google-cloud-auth
+//! [tokio]: https://docs.rs/tokio
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, Method, StatusCode, Uri};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// A fault injected by the [FaultServer].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// Return an error with the given HTTP status code.
+    ///
+    /// The body is a JSON object with the `google.rpc.Status` error, as
+    /// returned by Google Cloud services.
+    Status {
+        code: u16,
+        status: String,
+        message: String,
+    },
+    /// Wait before returning the successful response.
+    ///
+    /// Use this fault to test attempt and operation timeouts.
+    Delay(Duration),
+    /// Return the given HTTP status code and body, as-is.
+    ///
+    /// Use this fault to test malformed responses.
+    Raw { code: u16, body: String },
+}
+
+impl Fault {
+    /// Creates a [Fault::Status].
+    ///
+    /// The `status` is the name of the `google.rpc.Code`, e.g. `UNAVAILABLE`.
+    pub fn status<S: Into<String>, M: Into<String>>(code: u16, status: S, message: M) -> Self {
+        Self::Status {
+            code,
+            status: status.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [Fault::Delay].
+    pub fn delay(delay: Duration) -> Self {
+        Self::Delay(delay)
+    }
+
+    /// Creates a [Fault::Raw].
+    pub fn raw<B: Into<String>>(code: u16, body: B) -> Self {
+        Self::Raw {
+            code,
+            body: body.into(),
+        }
+    }
+}
+
+/// A request received by the [FaultServer].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// The HTTP method, e.g. `GET`.
+    pub method: String,
+    /// The request path and query, e.g. `/v1/projects/p/secrets?pageSize=10`.
+    pub uri: String,
+    /// The request headers. Headers that are not valid strings are omitted.
+    pub headers: Vec<(String, String)>,
+    /// The request body.
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Returns the value of the first header named `name`, if any.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A builder for [FaultServer].
+#[derive(Clone, Debug, Default)]
+pub struct FaultServerBuilder {
+    faults: Vec<Fault>,
+    response: Option<serde_json::Value>,
+}
+
+impl FaultServerBuilder {
+    /// Adds a fault for the next attempt.
+    ///
+    /// The first fault applies to the first request, the second fault to the
+    /// second request, and so on. Requests after the last fault get the
+    /// successful response.
+    pub fn fault(mut self, fault: Fault) -> Self {
+        self.faults.push(fault);
+        self
+    }
+
+    /// Sets the successful response, the default is an empty JSON object.
+    pub fn response(mut self, response: serde_json::Value) -> Self {
+        self.response = Some(response);
+        self
+    }
+
+    /// Starts the server, listening on a random port on `127.0.0.1`.
+    pub async fn start(self) -> Result<FaultServer> {
+        let state = Arc::new(Mutex::new(ServerState {
+            faults: self.faults,
+            response: self
+                .response
+                .unwrap_or_else(|| serde_json::Value::Object(Default::default())),
+            requests: Vec::new(),
+        }));
+        let app = axum::Router::new()
+            .fallback(handler)
+            .with_state(state.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = tokio::spawn(async {
+            let _ = axum::serve(listener, app).await;
+        });
+        Ok(FaultServer {
+            endpoint: format!("http://{}:{}", addr.ip(), addr.port()),
+            state,
+            server,
+        })
+    }
+}
+
+/// A fake HTTP service that injects faults on specific attempts.
+///
+/// The server responds to any method and path. The server stops when this
+/// object is dropped.
+#[derive(Debug)]
+pub struct FaultServer {
+    endpoint: String,
+    state: Arc<Mutex<ServerState>>,
+    server: JoinHandle<()>,
+}
+
+impl FaultServer {
+    /// Returns a builder to configure and start a server.
+    pub fn builder() -> FaultServerBuilder {
+        FaultServerBuilder::default()
+    }
+
+    /// The endpoint of the server, e.g. `http://127.0.0.1:12345`.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// The number of requests received so far.
+    pub fn attempts(&self) -> usize {
+        self.state.lock().unwrap().requests.len()
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for FaultServer {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+#[derive(Debug)]
+struct ServerState {
+    faults: Vec<Fault>,
+    response: serde_json::Value,
+    requests: Vec<RecordedRequest>,
+}
+
+async fn handler(
+    State(state): State<Arc<Mutex<ServerState>>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let (fault, response) = {
+        let mut state = state.lock().unwrap();
+        let attempt = state.requests.len();
+        state.requests.push(RecordedRequest {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            headers: headers
+                .iter()
+                .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
+                .collect(),
+            body: String::from_utf8_lossy(&body).to_string(),
+        });
+        (
+            state.faults.get(attempt).cloned(),
+            state.response.to_string(),
+        )
+    };
+    match fault {
+        None => (StatusCode::OK, response),
+        Some(Fault::Delay(delay)) => {
+            tokio::time::sleep(delay).await;
+            (StatusCode::OK, response)
+        }
+        Some(Fault::Status {
+            code,
+            status,
+            message,
+        }) => {
+            let body = serde_json::json!({"error": {
+                "code": code,
+                "status": status,
+                "message": message,
+            }});
+            (status_code(code), body.to_string())
+        }
+        Some(Fault::Raw { code, body }) => (status_code(code), body),
+    }
+}
+
+fn status_code(code: u16) -> StatusCode {
+    StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn faults_in_order() -> Result<()> {
+        let server = FaultServer::builder()
+            .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+            .fault(Fault::raw(200, "not json"))
+            .response(serde_json::json!({"name": "test-only"}))
+            .start()
+            .await?;
+        let client = reqwest::Client::new();
+        let url = format!("{}/v1/test?a=b", server.endpoint());
+
+        let response = client.post(&url).body("body-0").send().await?;
+        assert_eq!(response.status(), 503);
+        let body = response.json::<serde_json::Value>().await?;
+        assert_eq!(body["error"]["status"], "UNAVAILABLE");
+        assert_eq!(body["error"]["message"], "try again");
+
+        let response = client.get(&url).send().await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await?, "not json");
+
+        let response = client.get(&url).send().await?;
+        assert_eq!(response.status(), 200);
+        let body = response.json::<serde_json::Value>().await?;
+        assert_eq!(body, serde_json::json!({"name": "test-only"}));
+
+        assert_eq!(server.attempts(), 3);
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].uri, "/v1/test?a=b");
+        assert_eq!(requests[0].body, "body-0");
+        assert_eq!(requests[1].method, "GET");
+        Ok(())
+    }
+
+    #[test]
+    fn recorded_request_header() {
+        let request = RecordedRequest {
+            method: "GET".to_string(),
+            uri: "/".to_string(),
+            headers: vec![("x-goog-api-client".to_string(), "test-only".to_string())],
+            body: String::new(),
+        };
+        assert_eq!(request.header("X-Goog-Api-Client"), Some("test-only"));
+        assert_eq!(request.header("authorization"), None);
+    }
+}
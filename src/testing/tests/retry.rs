@@ -0,0 +1,126 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify the fault server works with the client libraries.
+
+use gax::http_client::ReqwestClient;
+use gax::options::*;
+use gax::retry_policy::{Aip194Strict, RetryPolicyExt};
+use gcp_sdk_testing::{Fault, FaultServer};
+use serde_json::json;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+async fn client(server: &FaultServer) -> Result<ReqwestClient> {
+    let config = ClientConfig::default()
+        .set_credential(auth::Credential::anonymous())
+        .set_retry_policy(Aip194Strict.with_attempt_limit(3));
+    let client = ReqwestClient::new(config, server.endpoint()).await?;
+    Ok(client)
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_transient_errors() -> Result<()> {
+    let server = FaultServer::builder()
+        .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+        .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+        .response(json!({"name": "test-only"}))
+        .start()
+        .await?;
+    let client = client(&server).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let response: serde_json::Value = client
+        .execute(
+            builder,
+            None::<gax::http_client::NoBody>,
+            RequestOptions::default(),
+        )
+        .await?;
+    assert_eq!(response, json!({"name": "test-only"}));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    let ids: Vec<_> = requests
+        .iter()
+        .map(|r| r.header("x-goog-request-id"))
+        .collect();
+    assert!(ids[0].is_some(), "{requests:?}");
+    assert!(ids.iter().all(|id| id == &ids[0]), "{requests:?}");
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_exhausted() -> Result<()> {
+    let server = FaultServer::builder()
+        .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+        .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+        .fault(Fault::status(503, "UNAVAILABLE", "try again"))
+        .start()
+        .await?;
+    let client = client(&server).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let response = client
+        .execute::<_, serde_json::Value>(
+            builder,
+            None::<gax::http_client::NoBody>,
+            RequestOptions::default(),
+        )
+        .await;
+    assert!(response.is_err(), "{response:?}");
+    assert_eq!(server.attempts(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_response() -> Result<()> {
+    let server = FaultServer::builder()
+        .fault(Fault::raw(200, "not json"))
+        .start()
+        .await?;
+    let client = client(&server).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let response = client
+        .execute::<_, serde_json::Value>(
+            builder,
+            None::<gax::http_client::NoBody>,
+            RequestOptions::default(),
+        )
+        .await;
+    assert!(response.is_err(), "{response:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn attempt_timeout() -> Result<()> {
+    let server = FaultServer::builder()
+        .fault(Fault::delay(std::time::Duration::from_secs(5)))
+        .response(json!({"name": "test-only"}))
+        .start()
+        .await?;
+    let client = client(&server).await?;
+
+    let builder = client.builder(reqwest::Method::GET, "/v1/test".into());
+    let mut options = RequestOptions::default();
+    options.set_attempt_timeout(std::time::Duration::from_millis(100));
+    let response: serde_json::Value = client
+        .execute(builder, None::<gax::http_client::NoBody>, options)
+        .await?;
+    // The first attempt times out, the second attempt succeeds.
+    assert_eq!(response, json!({"name": "test-only"}));
+    assert_eq!(server.attempts(), 2);
+    Ok(())
+}
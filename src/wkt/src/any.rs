@@ -72,6 +72,8 @@ pub enum AnyError {
 
 type Error = AnyError;
 
+use crate::message::{Message, WKT_PREFIX};
+
 impl Any {
     /// Creates a new [Any] from any object that supports serialization to JSON.
     // TODO(#98) - each message should have a type value
@@ -108,6 +110,82 @@ impl Any {
         Ok(Any(value))
     }
 
+    /// Creates a new [Any] from a message, including its type URL.
+    ///
+    /// Well-known types, such as [Duration][crate::Duration], are stored in
+    /// the `value` field. Other messages are stored as the fields of the
+    /// [Any], next to the `@type` field.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::{Any, Duration};
+    /// let any = Any::pack(&Duration::clamp(60, 0))?;
+    /// assert_eq!(any.type_url(), Some("type.googleapis.com/google.protobuf.Duration"));
+    /// let duration = any.unpack::<Duration>()?;
+    /// assert_eq!(duration, Duration::clamp(60, 0));
+    /// # Ok::<(), gcp_sdk_wkt::AnyError>(())
+    /// ```
+    pub fn pack<T>(message: &T) -> Result<Self, Error>
+    where
+        T: Message + serde::ser::Serialize,
+    {
+        use serde_json::Value;
+
+        let typename = T::typename();
+        let value =
+            serde_json::to_value(message).map_err(|e| Error::SerializationError(e.into()))?;
+        let mut map = match value {
+            value if uses_value_field(typename) => {
+                let mut map = serde_json::Map::new();
+                map.insert("value".to_string(), value);
+                map
+            }
+            Value::Object(map) => map,
+            _ => {
+                return Err(Error::SerializationError(Box::from(
+                    "unexpected JSON type, only Object is supported",
+                )))
+            }
+        };
+        map.insert("@type".to_string(), Value::String(typename.to_string()));
+        Ok(Any(map))
+    }
+
+    /// Returns the type URL of the message stored in the [Any], if any.
+    pub fn type_url(&self) -> Option<&str> {
+        self.0.get("@type").and_then(|v| v.as_str())
+    }
+
+    /// Extracts a `T` value from the [Any], verifying the type URL.
+    ///
+    /// Returns [AnyError::TypeMismatchError] if the [Any] contains a
+    /// different type.
+    pub fn unpack<T>(&self) -> Result<T, Error>
+    where
+        T: Message + serde::de::DeserializeOwned,
+    {
+        let type_url = self
+            .type_url()
+            .ok_or_else(|| Self::map_de_str("@type field is missing or is not a string".into()))?;
+        if type_url != T::typename() {
+            return Err(Error::TypeMismatchError(format!(
+                "{type_url}, expected={}",
+                T::typename()
+            )));
+        }
+        let value = if uses_value_field(type_url) {
+            self.0
+                .get("value")
+                .cloned()
+                .ok_or_else(|| Self::map_de_str("value field is missing".to_string()))?
+        } else {
+            let mut map = self.0.clone();
+            map.remove("@type");
+            serde_json::Value::Object(map)
+        };
+        serde_json::from_value::<T>(value).map_err(|e| Self::map_de_err(e.into()))
+    }
+
     fn map_de_err(e: Box<dyn std::error::Error>) -> Error {
         Error::DeserializationError(e)
     }
@@ -139,6 +217,11 @@ impl Any {
     }
 }
 
+/// Returns true if messages of this type are stored in the `value` field.
+fn uses_value_field(typename: &str) -> bool {
+    typename.starts_with(WKT_PREFIX) && typename != crate::Empty::typename()
+}
+
 /// Implement [`serde`](::serde) serialization for [Any].
 impl serde::ser::Serialize for Any {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -246,6 +329,93 @@ mod test {
         Ok(())
     }
 
+    impl Message for Stored {
+        fn typename() -> &'static str {
+            "type.googleapis.com/test.Stored"
+        }
+    }
+
+    #[test]
+    fn pack_duration() -> Result {
+        let input = Duration::clamp(60, 0);
+        let any = Any::pack(&input)?;
+        let got = serde_json::to_value(&any)?;
+        let want = json!({"@type": "type.googleapis.com/google.protobuf.Duration", "value": "60s"});
+        assert_eq!(got, want);
+        assert_eq!(any.unpack::<Duration>()?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn pack_generic() -> Result {
+        let input = Stored {
+            parent: "parent".to_string(),
+            id: "id".to_string(),
+        };
+        let any = Any::pack(&input)?;
+        let got = serde_json::to_value(&any)?;
+        let want =
+            json!({"@type": "type.googleapis.com/test.Stored", "parent": "parent", "id": "id"});
+        assert_eq!(got, want);
+        assert_eq!(any.type_url(), Some("type.googleapis.com/test.Stored"));
+        assert_eq!(any.unpack::<Stored>()?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn pack_empty() -> Result {
+        let any = Any::pack(&crate::Empty::default())?;
+        let got = serde_json::to_value(&any)?;
+        let want = json!({"@type": "type.googleapis.com/google.protobuf.Empty"});
+        assert_eq!(got, want);
+        assert_eq!(any.unpack::<crate::Empty>()?, crate::Empty::default());
+        Ok(())
+    }
+
+    #[test]
+    fn pack_struct() -> Result {
+        let input = json!({"a": 1, "b": [true, null]});
+        let input = input.as_object().cloned().unwrap();
+        let any = Any::pack(&input)?;
+        let got = serde_json::to_value(&any)?;
+        let want = json!({"@type": "type.googleapis.com/google.protobuf.Struct", "value": {"a": 1, "b": [true, null]}});
+        assert_eq!(got, want);
+        assert_eq!(any.unpack::<crate::Struct>()?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn unpack_errors() -> Result {
+        let any = Any::pack(&Duration::clamp(60, 0))?;
+        let got = any.unpack::<Stored>();
+        assert!(matches!(got, Err(Error::TypeMismatchError(_))), "{got:?}");
+
+        let input = json!({"value": "60s"});
+        let any = serde_json::from_value::<Any>(input)?;
+        let got = any.unpack::<Duration>();
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+
+        let input = json!({"@type": "type.googleapis.com/google.protobuf.Duration"});
+        let any = serde_json::from_value::<Any>(input)?;
+        let got = any.unpack::<Duration>();
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+
+        let input = json!({"@type": "type.googleapis.com/google.protobuf.Duration", "value": 60});
+        let any = serde_json::from_value::<Any>(input)?;
+        let got = any.unpack::<Duration>();
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+        Ok(())
+    }
+
     #[test]
     fn deserialize_error() -> Result {
         let input = json!({"@type-is-missing": ""});
@@ -245,12 +245,114 @@ pub struct FieldMask {
     pub paths: Vec<String>,
 }
 
+/// Represent failures in creating [FieldMask] instances.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum FieldMaskError {
+    /// Problem serializing the message.
+    #[error("cannot serialize the message, source={0:?}")]
+    SerializationError(#[source] Box<dyn std::error::Error>),
+
+    /// The value does not serialize to a JSON object.
+    #[error("expected the message to serialize to a JSON object, got {0}")]
+    NotAMessage(String),
+
+    /// The path is not a valid field mask path.
+    #[error("invalid field mask path {0:?}, expected dot-separated snake_case field names")]
+    InvalidPath(String),
+}
+
+type Error = FieldMaskError;
+
 impl FieldMask {
     /// Set the paths.
     pub fn set_paths(mut self, paths: Vec<String>) -> Self {
         self.paths = paths;
         self
     }
+
+    /// Adds a path to the field mask, after validating it.
+    ///
+    /// A path is a dot-separated list of field names, using the `snake_case`
+    /// names from the proto definition, e.g., `user.display_name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::FieldMask;
+    /// let mask = FieldMask::default()
+    ///     .add_path("user.display_name")?
+    ///     .add_path("photo")?;
+    /// assert_eq!(mask.paths, vec!["user.display_name", "photo"]);
+    /// assert!(FieldMask::default().add_path("user.displayName").is_err());
+    /// # Ok::<(), gcp_sdk_wkt::FieldMaskError>(())
+    /// ```
+    pub fn add_path<T: Into<String>>(mut self, path: T) -> Result<Self, Error> {
+        let path = path.into();
+        validate_path(&path)?;
+        self.paths.push(path);
+        Ok(self)
+    }
+
+    /// Verifies all the paths are valid field mask paths.
+    ///
+    /// See [add_path][FieldMask::add_path] for the format of each path.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.paths.iter().try_for_each(|p| validate_path(p))
+    }
+
+    /// Creates a [FieldMask] with the fields that are set in `message`.
+    ///
+    /// This is useful to build the `update_mask` for update RPCs. A field is
+    /// set if it appears in the JSON serialization of the message. For
+    /// fields with [presence], such as proto3 `optional` fields or message
+    /// fields, that includes fields set to their default value, e.g.
+    /// `Some(0)`. For fields without presence, only non-default values are
+    /// set.
+    ///
+    /// The paths use the `snake_case` field names, and only include the
+    /// top-level fields.
+    ///
+    /// [presence]: https://protobuf.dev/programming-guides/field_presence/
+    pub fn from_set_fields<T>(message: &T) -> Result<Self, Error>
+    where
+        T: serde::ser::Serialize,
+    {
+        let value =
+            serde_json::to_value(message).map_err(|e| Error::SerializationError(Box::new(e)))?;
+        let serde_json::Value::Object(object) = value else {
+            return Err(Error::NotAMessage(value.to_string()));
+        };
+        let paths = object.keys().map(|k| to_snake_case(k)).collect();
+        Ok(Self { paths })
+    }
+}
+
+fn validate_path(path: &str) -> Result<(), Error> {
+    let valid_name = |name: &str| {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    };
+    if path.split('.').all(valid_name) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPath(path.to_string()))
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
 }
 
 /// Implement [`serde`](::serde) serialization for [FieldMask]
@@ -318,6 +420,83 @@ mod test {
         Ok(())
     }
 
+    #[derive(Default, serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Message {
+        #[serde(skip_serializing_if = "String::is_empty")]
+        display_name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_count: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data_crc32c: Option<i64>,
+    }
+
+    #[test]
+    fn from_set_fields() -> Result {
+        let got = FieldMask::from_set_fields(&Message::default())?;
+        assert!(got.paths.is_empty(), "{got:?}");
+
+        let message = Message {
+            display_name: "test-only".to_string(),
+            ..Default::default()
+        };
+        let got = FieldMask::from_set_fields(&message)?;
+        assert_eq!(got.paths, vec!["display_name"]);
+
+        // Fields with presence are set even if they have the default value.
+        let message = Message {
+            max_count: Some(0),
+            data_crc32c: Some(0),
+            ..Default::default()
+        };
+        let mut got = FieldMask::from_set_fields(&message)?;
+        got.paths.sort();
+        assert_eq!(got.paths, vec!["data_crc32c", "max_count"]);
+        Ok(())
+    }
+
+    #[test_case("name")]
+    #[test_case("display_name")]
+    #[test_case("user.display_name")]
+    #[test_case("data_crc32c")]
+    #[test_case("_private")]
+    fn add_path(path: &str) -> Result {
+        let got = FieldMask::default().add_path(path)?;
+        assert_eq!(got.paths, vec![path]);
+        got.validate()?;
+        Ok(())
+    }
+
+    #[test_case("")]
+    #[test_case("displayName")]
+    #[test_case("user..name")]
+    #[test_case("user.")]
+    #[test_case("1name")]
+    #[test_case("a,b")]
+    #[test_case("name with spaces")]
+    fn add_path_invalid(path: &str) {
+        let got = FieldMask::default().add_path(path);
+        assert!(
+            matches!(got, Err(FieldMaskError::InvalidPath(ref p)) if p == path),
+            "{got:?}"
+        );
+        let mask = FieldMask::default().set_paths(vec!["name".to_string(), path.to_string()]);
+        let got = mask.validate();
+        assert!(
+            matches!(got, Err(FieldMaskError::InvalidPath(ref p)) if p == path),
+            "{got:?}"
+        );
+    }
+
+    #[test]
+    fn from_set_fields_not_a_message() {
+        let got = FieldMask::from_set_fields(&42);
+        assert!(
+            matches!(got, Err(FieldMaskError::NotAMessage(_))),
+            "{got:?}"
+        );
+    }
+
     #[test_case("", vec![]; "Deserialize empty")]
     #[test_case("field1", vec!["field1"]; "Deserialize single")]
     #[test_case("field1,field2,field3", vec!["field1" ,"field2", "field3"]; "Deserialize multiple")]
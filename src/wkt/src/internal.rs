@@ -0,0 +1,139 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine as _;
+
+const CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&base64::alphabet::STANDARD, CONFIG);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&base64::alphabet::URL_SAFE, CONFIG);
+
+/// Serializes and deserializes `bytes` fields as base64 strings.
+///
+/// The generated code uses this adapter with `serde_with::serde_as`. It
+/// decodes the string directly into the buffer backing the [bytes::Bytes]
+/// field, without intermediate copies. When possible, the string is borrowed
+/// from the input. Like other ProtoJSON parsers it accepts the standard and
+/// URL-safe alphabets, with or without padding.
+///
+/// Serialization always uses the standard alphabet with padding.
+pub struct Base64;
+
+impl<T> serde_with::SerializeAs<T> for Base64
+where
+    T: AsRef<[u8]>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&general_purpose::STANDARD.encode(source))
+    }
+}
+
+impl<'de> serde_with::DeserializeAs<'de, bytes::Bytes> for Base64 {
+    fn deserialize_as<D>(deserializer: D) -> Result<bytes::Bytes, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+struct Visitor;
+
+impl serde::de::Visitor<'_> for Visitor {
+    type Value = bytes::Bytes;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a base64 encoded string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        decode(value).map_err(E::custom)
+    }
+}
+
+fn decode(value: &str) -> Result<bytes::Bytes, base64::DecodeSliceError> {
+    let engine = if value.contains(['-', '_']) {
+        &URL_SAFE
+    } else {
+        &STANDARD
+    };
+    let mut buffer = bytes::BytesMut::zeroed(base64::decoded_len_estimate(value.len()));
+    let len = engine.decode_slice(value, &mut buffer)?;
+    buffer.truncate(len);
+    Ok(buffer.freeze())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use test_case::test_case;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[serde_with::serde_as]
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Helper {
+        #[serde_as(as = "Base64")]
+        value: bytes::Bytes,
+    }
+
+    #[test_case("", ""; "empty")]
+    #[test_case("Zg==", "f"; "padded")]
+    #[test_case("Zg", "f"; "unpadded")]
+    #[test_case("Zm9vYmFy", "foobar"; "no padding needed")]
+    #[test_case("-_-_", "\u{00fb}\u{00ff}\u{00bf}"; "url safe")]
+    #[test_case("+/+/", "\u{00fb}\u{00ff}\u{00bf}"; "standard")]
+    fn deserialize(input: &str, want: &str) -> Result {
+        let want = want.chars().map(|c| c as u8).collect::<Vec<u8>>();
+        let got = serde_json::from_value::<Helper>(json!({"value": input}))?;
+        assert_eq!(got.value, want);
+        Ok(())
+    }
+
+    #[test_case("Zg=g"; "bad padding")]
+    #[test_case("Z"; "bad length")]
+    #[test_case("Zm9v!"; "bad character")]
+    #[test_case("+/-_"; "mixed alphabets")]
+    fn deserialize_error(input: &str) {
+        let got = serde_json::from_value::<Helper>(json!({"value": input}));
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test]
+    fn serialize() -> Result {
+        let input = Helper {
+            value: bytes::Bytes::from_static(&[0xfb, 0xff, 0xbf, 0x66]),
+        };
+        let got = serde_json::to_value(&input)?;
+        assert_eq!(got, json!({"value": "+/+/Zg=="}));
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_large() -> Result {
+        let value = bytes::Bytes::from((0..=255_u8).cycle().take(64 * 1024).collect::<Vec<_>>());
+        let input = Helper { value };
+        let json = serde_json::to_vec(&input)?;
+        let got = serde_json::from_slice::<Helper>(&json)?;
+        assert_eq!(got, input);
+        Ok(())
+    }
+}
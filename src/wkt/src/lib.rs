@@ -26,7 +26,17 @@ mod empty;
 pub use crate::empty::*;
 mod field_mask;
 pub use crate::field_mask::*;
+mod message;
+pub use crate::message::Message;
+mod registry;
+pub use crate::registry::*;
+mod struct_value;
+pub use crate::struct_value::*;
 mod timestamp;
 pub use crate::timestamp::*;
 mod wrappers;
 pub use crate::wrappers::*;
+
+/// Implementation details used by the generated code.
+#[doc(hidden)]
+pub mod internal;
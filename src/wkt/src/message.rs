@@ -0,0 +1,65 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A message that can be stored in an [Any][crate::Any].
+///
+/// The type URL identifies the type of the message stored in an `Any`. It
+/// has the form `type.googleapis.com/{fully qualified message name}`, for
+/// example: `type.googleapis.com/google.protobuf.Duration`.
+pub trait Message {
+    /// The type URL for this message.
+    fn typename() -> &'static str;
+}
+
+/// The prefix used by the type URLs of all the well-known types.
+pub(crate) const WKT_PREFIX: &str = "type.googleapis.com/google.protobuf.";
+
+macro_rules! well_known {
+    ($($t:ty => $name:literal),* $(,)?) => {
+        $(
+            impl Message for $t {
+                fn typename() -> &'static str {
+                    concat!("type.googleapis.com/google.protobuf.", $name)
+                }
+            }
+        )*
+    };
+}
+
+well_known!(
+    crate::Duration => "Duration",
+    crate::Empty => "Empty",
+    crate::FieldMask => "FieldMask",
+    crate::Timestamp => "Timestamp",
+    crate::Struct => "Struct",
+    crate::Value => "Value",
+    crate::ListValue => "ListValue",
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(crate::Duration::typename(), "Duration")]
+    #[test_case(crate::Empty::typename(), "Empty")]
+    #[test_case(crate::FieldMask::typename(), "FieldMask")]
+    #[test_case(crate::Timestamp::typename(), "Timestamp")]
+    #[test_case(crate::Struct::typename(), "Struct")]
+    #[test_case(crate::Value::typename(), "Value")]
+    #[test_case(crate::ListValue::typename(), "ListValue")]
+    fn typename(got: &str, name: &str) {
+        assert_eq!(got, format!("{WKT_PREFIX}{name}"));
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Any, AnyError, Message};
+use std::collections::HashMap;
+
+/// The result of unpacking an [Any] with a [TypeRegistry].
+pub type UnpackedAny = Box<dyn std::any::Any + Send + Sync>;
+
+type Decoder = fn(&Any) -> Result<UnpackedAny, AnyError>;
+
+/// Unpacks [Any] values into messages, based on their type URL.
+///
+/// Some fields, such as the `metadata` in a long-running operation or the
+/// `details` in an error status, may contain one of several message types.
+/// Applications register the types they expect, and then unpack the values
+/// without matching each type URL themselves.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_wkt::{Any, Duration, Timestamp, TypeRegistry};
+/// let registry = TypeRegistry::new()
+///     .register::<Duration>()
+///     .register::<Timestamp>();
+/// let any = Any::pack(&Duration::clamp(60, 0))?;
+/// let unpacked = registry.unpack(&any)?;
+/// if let Some(d) = unpacked.downcast_ref::<Duration>() {
+///     println!("duration = {d:?}");
+/// }
+/// # Ok::<(), gcp_sdk_wkt::AnyError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TypeRegistry {
+    decoders: HashMap<&'static str, Decoder>,
+}
+
+impl TypeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a message type.
+    pub fn register<T>(mut self) -> Self
+    where
+        T: Message + serde::de::DeserializeOwned + Send + Sync + 'static,
+    {
+        self.decoders.insert(T::typename(), |any| {
+            any.unpack::<T>().map(|v| Box::new(v) as UnpackedAny)
+        });
+        self
+    }
+
+    /// Returns true if the registry contains the type URL.
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Unpacks `any` into the message type registered for its type URL.
+    ///
+    /// Use [downcast][Box::downcast] or [downcast_ref][std::any::Any::downcast_ref]
+    /// to get the message. Returns [AnyError::TypeMismatchError] if the type
+    /// is not registered.
+    pub fn unpack(&self, any: &Any) -> Result<UnpackedAny, AnyError> {
+        let type_url = any.type_url().unwrap_or_default();
+        let decoder = self
+            .decoders
+            .get(type_url)
+            .ok_or_else(|| AnyError::TypeMismatchError(format!("{type_url} is not registered")))?;
+        decoder(any)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Duration, FieldMask, Timestamp};
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn unpack() -> Result {
+        let registry = TypeRegistry::new()
+            .register::<Duration>()
+            .register::<Timestamp>();
+        assert!(registry.contains(Duration::typename()));
+        assert!(registry.contains(Timestamp::typename()));
+        assert!(!registry.contains(FieldMask::typename()));
+
+        let any = Any::pack(&Duration::clamp(60, 0))?;
+        let got = registry.unpack(&any)?;
+        assert_eq!(
+            got.downcast_ref::<Duration>(),
+            Some(&Duration::clamp(60, 0))
+        );
+        assert!(got.downcast_ref::<Timestamp>().is_none());
+
+        let any = Any::pack(&Timestamp::clamp(123, 0))?;
+        let got = registry.unpack(&any)?.downcast::<Timestamp>();
+        assert_eq!(got.ok().map(|b| *b), Some(Timestamp::clamp(123, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn unpack_errors() -> Result {
+        let registry = TypeRegistry::new().register::<Duration>();
+        let any = Any::pack(&FieldMask::default())?;
+        let got = registry.unpack(&any);
+        assert!(
+            matches!(got, Err(AnyError::TypeMismatchError(_))),
+            "{:?}",
+            got.err()
+        );
+
+        let any = serde_json::from_value::<Any>(serde_json::json!({}))?;
+        let got = registry.unpack(&any);
+        assert!(
+            matches!(got, Err(AnyError::TypeMismatchError(_))),
+            "{:?}",
+            got.err()
+        );
+
+        let any = serde_json::from_value::<Any>(serde_json::json!({
+            "@type": Duration::typename(),
+            "value": 42,
+        }))?;
+        let got = registry.unpack(&any);
+        assert!(
+            matches!(got, Err(AnyError::DeserializationError(_))),
+            "{:?}",
+            got.err()
+        );
+        Ok(())
+    }
+}
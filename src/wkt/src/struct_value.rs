@@ -0,0 +1,117 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implements the `google.protobuf.Struct` well-known type.
+///
+/// `Struct` represents a structured data value, consisting of fields which map
+/// to dynamically typed values. Its JSON representation is a JSON object. In
+/// Rust, we represent it with the corresponding [serde_json] type, so values
+/// convert to and from [serde_json::Value] without any copies.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_wkt::Struct;
+/// let value = serde_json::json!({"name": "test", "count": 42});
+/// let s: Struct = value.as_object().cloned().unwrap();
+/// assert_eq!(serde_json::Value::Object(s), value);
+/// ```
+pub type Struct = serde_json::Map<String, serde_json::Value>;
+
+/// Implements the `google.protobuf.Value` well-known type.
+///
+/// `Value` represents a dynamically typed value which can be either null, a
+/// number, a string, a boolean, a recursive struct value, or a list of values.
+/// In Rust, we represent it with [serde_json::Value].
+pub type Value = serde_json::Value;
+
+/// Implements the `google.protobuf.ListValue` well-known type.
+///
+/// `ListValue` is a wrapper around a repeated field of values. Its JSON
+/// representation is a JSON array.
+pub type ListValue = Vec<serde_json::Value>;
+
+/// Implements the `google.protobuf.NullValue` well-known type.
+///
+/// `NullValue` is a singleton enumeration to represent the null value for the
+/// [Value] type union. Its JSON representation is `null`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NullValue;
+
+impl std::convert::From<NullValue> for Value {
+    fn from(_: NullValue) -> Self {
+        Value::Null
+    }
+}
+
+/// Implement [`serde`](::serde) serialization for [NullValue].
+impl serde::ser::Serialize for NullValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+/// Implement [`serde`](::serde) deserialization for [NullValue].
+impl<'de> serde::de::Deserialize<'de> for NullValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <()>::deserialize(deserializer).map(|_| NullValue)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn null_value() -> Result {
+        let got = serde_json::to_value(NullValue)?;
+        assert_eq!(got, json!(null));
+        let got = serde_json::from_value::<NullValue>(json!(null))?;
+        assert_eq!(got, NullValue);
+        let got = serde_json::from_value::<NullValue>(json!(0));
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(Value::from(NullValue), Value::Null);
+        Ok(())
+    }
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Message {
+        labels: Struct,
+        values: ListValue,
+        value: Value,
+    }
+
+    #[test]
+    fn roundtrip() -> Result {
+        let input = json!({
+            "labels": {"a": 1, "b": [true, null], "c": {"d": "e"}},
+            "values": [1.5, "two", {"three": 3}],
+            "value": "four",
+        });
+        let message = serde_json::from_value::<Message>(input.clone())?;
+        assert_eq!(message.labels.get("a"), Some(&json!(1)));
+        assert_eq!(message.values.len(), 3);
+        assert_eq!(message.value, json!("four"));
+        let got = serde_json::to_value(&message)?;
+        assert_eq!(got, input);
+        Ok(())
+    }
+}
@@ -237,6 +237,52 @@ impl TryFrom<Timestamp> for time::OffsetDateTime {
     }
 }
 
+/// Converts from [std::time::SystemTime] to [Timestamp].
+///
+/// This conversion may fail if the [std::time::SystemTime] value is out of
+/// range.
+impl TryFrom<std::time::SystemTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: std::time::SystemTime) -> std::result::Result<Self, Self::Error> {
+        match value.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => {
+                let seconds = i64::try_from(d.as_secs()).map_err(|_| Error::OutOfRange())?;
+                Timestamp::new(seconds, d.subsec_nanos() as i32)
+            }
+            Err(e) => {
+                // `value` is before the epoch, the nanos must count forward.
+                let d = e.duration();
+                let seconds = i64::try_from(d.as_secs()).map_err(|_| Error::OutOfRange())?;
+                match d.subsec_nanos() {
+                    0 => Timestamp::new(-seconds, 0),
+                    n => Timestamp::new(-seconds - 1, Self::NS - n as i32),
+                }
+            }
+        }
+    }
+}
+
+/// Converts from [Timestamp] to [std::time::SystemTime].
+///
+/// This conversion may fail if the [Timestamp] value is not representable
+/// as a [std::time::SystemTime] on the current platform.
+impl TryFrom<Timestamp> for std::time::SystemTime {
+    type Error = TimestampError;
+
+    fn try_from(value: Timestamp) -> std::result::Result<Self, Self::Error> {
+        let seconds = std::time::Duration::from_secs(value.seconds.unsigned_abs());
+        let nanos = std::time::Duration::from_nanos(value.nanos as u64);
+        let ts = if value.seconds >= 0 {
+            std::time::UNIX_EPOCH.checked_add(seconds)
+        } else {
+            std::time::UNIX_EPOCH.checked_sub(seconds)
+        };
+        ts.and_then(|t| t.checked_add(nanos))
+            .ok_or(TimestampError::OutOfRange())
+    }
+}
+
 /// Converts a [Timestamp] to its [String] representation.
 impl TryFrom<&Timestamp> for String {
     type Error = TimestampError;
@@ -22,11 +22,11 @@ mod test {
     #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
     #[serde(rename_all = "camelCase")]
     pub struct MessageWithBytes {
-        #[serde_as(as = "serde_with::base64::Base64")]
+        #[serde_as(as = "gcp_sdk_wkt::internal::Base64")]
         pub singular: bytes::Bytes,
-        #[serde_as(as = "Option<serde_with::base64::Base64>")]
+        #[serde_as(as = "Option<gcp_sdk_wkt::internal::Base64>")]
         pub optional: Option<bytes::Bytes>,
-        #[serde_as(as = "Vec<serde_with::base64::Base64>")]
+        #[serde_as(as = "Vec<gcp_sdk_wkt::internal::Base64>")]
         pub repeated: Vec<bytes::Bytes>,
     }
 
@@ -34,7 +34,7 @@ mod test {
         pub map_i64_key: HashMap<i64, String>,
 
         #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
-        #[serde_as(as = "HashMap<_, serde_with::base64::Base64>")]
+        #[serde_as(as = "HashMap<_, gcp_sdk_wkt::internal::Base64>")]
         pub map_bytes: HashMap<String, bytes::Bytes>,
     }
 
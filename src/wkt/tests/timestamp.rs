@@ -117,3 +117,44 @@ fn convert_to_chrono_time() -> Result {
     assert_eq!(got, want);
     Ok(())
 }
+
+#[test]
+fn convert_from_system_time() -> Result {
+    let ts = std::time::UNIX_EPOCH + std::time::Duration::new(123, 456789012);
+    let got = Timestamp::try_from(ts)?;
+    let want = Timestamp::new(123, 456789012)?;
+    assert_eq!(got, want);
+
+    let ts = std::time::UNIX_EPOCH - std::time::Duration::new(123, 456789012);
+    let got = Timestamp::try_from(ts)?;
+    let want = Timestamp::new(-124, 543210988)?;
+    assert_eq!(got, want);
+
+    let ts = std::time::UNIX_EPOCH - std::time::Duration::from_secs(123);
+    let got = Timestamp::try_from(ts)?;
+    let want = Timestamp::new(-123, 0)?;
+    assert_eq!(got, want);
+    Ok(())
+}
+
+#[test]
+fn convert_from_system_time_out_of_range() {
+    let ts =
+        std::time::UNIX_EPOCH + std::time::Duration::from_secs(Timestamp::MAX_SECONDS as u64 + 1);
+    let got = Timestamp::try_from(ts);
+    assert!(got.is_err(), "{got:?}");
+}
+
+#[test]
+fn convert_to_system_time() -> Result {
+    let ts = Timestamp::new(123, 456789012)?;
+    let got = std::time::SystemTime::try_from(ts)?;
+    let want = std::time::UNIX_EPOCH + std::time::Duration::new(123, 456789012);
+    assert_eq!(got, want);
+
+    let ts = Timestamp::new(-124, 543210988)?;
+    let got = std::time::SystemTime::try_from(ts)?;
+    let want = std::time::UNIX_EPOCH - std::time::Duration::new(123, 456789012);
+    assert_eq!(got, want);
+    Ok(())
+}
@@ -32,7 +32,7 @@ pub struct Helper {
     pub field_uint32: Option<UInt32Value>,
     pub field_bool: Option<BoolValue>,
     pub field_string: Option<StringValue>,
-    #[serde_as(as = "Option<serde_with::base64::Base64>")]
+    #[serde_as(as = "Option<gcp_sdk_wkt::internal::Base64>")]
     pub field_bytes: Option<BytesValue>,
 }
 